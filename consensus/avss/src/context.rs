@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use common::{Event, Net};
+use config::Node;
+use consensus::HighThresholdSharing;
+use tokio::sync::oneshot;
+use types::{RBCSyncMsg, Replica};
+
+use super::{ProtMsg, AVSSState};
+
+pub struct Context {
+    /// Networking context
+    pub net: Net<ProtMsg>,
+    /// Data context
+    pub num_nodes: usize,
+    pub myid: usize,
+    pub num_faults: usize,
+    pub(crate) byz: bool,
+
+    /// Bivariate sharing of degree t in both variables over the n nodes, node i holds the row, the column and
+    /// the share at party index i + 1
+    pub sharing: HighThresholdSharing,
+    // Each sharing is associated with a Unique Identifier.
+    pub avss_context: HashMap<usize, AVSSState>,
+}
+
+impl Context {
+    pub fn spawn(config: Node, byz: bool) -> anyhow::Result<oneshot::Sender<()>> {
+        let (net, exit_tx) = Net::spawn(&config, byz);
+
+        let sharing = HighThresholdSharing::new(config.num_faults + 1, config.num_faults + 1, config.num_nodes)
+            .map_err(|e| anyhow!("Invalid AVSS parameters: {}", e))?;
+
+        tokio::spawn(async move {
+            let mut c = Context {
+                net,
+                num_nodes: config.num_nodes,
+                myid: config.id,
+                byz,
+                num_faults: config.num_faults,
+                sharing,
+                avss_context: HashMap::default(),
+            };
+
+            // Run the consensus context
+            if let Err(e) = c.run().await {
+                log::error!("Consensus error: {}", e);
+            }
+        });
+
+        Ok(exit_tx)
+    }
+
+    pub async fn run(&mut self) -> Result<()> {
+        // The process starts listening to messages in this process.
+        // First, the node sends an alive message
+        self.net.alive().await;
+        loop {
+            match self.net.next().await? {
+                Event::Message(msg) => self.process_msg(msg).await,
+                Event::Start(value) => {
+                    // The syncer starts every node, the dealer shares the value of the START message
+                    if self.myid != self.dealer() {
+                        continue;
+                    }
+                    let avss_msg: RBCSyncMsg = bincode::deserialize(&value)
+                        .expect("Unable to deserialize message received from the syncer");
+                    self.start_avss(avss_msg.id, avss_msg.msg.into_bytes()).await;
+                }
+                Event::Stop => break,
+            }
+        }
+        Ok(())
+    }
+
+    /// The node that deals in every epoch.
+    pub fn dealer(&self) -> Replica {
+        0
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt::{self, Debug};
+
+use consensus::{Erase, Share};
+use crypto::hash::Hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use serde::{Deserialize, Serialize};
+use types::Replica;
+
+/// The public part of the dealer's sharing, the length of its input and the compressed commitments of every limb.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Dealing {
+    pub length: usize,
+    pub commitments: Vec<Vec<u8>>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RowMsg {
+    /// For every limb, the receiver's row evaluations at 0, ..., t in canonical encoding
+    pub rows: Vec<Vec<Vec<u8>>>,
+    pub dealing: Dealing,
+    pub origin: Replica,
+}
+
+// A row is a share of the dealer's polynomial, so it is wiped like one and kept out of the logs
+impl Drop for RowMsg {
+    fn drop(&mut self) {
+        self.rows.erase();
+    }
+}
+
+impl Debug for RowMsg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RowMsg")
+            .field("rows", &"<redacted>")
+            .field("dealing", &self.dealing)
+            .field("origin", &self.origin)
+            .finish()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PointMsg {
+    /// One point of the receiver's column per limb, as a share at the sender's index
+    pub points: Vec<Share<FrField>>,
+    /// The dealing the sender's row matches, so a party the dealer left out can check the points
+    pub dealing: Dealing,
+    pub origin: Replica,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Vote {
+    Accept,
+    Complaint,
+}
+
+/// A party's vote on the dealer's commitments, delivered to everyone through reliable broadcast.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct VoteMsg {
+    pub voter: Replica,
+    pub vote: Vote,
+    /// Hash of the dealing the vote refers to
+    pub hash: Hash,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RBCMsg {
+    pub vote: VoteMsg,
+    pub origin: Replica,
+}
+
+/*
+this is how an epoch of the AVSS protocol works
+1. The dealer shares every limb of its input with a bivariate polynomial phi(x, y) of degree t in x and in y
+   and sends <row, phi(x, j), dealing> to party j, the row as its evaluations at 0, ..., t. The dealing holds the
+   Feldman commitments to the coefficients of phi
+2. Party j completes its row, checks it against the commitments and reliably broadcasts <accept, H(dealing)>, or
+   <complaint, H(dealing)> if the check fails
+   Votes go through Bracha's RBC: <init> from the voter, <echo> from everyone, <ready> after 2t+1 echoes
+   or t+1 readys, delivered after 2t+1 readys
+3. A party with a valid row j sends <column point, phi(i, j), dealing> to every party i
+4. Party i checks the column points against the commitments. Once 2t+1 accepts on the same dealing are delivered
+   and t+1 valid points under it arrived, it interpolates its column phi(i, y) and terminates with its share phi(i, 0)
+At least t+1 of the 2t+1 accepting parties are honest and send column points to everybody, so every honest party
+gets its share, whether or not the dealer sent it a row. Honest parties only accept once, so no two dealings get
+2t+1 accepts and all shares lie on phi(x, 0).
+*/
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum ProtMsg {
+    Row(RowMsg, usize), // Epoch
+    ColumnPoint(PointMsg, usize),
+    Init(RBCMsg, usize),
+    Echo(RBCMsg, usize),
+    Ready(RBCMsg, usize),
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use super::ProtMsg;
+use crate::context::Context;
+use types::WrapperMsg;
+
+impl Context {
+    pub(crate) async fn process_msg(&mut self, wrapper_msg: WrapperMsg<ProtMsg>) {
+        log::debug!("Received protocol msg: {:?}", wrapper_msg);
+        let msg = Arc::new(wrapper_msg.clone());
+
+        // Verify the message's authenticity before proceeding
+        if !self.net.check_proposal(msg) {
+            log::warn!(
+                "MAC Verification failed for message {:?}",
+                wrapper_msg.protmsg
+            );
+            return;
+        }
+        // Votes are counted per origin, so a node may only speak for itself
+        let origin = match &wrapper_msg.protmsg {
+            ProtMsg::Row(msg, _) => msg.origin,
+            ProtMsg::ColumnPoint(msg, _) => msg.origin,
+            ProtMsg::Init(msg, _) | ProtMsg::Echo(msg, _) | ProtMsg::Ready(msg, _) => msg.origin,
+        };
+        if origin != wrapper_msg.sender {
+            log::warn!("Node {} sent a message on behalf of node {}", wrapper_msg.sender, origin);
+            return;
+        }
+        match wrapper_msg.protmsg {
+            ProtMsg::Row(main_msg, epoch) => {
+                log::info!("Received Row for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_row(main_msg, epoch).await;
+            }
+            ProtMsg::Init(main_msg, epoch) => {
+                log::info!("Received Init for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_init(main_msg, epoch).await;
+            }
+            ProtMsg::Echo(main_msg, epoch) => {
+                log::info!("Received Echo for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_echo(main_msg, epoch).await;
+            }
+            ProtMsg::Ready(main_msg, epoch) => {
+                log::info!("Received Ready for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_ready(main_msg, epoch).await;
+            }
+            ProtMsg::ColumnPoint(main_msg, epoch) => {
+                log::info!("Received ColumnPoint for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_column_point(main_msg, epoch).await;
+            }
+        }
+    }
+}
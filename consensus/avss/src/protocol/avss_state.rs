@@ -0,0 +1,55 @@
+use std::collections::{HashMap, HashSet};
+
+use consensus::{BivariateCommitments, Share};
+use crypto::hash::Hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use types::Replica;
+
+use crate::VoteMsg;
+
+pub struct AVSSState {
+    /// The dealings this party saw, by hash, as the length of the input and the commitments of every limb
+    pub dealings: HashMap<Hash, (usize, Vec<BivariateCommitments<BLS12381Curve>>)>,
+    /// Valid points on this party's column, by dealing and sender
+    pub column_points: HashMap<Hash, HashMap<Replica, Vec<Share<FrField>>>>,
+    /// Parties that sent this party column points, each sends them once
+    pub point_senders: HashSet<Replica>,
+
+    // Reliable broadcast of the votes, every vote is its own instance
+    pub echo_senders: HashMap<VoteMsg, HashSet<Replica>>,
+    pub ready_senders: HashMap<VoteMsg, HashSet<Replica>>,
+    pub echoed: HashSet<VoteMsg>,
+    pub readied: HashSet<VoteMsg>,
+    pub delivered: HashSet<VoteMsg>,
+
+    pub dealt: bool,
+    pub voted: bool,
+    pub terminated: bool,
+}
+
+impl AVSSState {
+    pub fn new() -> AVSSState {
+        AVSSState {
+            dealings: HashMap::default(),
+            column_points: HashMap::default(),
+            point_senders: HashSet::default(),
+
+            echo_senders: HashMap::default(),
+            ready_senders: HashMap::default(),
+            echoed: HashSet::default(),
+            readied: HashSet::default(),
+            delivered: HashSet::default(),
+
+            dealt: false,
+            voted: false,
+            terminated: false,
+        }
+    }
+}
+
+impl Default for AVSSState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,86 @@
+use consensus::{Scheme, Secret, Share};
+use crypto::hash::Hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{FrElement, FrField};
+use types::WrapperMsg;
+
+use crate::{Context, Dealing, PointMsg, ProtMsg};
+
+impl Context {
+    /// Sends every node i the point phi(i, j) of its column, from this node's row j.
+    pub(crate) async fn send_column_points(
+        &mut self,
+        row: Vec<Secret<Vec<FrElement>>>,
+        hash: Hash,
+        dealing: &Dealing,
+        epoch: usize,
+    ) {
+        let sec_key_map = self.net.sec_key_map.clone();
+        for (replica, sec_key) in sec_key_map.into_iter() {
+            let points: Vec<Share<FrField>> = row
+                .iter()
+                .map(|limb_row| Share {
+                    index: self.myid as u64 + 1,
+                    value: limb_row[replica + 1].clone(),
+                    threshold: self.sharing.low.threshold,
+                    scheme: Scheme::Shamir,
+                })
+                .collect();
+            if replica == self.myid {
+                // This node's own point is on its row, so it needs no check
+                let avss_context = self.avss_context.entry(epoch).or_default();
+                avss_context.point_senders.insert(self.myid);
+                avss_context.column_points.entry(hash).or_default().insert(self.myid, points);
+                continue;
+            }
+            let msg = PointMsg {
+                points,
+                dealing: dealing.clone(),
+                origin: self.myid,
+            };
+            let wrapper_msg = WrapperMsg::new(ProtMsg::ColumnPoint(msg, epoch), self.myid, sec_key.as_slice());
+            self.net.send(replica, wrapper_msg).await;
+        }
+        self.try_complete(epoch).await;
+    }
+
+    /// Keeps the points on this node's column that match the sender's dealing, which need not be the dealing
+    /// this node got from the dealer.
+    pub async fn handle_column_point(&mut self, msg: PointMsg, epoch: usize) {
+        if !self.avss_context.entry(epoch).or_default().point_senders.insert(msg.origin) {
+            return;
+        }
+        let hash = match self.record_dealing(&msg.dealing, epoch) {
+            Some(hash) => hash,
+            None => {
+                log::warn!("Node {} sent column points under an invalid dealing for epoch {}", msg.origin, epoch);
+                return;
+            }
+        };
+        let (party, sender_party) = (self.myid as u64 + 1, msg.origin as u64 + 1);
+        let avss_context = self.avss_context.entry(epoch).or_default();
+        let (_, commitments) = &avss_context.dealings[&hash];
+        // phi(i, j) on this node's column i, from node j's row
+        let valid = msg.points.len() == commitments.len()
+            && msg
+                .points
+                .iter()
+                .zip(commitments)
+                .all(|(point, commitments)| self.sharing.verify_point(party, sender_party, &point.value, commitments));
+        if !valid {
+            log::warn!("Node {} sent invalid column points for epoch {}", msg.origin, epoch);
+            return;
+        }
+        let points = msg
+            .points
+            .into_iter()
+            .map(|point| Share {
+                index: sender_party,
+                value: point.value.clone(),
+                threshold: self.sharing.low.threshold,
+                scheme: Scheme::Shamir,
+            })
+            .collect();
+        avss_context.column_points.entry(hash).or_default().insert(msg.origin, points);
+        self.try_complete(epoch).await;
+    }
+}
@@ -0,0 +1,8 @@
+mod share;
+
+mod vote;
+
+mod column;
+
+mod avss_state;
+pub use avss_state::*;
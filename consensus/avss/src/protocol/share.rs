@@ -0,0 +1,146 @@
+use consensus::{
+    bytes_to_limbs, commitments_from_bytes, commitments_to_bytes, field_from_bytes, field_to_bytes,
+    BivariateCommitments, Rows, Secret,
+};
+use crypto::hash::{do_hash, Hash};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{FrElement, FrField};
+use types::WrapperMsg;
+
+use crate::{Context, Dealing, ProtMsg, RowMsg, Vote, VoteMsg};
+
+impl Context {
+    pub async fn start_avss(&mut self, epoch: usize, secret: Vec<u8>) {
+        let avss_context = self.avss_context.entry(epoch).or_default();
+        if avss_context.dealt {
+            return;
+        }
+        avss_context.dealt = true;
+
+        let limbs = match bytes_to_limbs::<FrField>(&secret) {
+            Ok(limbs) => limbs,
+            Err(e) => {
+                log::error!("Failed to deal the secret for epoch {}: {}", epoch, e);
+                return;
+            }
+        };
+        let (rows, commitments): (Vec<Rows<FrField>>, Vec<BivariateCommitments<BLS12381Curve>>) =
+            limbs.into_iter().map(|limb| self.sharing.deal(limb)).unzip();
+        let dealing = Dealing {
+            length: secret.len(),
+            commitments: commitments
+                .iter()
+                .map(|limb_commitments| commitments_to_bytes(&limb_commitments.concat()))
+                .collect(),
+        };
+        log::info!("Dealt a sharing of {} bytes for epoch {}", secret.len(), epoch);
+
+        // Every node gets its own row, party j is node j - 1
+        let sec_key_map = self.net.sec_key_map.clone();
+        for (replica, sec_key) in sec_key_map.into_iter() {
+            let msg = RowMsg {
+                rows: rows
+                    .iter()
+                    .map(|limb_rows| limb_rows[replica].iter().map(field_to_bytes).collect())
+                    .collect(),
+                dealing: dealing.clone(),
+                origin: self.myid,
+            };
+            if replica == self.myid {
+                self.handle_row(msg, epoch).await;
+                continue;
+            }
+            let wrapper_msg = WrapperMsg::new(ProtMsg::Row(msg, epoch), self.myid, sec_key.as_slice());
+            self.net.send(replica, wrapper_msg).await;
+        }
+    }
+
+    /// The hash votes and points refer to a dealing by.
+    pub(crate) fn dealing_hash(dealing: &Dealing) -> Hash {
+        do_hash(&bincode::serialize(dealing).expect("Failed to serialize dealing"))
+    }
+
+    /// Keeps the commitments of a dealing, t + 1 commitments of t + 1 each for every limb, if they parse.
+    pub(crate) fn record_dealing(&mut self, dealing: &Dealing, epoch: usize) -> Option<Hash> {
+        let hash = Self::dealing_hash(dealing);
+        let avss_context = self.avss_context.entry(epoch).or_default();
+        if avss_context.dealings.contains_key(&hash) {
+            return Some(hash);
+        }
+        let (high, low) = (self.sharing.high.sss.threshold, self.sharing.low.threshold);
+        let commitments = dealing
+            .commitments
+            .iter()
+            .map(|bytes| match commitments_from_bytes(bytes) {
+                Ok(commitments) if commitments.len() == high * low => {
+                    Some(commitments.chunks(high).map(|commitment| commitment.to_vec()).collect())
+                }
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        avss_context.dealings.insert(hash, (dealing.length, commitments));
+        Some(hash)
+    }
+
+    /// This node's row, completed to all parties, if it matches the commitments of the dealing.
+    fn check_row(&self, rows: &[Vec<Vec<u8>>], hash: Hash, epoch: usize) -> Option<Vec<Secret<Vec<FrElement>>>> {
+        let (_, commitments) = self.avss_context.get(&epoch)?.dealings.get(&hash)?;
+        if rows.len() != commitments.len() {
+            return None;
+        }
+        let party = self.myid as u64 + 1;
+        rows.iter()
+            .zip(commitments)
+            .map(|(row, commitments)| {
+                let row = Secret::new(
+                    row.iter()
+                        .map(|value| field_from_bytes::<FrField>(value))
+                        .collect::<Result<Vec<_>, _>>()
+                        .ok()?,
+                );
+                let row = self.sharing.complete_row(&row).ok()?;
+                self.sharing.verify_row(party, &row, commitments).then_some(row)
+            })
+            .collect()
+    }
+
+    pub async fn handle_row(&mut self, msg: RowMsg, epoch: usize) {
+        if msg.origin != self.dealer() {
+            log::warn!("Node {} is not the dealer of epoch {}", msg.origin, epoch);
+            return;
+        }
+        let avss_context = self.avss_context.entry(epoch).or_default();
+        if avss_context.voted {
+            return;
+        }
+        avss_context.voted = true;
+
+        let hash = Self::dealing_hash(&msg.dealing);
+        let row = match self.record_dealing(&msg.dealing, epoch) {
+            Some(_) => self.check_row(&msg.rows, hash, epoch),
+            None => None,
+        };
+        let vote = match row {
+            Some(row) if !self.byz => {
+                log::info!("Accepted the row of epoch {}", epoch);
+                self.send_column_points(row, hash, &msg.dealing, epoch).await;
+                Vote::Accept
+            }
+            // Complains about a valid row and keeps its column points, which doesn't keep the others from their shares
+            Some(_) => Vote::Complaint,
+            None => {
+                log::warn!("Rejected the row of epoch {}", epoch);
+                Vote::Complaint
+            }
+        };
+        self.start_rbc(
+            VoteMsg {
+                voter: self.myid,
+                vote,
+                hash,
+            },
+            epoch,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::{HashMap, HashSet};
+
+use async_recursion::async_recursion;
+use consensus::{ByteShare, Scheme, Share};
+use crypto::hash::Hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{FrElement, FrField};
+use types::{Replica, ShareSyncMsg};
+
+use crate::{Context, ProtMsg, RBCMsg, Vote, VoteMsg};
+
+impl Context {
+    /// Reliably broadcasts this node's vote, starting with an <init> to everyone.
+    pub async fn start_rbc(&mut self, vote: VoteMsg, epoch: usize) {
+        let msg = RBCMsg {
+            vote,
+            origin: self.myid,
+        };
+        self.net.broadcast(ProtMsg::Init(msg.clone(), epoch)).await;
+        self.handle_init(msg, epoch).await;
+    }
+
+    #[async_recursion]
+    pub async fn handle_init(&mut self, msg: RBCMsg, epoch: usize) {
+        if msg.vote.voter != msg.origin {
+            log::warn!("Node {} sent an init for the vote of node {}", msg.origin, msg.vote.voter);
+            return;
+        }
+        let avss_context = self.avss_context.entry(epoch).or_default();
+        if !avss_context.echoed.insert(msg.vote.clone()) {
+            return;
+        }
+        let echo = RBCMsg {
+            vote: msg.vote,
+            origin: self.myid,
+        };
+        self.net.broadcast(ProtMsg::Echo(echo.clone(), epoch)).await;
+        self.handle_echo(echo, epoch).await;
+    }
+
+    #[async_recursion]
+    pub async fn handle_echo(&mut self, msg: RBCMsg, epoch: usize) {
+        let avss_context = self.avss_context.entry(epoch).or_default();
+        let senders = avss_context.echo_senders.entry(msg.vote.clone()).or_default();
+        if !senders.insert(msg.origin) {
+            return;
+        }
+        if senders.len() > 2 * self.num_faults {
+            self.send_ready(msg.vote, epoch).await;
+        }
+    }
+
+    #[async_recursion]
+    pub async fn send_ready(&mut self, vote: VoteMsg, epoch: usize) {
+        let avss_context = self.avss_context.entry(epoch).or_default();
+        if !avss_context.readied.insert(vote.clone()) {
+            return;
+        }
+        let ready = RBCMsg {
+            vote,
+            origin: self.myid,
+        };
+        self.net.broadcast(ProtMsg::Ready(ready.clone(), epoch)).await;
+        self.handle_ready(ready, epoch).await;
+    }
+
+    #[async_recursion]
+    pub async fn handle_ready(&mut self, msg: RBCMsg, epoch: usize) {
+        let avss_context = self.avss_context.entry(epoch).or_default();
+        let senders = avss_context.ready_senders.entry(msg.vote.clone()).or_default();
+        if !senders.insert(msg.origin) {
+            return;
+        }
+        // t + 1 readys include an honest node's, so this node can send its own
+        if senders.len() > self.num_faults {
+            self.send_ready(msg.vote.clone(), epoch).await;
+        }
+
+        let avss_context = self.avss_context.entry(epoch).or_default();
+        let delivered = avss_context.ready_senders[&msg.vote].len() > 2 * self.num_faults
+            && avss_context.delivered.insert(msg.vote.clone());
+        if delivered {
+            log::info!("Delivered the {:?} of node {} for epoch {}", msg.vote.vote, msg.vote.voter, epoch);
+            self.try_complete(epoch).await;
+        }
+    }
+
+    /// Terminates once 2t + 1 parties accepted the same dealing and t + 1 points on this node's column under it
+    /// arrived, the share is the column at 0.
+    #[async_recursion]
+    pub async fn try_complete(&mut self, epoch: usize) {
+        let avss_context = self.avss_context.entry(epoch).or_default();
+        if avss_context.terminated {
+            return;
+        }
+        // Honest parties accept once, so at most one dealing gets 2t + 1 accepts
+        let mut accepts: HashMap<Hash, HashSet<Replica>> = HashMap::default();
+        for vote in avss_context.delivered.iter().filter(|vote| vote.vote == Vote::Accept) {
+            accepts.entry(vote.hash).or_default().insert(vote.voter);
+        }
+        let hash = match accepts
+            .into_iter()
+            .find(|(_, voters)| voters.len() > 2 * self.num_faults)
+        {
+            Some((hash, _)) => hash,
+            None => return,
+        };
+        // At least t + 1 of the accepting parties are honest and send this node its points
+        let points = match avss_context.column_points.get(&hash) {
+            Some(points) if points.len() >= self.sharing.low.threshold => points,
+            _ => return,
+        };
+        let (length, commitments) = &avss_context.dealings[&hash];
+        let values: Option<Vec<FrElement>> = (0..commitments.len())
+            .map(|limb| {
+                let points: Vec<Share<FrField>> = points.values().map(|points| points[limb].clone()).collect();
+                let column = self.sharing.interpolate_column(&points).ok()?;
+                Some(column.evaluate(&FrElement::zero()))
+            })
+            .collect();
+        let values = match values {
+            Some(values) => values,
+            None => {
+                log::error!("Failed to interpolate the column for epoch {}", epoch);
+                return;
+            }
+        };
+        let share = ByteShare {
+            index: self.myid as u64 + 1,
+            // All shares of one epoch belong to the same split
+            split_id: epoch as u64,
+            length: *length,
+            values,
+            threshold: self.sharing.high.sss.threshold,
+            scheme: Scheme::Shamir,
+        };
+        avss_context.terminated = true;
+        log::info!("Holds a verified share for epoch {}", epoch);
+        let sync_msg = ShareSyncMsg {
+            id: epoch,
+            share: share.to_bytes(),
+        };
+        self.net.terminate_shared(bincode::serialize(&sync_msg).expect("Failed to serialize share")).await;
+    }
+}
@@ -1,144 +1,168 @@
-use consensus::{LargeField, LargeFieldSSS, ShamirSecretSharing, ShamirSecretSharingFFT};
+use consensus::{LargeField, LargeFieldSSS, SecretSharingScheme, ShamirSecretSharing, ShamirSecretSharingFFT};
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
 use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
 use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
 use lambdaworks_math::unsigned_integer::element::UnsignedInteger;
 use num_bigint_dig::{BigInt, Sign};
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 use std::time::Duration;
 
-fn bench_shamir_secret_sharing(c: &mut Criterion) {
-    type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
-    let secret = LargeField::new(UnsignedInteger::from(1234u64));
+fn large_field_sss() -> LargeFieldSSS {
+    let large_field_prime: BigInt = BigInt::parse_bytes(
+        b"115792088158918333131516597762172392628570465465856793992332884130307292657121",
+        10,
+    )
+    .unwrap();
+    LargeFieldSSS::new(16, 32, large_field_prime).unwrap()
+}
+
+// The benchmarks below are written once against `SecretSharingScheme` and run for every backend
+
+fn bench_split<S: SecretSharingScheme>(c: &mut Criterion, name: &str, sss: &S, secret: S::Field) {
+    c.bench_function(name, |b| b.iter(|| black_box(sss.split(secret.clone()).unwrap())));
+}
+
+fn bench_recover<S: SecretSharingScheme>(c: &mut Criterion, name: &str, sss: &S, secret: S::Field) {
+    let shares = sss.split(secret.clone()).unwrap();
 
-    let sss = ShamirSecretSharing {
-        share_amount: 32,
-        threshold: 16,
-    };
+    // use the shares of all even parties from 2 to 32
+    let shares_to_use: Vec<S::Share> = shares
+        .iter()
+        .filter(|share| S::share_index(share) % 2 == 0)
+        .cloned()
+        .collect();
+    c.bench_function(name, |b| {
+        b.iter(|| {
+            let secret_recovered = sss.recover(&shares_to_use).unwrap();
+            assert_eq!(secret, secret_recovered);
+        })
+    });
+}
 
-    c.bench_function("Secret Generation using Lambdaworks", |b| {
+// generate shares, then fill the evaluations from the secret and the first t - 1 shares and check that they match
+fn bench_fill<S: SecretSharingScheme>(c: &mut Criterion, name: &str, sss: &S, secret: S::Field) {
+    c.bench_function(name, |b| {
         b.iter(|| {
-            let shares = sss.split(secret);
+            let shares: Vec<S::Field> = sss
+                .split(secret.clone())
+                .unwrap()
+                .iter()
+                .map(|share| S::share_value(share).clone())
+                .collect();
+            let mut shares_to_use = vec![secret.clone()];
+            shares_to_use.extend(shares[0..sss.threshold() - 1].to_vec());
+
+            sss.fill_evaluation_at_all_points(&mut shares_to_use).unwrap();
+            // assert first element of shares_to_use is equal to secret
+            assert_eq!(shares_to_use[0], secret);
+            // remove shares_to_use[0]
+            shares_to_use.remove(0);
+            // assert shares_to_use is equal to shares
+            assert_eq!(shares_to_use, shares);
         })
     });
 }
 
-fn bench_shamir_secret_sharing_fft(c: &mut Criterion) {
+fn bench_shamir_secret_sharing(c: &mut Criterion) {
     type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
     let secret = LargeField::new(UnsignedInteger::from(1234u64));
 
-    let sss = ShamirSecretSharingFFT {
-        share_amount: 32,
-        threshold: 16,
-        roots_of_unity: ShamirSecretSharingFFT::gen_roots_of_unity(32),
-    };
+    let sss = ShamirSecretSharing::new(16, 32).unwrap();
+    let sss_fft = ShamirSecretSharingFFT::new(16, 32).unwrap();
+    bench_split(c, "Secret Generation using Lambdaworks", &sss, secret);
+    bench_split(c, "Secret Generation using FFT", &sss_fft, secret);
+    bench_split(c, "Secret Generation usingLarge Field", &large_field_sss(), BigInt::from(1234u64));
+}
+
+fn bench_split_many_fft(c: &mut Criterion) {
+    type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
+    let secrets: Vec<LargeField> = (0..1000u64).map(LargeField::from).collect();
+    let sss = ShamirSecretSharingFFT::new(16, 32).unwrap();
 
-    c.bench_function("Secret Generation using FFT", |b| {
+    c.bench_function("Secret Generation using FFT, 1000 secrets one by one", |b| {
         b.iter(|| {
-            let shares = sss.split(secret);
+            for secret in &secrets {
+                black_box(sss.split(*secret).unwrap());
+            }
         })
     });
+    c.bench_function("Secret Generation using FFT, 1000 secrets with split_many", |b| {
+        b.iter(|| black_box(sss.split_many(&secrets)))
+    });
 }
 
-fn bench_shamir_secret_sharing_largefield(c: &mut Criterion) {
-    let secret: BigInt = BigInt::parse_bytes(b"1234", 10).unwrap();
+fn bench_split_batch(c: &mut Criterion) {
+    type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
+    let secrets: Vec<LargeField> = (0..1000u64).map(LargeField::from).collect();
+    let sss = ShamirSecretSharing::new(16, 32).unwrap();
+    let sss_fft = ShamirSecretSharingFFT::new(16, 32).unwrap();
 
-    let large_field_prime: BigInt = BigInt::parse_bytes(
-        b"115792088158918333131516597762172392628570465465856793992332884130307292657121",
-        10,
-    )
-    .unwrap();
-    let sss = LargeFieldSSS::new(16, 32, large_field_prime);
-    c.bench_function("Secret Generation usingLarge Field", |b| {
-        b.iter(|| {
-            let shares = sss.split(secret.clone());
-        })
+    c.bench_function("Secret Generation using Lambdaworks, 1000 secrets with split_batch", |b| {
+        b.iter(|| black_box(sss.split_batch(&secrets)))
+    });
+    c.bench_function("Secret Generation using FFT, 1000 secrets with split_batch", |b| {
+        b.iter(|| black_box(sss_fft.split_batch(&secrets)))
     });
 }
 
 fn bench_shamir_secret_sharing_reconstruct(c: &mut Criterion) {
     type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
     let secret = LargeField::new(UnsignedInteger::from(1234u64));
-    let sss = ShamirSecretSharing {
-        share_amount: 32,
-        threshold: 16,
-    };
-    let shares = sss.split(secret);
-
-    // let shares to use x be all even numbers from 2 to 32
-    let shares_to_use_x: Vec<LargeField> = (2..=32)
-        .filter(|x| x % 2 == 0)
-        .map(|x| LargeField::new(UnsignedInteger::from(x as u64)))
-        .collect();
-    let shares_to_use_y: Vec<LargeField> = shares
-        .iter()
-        .enumerate()
-        .filter(|(i, _)| i % 2 == 1)
-        .map(|(_, y)| *y)
-        .collect();
-    c.bench_function("Reconstructing Secret using Lambdaworks", |b| {
-        b.iter(|| {
-            let poly_2 = sss.reconstructing(&shares_to_use_x, &shares_to_use_y);
-            let secret_recovered = sss.recover(&poly_2);
-            assert_eq!(secret, secret_recovered);
-        })
-    });
+
+    let sss = ShamirSecretSharing::new(16, 32).unwrap();
+    let sss_fft = ShamirSecretSharingFFT::new(16, 32).unwrap();
+    bench_recover(c, "Reconstructing Secret using Lambdaworks", &sss, secret);
+    bench_recover(c, "Reconstructing Secret using FFT", &sss_fft, secret);
+    bench_recover(c, "Reconstructing Secret Large Field", &large_field_sss(), BigInt::from(1234u64));
 }
 
-fn bench_shamir_secret_sharing_reconstruct_fft(c: &mut Criterion) {
+fn bench_erasure_decoding(c: &mut Criterion) {
     type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
     let secret = LargeField::new(UnsignedInteger::from(1234u64));
-    let sss = ShamirSecretSharingFFT {
-        share_amount: 32,
-        threshold: 16,
-        roots_of_unity: ShamirSecretSharingFFT::gen_roots_of_unity(32),
-    };
-    let shares = sss.split(secret);
-
-    // let shares to use x be all even numbers from 2 to 32
-    let shares_to_use_x: Vec<u64> = (2..=32)
-        .filter(|x| x % 2 == 0)
-        .map(|x| (x as u64))
-        .collect();
-    let shares_to_use_y: Vec<LargeField> = shares
-        .iter()
-        .enumerate()
-        .filter(|(i, _)| i % 2 == 1)
-        .map(|(_, y)| *y)
-        .collect();
-    c.bench_function("Reconstructing Secret using FFT", |b| {
-        b.iter(|| {
-            let poly_2 = sss.reconstructing(&shares_to_use_x, &shares_to_use_y);
-            let secret_recovered = sss.recover(&poly_2);
-            assert_eq!(secret, secret_recovered);
-        })
-    });
-}
 
-fn bench_shamir_secret_sharing_reconstruct_largefield(c: &mut Criterion) {
-    let secret: BigInt = BigInt::parse_bytes(b"1234", 10).unwrap();
-    let large_field_prime: BigInt = BigInt::parse_bytes(
-        b"115792088158918333131516597762172392628570465465856793992332884130307292657121",
-        10,
-    )
-    .unwrap();
-    let sss = LargeFieldSSS::new(16, 32, large_field_prime);
-    let shares = sss.split(secret.clone());
-
-    // let shares to use x be all even numbers from 2 to 32
-    let mut shares_to_use_x: Vec<(usize, BigInt)> = Vec::new();
-    for i in 2..=32 {
-        if i%2 == 0 {
-            shares_to_use_x.push(shares[i-1].clone());
+    // Lagrange interpolation against the erasure decoder, from the shares of the even parties
+    for (threshold, share_amount) in [(16, 32), (512, 1024)] {
+        let sss = ShamirSecretSharingFFT::new(threshold, share_amount).unwrap();
+        let shares = sss.split(secret).unwrap();
+        let shares_to_use: Vec<_> = shares.iter().filter(|share| share.index % 2 == 0).cloned().collect();
+        let x: Vec<u64> = shares_to_use.iter().map(|share| share.index).collect();
+        let y: Vec<LargeField> = shares_to_use.iter().map(|share| share.value).collect();
+
+        let mut group: BenchmarkGroup<_> = c.benchmark_group(format!("Interpolating (n = {})", share_amount));
+        if share_amount >= 1024 {
+            // Lagrange takes seconds per iteration at this size
+            group.sample_size(10);
         }
-        
+        group.bench_function("with Lagrange", |b| b.iter(|| sss.interpolate_parties(&x, &y).unwrap()));
+        group.bench_function("with the erasure decoder", |b| {
+            b.iter(|| sss.interpolate_on_domain(&x, &y).unwrap())
+        });
+        group.finish();
     }
+}
 
-    c.bench_function("Reconstructing Secret Large Field", |b| {
+fn bench_shamir_secret_sharing_reconstructor(c: &mut Criterion) {
+    type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
+    let secret = LargeField::new(UnsignedInteger::from(1234u64));
+    let sss = ShamirSecretSharing::new(16, 32).unwrap();
+    let shares = sss.split(secret).unwrap();
+
+    // use the shares of all even parties from 2 to 32, the weights are computed once outside the loop
+    let shares_to_use: Vec<_> = shares
+        .iter()
+        .filter(|share| share.index % 2 == 0)
+        .cloned()
+        .collect();
+    let indices: Vec<u64> = shares_to_use.iter().map(|share| share.index).collect();
+    let reconstructor = sss.reconstructor(&indices).unwrap();
+    c.bench_function("Reconstructing Secret using precomputed weights", |b| {
         b.iter(|| {
-            let secret_recovered = sss.recover(&shares_to_use_x);
-            assert_eq!(secret, secret_recovered);
+            let secret_recovered = reconstructor.recover(&shares_to_use).unwrap();
+            assert_eq!(secret, *secret_recovered);
         })
     });
 }
@@ -147,103 +171,65 @@ fn bench_fill_evaluation_at_all_points(c: &mut Criterion) {
     type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
     let secret = LargeField::new(UnsignedInteger::from(1234u64));
 
-    let sss = ShamirSecretSharing {
-        share_amount: 32,
-        threshold: 16,
-    };
-
-    // generate polynomial, generate shares, then create a new vector with the first t+1 shares and the secret, and then verify that its equal to the shares polynomial after fill evals at all points
-    c.bench_function("Fill Evaluation at all points using Lambdaworks", |b| {
-        b.iter(|| {
-            let polynomial = sss.sample_polynomial(secret);
-            let shares = sss.generating_shares(&polynomial);
-            let mut shares_to_use = Vec::new();
-            shares_to_use.push(secret);
-            shares_to_use.extend(shares[0..sss.threshold - 1].to_vec());
-
-            sss.fill_evaluation_at_all_points(&mut shares_to_use);
-            // remove shares_to_use[0]
-            shares_to_use.remove(0);
-            // assert shares_to_use is equal to shares
-            assert_eq!(shares_to_use, shares);
-        })
-    });
+    let sss = ShamirSecretSharing::new(16, 32).unwrap();
+    let sss_fft = ShamirSecretSharingFFT::new(16, 32).unwrap();
+    bench_fill(c, "Fill Evaluation at all points using Lambdaworks", &sss, secret);
+    bench_fill(c, "Fill Evaluation at all points using FFT", &sss_fft, secret);
+    bench_fill(c, "Fill Evaluation at all points using Large Field", &large_field_sss(), BigInt::from(1234u64));
 }
 
 fn bench_fill_evaluation_at_all_points_fft(c: &mut Criterion) {
     type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
     let secret = LargeField::new(UnsignedInteger::from(1234u64));
 
-    let sss = ShamirSecretSharingFFT {
-        share_amount: 32,
-        threshold: 16,
-        roots_of_unity: ShamirSecretSharingFFT::gen_roots_of_unity(32),
-    };
+    let sss = ShamirSecretSharingFFT::new(16, 32).unwrap();
 
-    // generate polynomial, generate shares, then create a new vector with the first t+1 shares and the secret, and then verify that its equal to the shares polynomial after fill evals at all points
-
-    c.bench_function("Fill Evaluation at all points using FFT", |b| {
+    // interpolate from the first t shares on the roots-of-unity domain, without the secret
+    c.bench_function("Fill Evaluation at all points using FFT interpolation", |b| {
         b.iter(|| {
             let polynomial = sss.sample_polynomial(secret);
-            let shares = sss.generating_shares(&polynomial);
-            let mut shares_to_use = Vec::new();
-            shares_to_use.push(secret);
-            shares_to_use.extend(shares[0..sss.threshold - 1].to_vec());
+            let shares = sss.evaluations(&polynomial);
+            let mut shares_to_use = shares[0..sss.threshold].to_vec();
 
-            sss.fill_evaluation_at_all_points(&mut shares_to_use);
-            // assert first element of shares_to_use is equal to secret
-            assert_eq!(shares_to_use[0], secret);
-            // remove shares_to_use[0]
-            shares_to_use.remove(0);
-            // assert shares_to_use is equal to shares
+            sss.fill_evaluation_at_all_points_fft(&mut shares_to_use).unwrap();
             assert_eq!(shares_to_use, shares);
         })
     });
 }
 
-fn bench_fill_evaluation_at_all_points_largefield(c: &mut Criterion) {
-    let secret: BigInt = BigInt::parse_bytes(b"1234", 10).unwrap();
-    let large_field_prime: BigInt = BigInt::parse_bytes(
-        b"115792088158918333131516597762172392628570465465856793992332884130307292657121",
-        10,
-    )
-    .unwrap();
+fn bench_share_generation_in_field<F: IsFFTField + IsPrimeField>(c: &mut Criterion, field_name: &str) {
+    let secret = FieldElement::<F>::from(1234u64);
+    let sss = ShamirSecretSharing::<F>::new(16, 32).unwrap();
+    let sss_fft = ShamirSecretSharingFFT::<F>::new(16, 32).unwrap();
 
-    let sss = LargeFieldSSS::new(16, 32, large_field_prime);
-
-    c.bench_function("Fill Evaluation at all points using Large Field", |b| {
+    c.bench_function(&format!("Secret Generation over {}", field_name), |b| {
         b.iter(|| {
-            let mut shares = Vec::new();
-                let temp = sss.split(secret.clone()); 
-                for i in 0..temp.len() {
-                    shares.push(temp[i].1.clone());
-                }
-            
-            let mut shares_to_use = Vec::new();
-            shares_to_use.push(secret.clone());
-            shares_to_use.extend(shares[0..sss.threshold-1].to_vec());
-
-            sss.fill_evaluation_at_all_points(&mut shares_to_use);
-            // assert first element of shares_to_use is equal to secret
-            assert_eq!(shares_to_use[0], secret);
-            // remove shares_to_use[0]
-            shares_to_use.remove(0);
-            // assert shares_to_use is equal to shares
-            assert_eq!(shares_to_use, shares);
+            let _shares = sss.split(secret.clone());
+        })
+    });
+    c.bench_function(&format!("Secret Generation using FFT over {}", field_name), |b| {
+        b.iter(|| {
+            let _shares = sss_fft.split(secret.clone());
         })
     });
 }
 
+fn bench_shamir_secret_sharing_fields(c: &mut Criterion) {
+    bench_share_generation_in_field::<Stark252PrimeField>(c, "Stark252");
+    bench_share_generation_in_field::<Babybear31PrimeField>(c, "BabyBear");
+    bench_share_generation_in_field::<FrField>(c, "BLS12-381 Fr");
+}
+
 criterion_group!(
     benches,
+    bench_shamir_secret_sharing_fields,
     bench_shamir_secret_sharing,
-    bench_shamir_secret_sharing_fft,
-    bench_shamir_secret_sharing_largefield,
+    bench_split_many_fft,
+    bench_split_batch,
     bench_shamir_secret_sharing_reconstruct,
-    bench_shamir_secret_sharing_reconstruct_fft,
-    bench_shamir_secret_sharing_reconstruct_largefield,
+    bench_shamir_secret_sharing_reconstructor,
+    bench_erasure_decoding,
     bench_fill_evaluation_at_all_points,
-    bench_fill_evaluation_at_all_points_fft,
-    bench_fill_evaluation_at_all_points_largefield
+    bench_fill_evaluation_at_all_points_fft
 );
 criterion_main!(benches);
@@ -0,0 +1,43 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use futures_util::SinkExt;
+use network::Acknowledgement;
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+
+use types::WrapperMsg;
+
+#[derive(Debug, Clone)]
+pub struct Handler<M: Debug + Serialize + Clone> {
+    consensus_tx: UnboundedSender<WrapperMsg<M>>,
+}
+
+impl<M: Debug + Serialize + Clone> Handler<M> {
+    pub fn new(consensus_tx: UnboundedSender<WrapperMsg<M>>) -> Self {
+        Self { consensus_tx }
+    }
+}
+
+#[async_trait]
+impl<M> network::Handler<Acknowledgement, WrapperMsg<M>> for Handler<M>
+where
+    M: Debug + Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    async fn dispatch(
+        &self,
+        msg: WrapperMsg<M>,
+        writer: &mut network::Writer<Acknowledgement>,
+    ) {
+        // Forward the message
+        self.consensus_tx
+            .send(msg)
+            .expect("Failed to send message to the consensus channel");
+
+        // Acknowledge
+        writer
+            .send(Acknowledgement::Pong)
+            .await
+            .expect("Failed to send an acknowledgement");
+    }
+}
@@ -0,0 +1,5 @@
+mod net;
+pub use net::*;
+
+mod handlers;
+pub use handlers::*;
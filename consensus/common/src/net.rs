@@ -0,0 +1,233 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    net::{SocketAddr, SocketAddrV4},
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Result};
+use config::Node;
+use crypto::hash::verf_mac;
+use fnv::FnvHashMap;
+use network::{
+    plaintcp::{CancelHandler, TcpReceiver, TcpReliableSender},
+    Acknowledgement,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::sync::{
+    mpsc::{unbounded_channel, UnboundedReceiver},
+    oneshot,
+};
+use types::{Replica, SyncMsg, SyncState, WrapperMsg};
+
+use super::{Handler, SyncHandler};
+
+/// What a protocol's main loop has to act on next.
+pub enum Event<M: Debug + Serialize + Clone> {
+    /// A message from another node, not yet authenticated
+    Message(WrapperMsg<M>),
+    /// A START message from the syncer, with its value
+    Start(Vec<u8>),
+    /// The syncer or the exit handle stopped the node
+    Stop,
+}
+
+/// The networking context every protocol runs on: the channels to the other nodes and to the syncer.
+pub struct Net<M: Debug + Serialize + Clone> {
+    pub net_send: TcpReliableSender<Replica, WrapperMsg<M>, Acknowledgement>,
+    pub net_recv: UnboundedReceiver<WrapperMsg<M>>,
+    pub sync_send: TcpReliableSender<Replica, SyncMsg, Acknowledgement>,
+    pub sync_recv: UnboundedReceiver<SyncMsg>,
+    pub myid: Replica,
+    pub(crate) byz: bool,
+
+    /// Secret Key map
+    pub sec_key_map: HashMap<Replica, Vec<u8>>,
+
+    /// Cancel Handlers
+    pub cancel_handlers: HashMap<u64, Vec<CancelHandler<Acknowledgement>>>,
+    exit_rx: oneshot::Receiver<()>,
+}
+
+impl<M> Net<M>
+where
+    M: Debug + Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    pub fn spawn(config: &Node, byz: bool) -> (Self, oneshot::Sender<()>) {
+        let mut consensus_addrs: FnvHashMap<Replica, SocketAddr> = FnvHashMap::default();
+        for (replica, address) in config.net_map.iter() {
+            let address: SocketAddr = address.parse().expect("Unable to parse address");
+            consensus_addrs.insert(*replica, address);
+        }
+        let my_port = consensus_addrs.get(&config.id).unwrap();
+        let my_address = to_socket_address("0.0.0.0", my_port.port());
+        let mut syncer_map: FnvHashMap<Replica, SocketAddr> = FnvHashMap::default();
+        syncer_map.insert(0, config.client_addr);
+
+        // Setup networking
+        let (tx_net_to_consensus, rx_net_to_consensus) = unbounded_channel();
+        TcpReceiver::<Acknowledgement, WrapperMsg<M>, _>::spawn(
+            my_address,
+            Handler::new(tx_net_to_consensus),
+        );
+
+        let syncer_listen_port = config.client_port;
+        let syncer_l_address = to_socket_address("0.0.0.0", syncer_listen_port);
+
+        // The server must listen to the client's messages on some port that is not being used to listen to other servers
+        let (tx_net_to_client, rx_net_from_client) = unbounded_channel();
+        TcpReceiver::<Acknowledgement, SyncMsg, _>::spawn(
+            syncer_l_address,
+            SyncHandler::new(tx_net_to_client),
+        );
+
+        let consensus_net =
+            TcpReliableSender::<Replica, WrapperMsg<M>, Acknowledgement>::with_peers(
+                consensus_addrs.clone(),
+            );
+        let sync_net =
+            TcpReliableSender::<Replica, SyncMsg, Acknowledgement>::with_peers(syncer_map);
+        let (exit_tx, exit_rx) = oneshot::channel();
+
+        let net = Net {
+            net_send: consensus_net,
+            net_recv: rx_net_to_consensus,
+            sync_send: sync_net,
+            sync_recv: rx_net_from_client,
+            myid: config.id,
+            byz,
+            // Populate secret keys from config
+            sec_key_map: config.sk_map.clone().into_iter().collect(),
+            cancel_handlers: HashMap::default(),
+            exit_rx,
+        };
+        (net, exit_tx)
+    }
+
+    pub async fn broadcast(&mut self, protmsg: M) {
+        let sec_key_map = self.sec_key_map.clone();
+        for (replica, sec_key) in sec_key_map.into_iter() {
+            if self.byz && replica % 2 == 0 {
+                // Simulates a crash fault
+                continue;
+            }
+            if replica != self.myid {
+                let wrapper_msg = WrapperMsg::new(protmsg.clone(), self.myid, sec_key.as_slice());
+                let cancel_handler: CancelHandler<Acknowledgement> =
+                    self.net_send.send(replica, wrapper_msg).await;
+                self.add_cancel_handler(cancel_handler);
+            }
+        }
+    }
+
+    pub fn add_cancel_handler(&mut self, canc: CancelHandler<Acknowledgement>) {
+        self.cancel_handlers.entry(0).or_default().push(canc);
+    }
+
+    pub async fn send(&mut self, replica: Replica, wrapper_msg: WrapperMsg<M>) {
+        let cancel_handler: CancelHandler<Acknowledgement> =
+            self.net_send.send(replica, wrapper_msg).await;
+        self.add_cancel_handler(cancel_handler);
+    }
+
+    // This function verifies the Message Authentication Code (MAC) of a sent message
+    // A node cannot impersonate as another node because of MACs
+    pub fn check_proposal(&self, wrapper_msg: Arc<WrapperMsg<M>>) -> bool {
+        // validate MAC
+        let byte_val =
+            bincode::serialize(&wrapper_msg.protmsg).expect("Failed to serialize object");
+        let sec_key = match self.sec_key_map.get(&wrapper_msg.clone().sender) {
+            Some(val) => val,
+            None => {
+                panic!("Secret key not available, this shouldn't happen")
+            }
+        };
+        if !verf_mac(&byte_val, sec_key.as_slice(), &wrapper_msg.mac) {
+            log::warn!("MAC Verification failed.");
+            return false;
+        }
+        true
+    }
+
+    async fn sync(&mut self, state: SyncState, value: Vec<u8>) {
+        let cancel_handler = self
+            .sync_send
+            .send(
+                0,
+                SyncMsg {
+                    sender: self.myid,
+                    state,
+                    value,
+                },
+            )
+            .await;
+        self.add_cancel_handler(cancel_handler);
+    }
+
+    // The node tells the syncer it is up before it starts listening to messages
+    pub async fn alive(&mut self) {
+        self.sync(SyncState::ALIVE, "".to_string().into_bytes()).await;
+    }
+
+    // Invoke this function once you terminate the protocol
+    pub async fn terminate(&mut self, data: Vec<u8>) {
+        self.sync(SyncState::COMPLETED, data).await;
+    }
+
+    // Invoke this function once you terminate a sharing, the syncer reconstructs from the shares it gets
+    pub async fn terminate_shared(&mut self, data: Vec<u8>) {
+        self.sync(SyncState::SHARED, data).await;
+    }
+
+    /// Waits for the next message from the other nodes or the syncer, or for the exit signal.
+    pub async fn next(&mut self) -> Result<Event<M>> {
+        loop {
+            tokio::select! {
+                // Receive exit handlers
+                exit_val = &mut self.exit_rx => {
+                    exit_val.map_err(anyhow::Error::new)?;
+                    log::info!("Termination signal received by the server. Exiting.");
+                    return Ok(Event::Stop);
+                },
+                msg = self.net_recv.recv() => {
+                    // Received messages are processed here
+                    log::debug!("Got a consensus message from the network: {:?}", msg);
+                    let msg = msg.ok_or_else(||
+                        anyhow!("Networking layer has closed")
+                    )?;
+                    return Ok(Event::Message(msg));
+                },
+                sync_msg = self.sync_recv.recv() =>{
+                    let sync_msg = sync_msg.ok_or_else(||
+                        anyhow!("Networking layer has closed")
+                    )?;
+                    match sync_msg.state {
+                        SyncState::START =>{
+                            log::error!("Consensus Start time: {:?}", SystemTime::now()
+                                .duration_since(UNIX_EPOCH)
+                                .unwrap()
+                                .as_millis());
+                            return Ok(Event::Start(sync_msg.value));
+                        },
+                        SyncState::STOP =>{
+                            // Code used for internal purposes
+                            log::error!("Consensus Stop time: {:?}", SystemTime::now()
+                                .duration_since(UNIX_EPOCH)
+                                .unwrap()
+                                .as_millis());
+                            log::info!("Termination signal received by the server. Exiting.");
+                            return Ok(Event::Stop);
+                        },
+                        _=>{}
+                    }
+                },
+            };
+        }
+    }
+}
+
+pub fn to_socket_address(ip_str: &str, port: u16) -> SocketAddr {
+    let addr = SocketAddrV4::new(ip_str.parse().unwrap(), port);
+    addr.into()
+}
@@ -20,7 +20,7 @@ use tokio::sync::{
 use types::{Replica, SyncMsg, SyncState};
 
 
-use super::{Handler, ProtMsg, RBCState, SyncHandler};
+use super::{ErasureRBC, Handler, ProtMsg, RBCState, SyncHandler};
 
 use types::WrapperMsg;
 
@@ -44,6 +44,7 @@ pub struct Context {
     pub cancel_handlers: HashMap<u64, Vec<CancelHandler<Acknowledgement>>>,
     exit_rx: oneshot::Receiver<()>,
     // Add your custom fields here
+    pub rbc: ErasureRBC,
     pub rbc_context: HashMap<usize, RBCState>,
     pub max_id: usize,
 }
@@ -100,6 +101,7 @@ impl Context {
                 cancel_handlers: HashMap::default(),
                 exit_rx: exit_rx,
                 inp_message: message,
+                rbc: ErasureRBC::new(config.id, config.num_nodes, config.num_faults),
                 rbc_context: HashMap::default(),
                 max_id: rbc_start_id,
             };
@@ -36,7 +36,7 @@ this is how the rbc protocol works
 *   a. Hash m -> h = H(m)
 *   b. Use reed solomon encoding for m. f = f(1, 2, 3, ..., n). divide m into t+1 blocks, make f = t *      degree polynomial
 * 3. <ECHO, f(i), h> to party i
-* 4. On receiving n - t same values from n - t different nodes, send
+* 4. On receiving n - t same values from n - t different nodes, or t + 1 of them and t + 1 <Ready, h>, send
 *    <Ready, f(your own fragment), h> to everyone
 * 5. As you receive fragments in the form of Ready messages, start error correcting after receibing 2t+1 * fragments. When error correction passes, output message and terminate.
 */
@@ -0,0 +1,161 @@
+use crypto::hash::{do_hash, Hash};
+use reed_solomon_rs::fec::fec::*;
+use types::Replica;
+
+use crate::{RBCState, ShareMsg};
+
+// The bytes of the length the message is prefixed with before encoding
+const LENGTH_PREFIX: usize = 8;
+
+/// The steps of Das et al.'s erasure-coded RBC, without the networking, so every protocol that reliably
+/// broadcasts a large message drives the same code. The caller sends what the steps return.
+#[derive(Clone, Debug)]
+pub struct ErasureRBC {
+    pub myid: Replica,
+    pub num_nodes: usize,
+    pub num_faults: usize,
+}
+
+impl ErasureRBC {
+    pub fn new(myid: Replica, num_nodes: usize, num_faults: usize) -> ErasureRBC {
+        ErasureRBC {
+            myid,
+            num_nodes,
+            num_faults,
+        }
+    }
+
+    // The fragments of a message, any t + 1 of the n decode it
+    fn fec(&self) -> Option<FEC> {
+        match FEC::new(self.num_faults + 1, self.num_nodes) {
+            Ok(f) => Some(f),
+            Err(e) => {
+                log::info!("FEC initialization failed with error: {:?}", e);
+                None
+            }
+        }
+    }
+
+    /// The hash of the encoded message and the fragment of every node, the i-th for node i. The encoder takes a
+    /// multiple of t + 1 bytes, so the message is prefixed with its length and padded with zeros, and `ready`
+    /// strips both before delivering it.
+    pub fn encode(&self, message: Vec<u8>) -> Option<(Hash, Vec<Share>)> {
+        let mut content = (message.len() as u64).to_le_bytes().to_vec();
+        content.extend(message);
+        content.resize(content.len().next_multiple_of(self.num_faults + 1), 0);
+        let hash = do_hash(&content);
+        let f = self.fec()?;
+        let mut fragments: Vec<Share> = vec![
+            Share {
+                number: 0,
+                data: vec![]
+            };
+            self.num_nodes
+        ];
+        if let Err(e) = f.encode(&content, |fragment: Share| {
+            let number = fragment.number;
+            fragments[number] = fragment;
+        }) {
+            log::info!("Encoding failed with error: {:?}", e);
+            return None;
+        }
+        Some((hash, fragments))
+    }
+
+    /// Counts an echo of this node's fragment. Returns the ready to send, once, on n - t echoes or on t + 1 echoes
+    /// for a hash that t + 1 nodes are ready for.
+    pub fn echo(&self, state: &mut RBCState, msg: ShareMsg) -> Option<ShareMsg> {
+        // A node is echoed its own fragment
+        if msg.share.number != self.myid {
+            log::warn!("Node {} echoed fragment {} to node {}", msg.origin, msg.share.number, self.myid);
+            return None;
+        }
+        let senders = state.echo_senders.entry((msg.hash, msg.share.data.clone())).or_default();
+        if !senders.insert(msg.origin) {
+            return None;
+        }
+        let echoes = senders.len();
+        let readys = state.ready_senders.get(&msg.hash).map_or(0, |senders| senders.len());
+        if echoes >= self.num_nodes - self.num_faults || (echoes > self.num_faults && readys > self.num_faults) {
+            return self.ready_with(state, msg.hash, msg.share);
+        }
+        None
+    }
+
+    /// Counts a ready. Returns the ready this node sends in turn, if any, and the message, without its length and
+    /// padding, once it decodes from the fragments of n - t readys to the agreed hash.
+    pub fn ready(&self, state: &mut RBCState, msg: ShareMsg) -> (Option<ShareMsg>, Option<Vec<u8>>) {
+        // A node readies with its own fragment
+        if msg.share.number != msg.origin {
+            log::warn!("Node {} sent ready with fragment {}", msg.origin, msg.share.number);
+            return (None, None);
+        }
+        if !state.ready_senders.entry(msg.hash).or_default().insert(msg.origin) {
+            return (None, None);
+        }
+        state.ready_fragments.entry(msg.hash).or_default().push(msg.share);
+        let readys = state.ready_senders[&msg.hash].len();
+
+        // t + 1 readys include an honest node's, so this node can be ready too once t + 1 echoes agree on its fragment
+        let mut ready = None;
+        if readys > self.num_faults && !state.readied {
+            let fragment = state
+                .echo_senders
+                .iter()
+                .find(|((hash, _), senders)| *hash == msg.hash && senders.len() > self.num_faults)
+                .map(|((_, data), _)| Share {
+                    number: self.myid,
+                    data: data.clone(),
+                });
+            if let Some(fragment) = fragment {
+                ready = self.ready_with(state, msg.hash, fragment);
+            }
+        }
+
+        if state.delivered || readys < self.num_nodes - self.num_faults {
+            return (ready, None);
+        }
+        // Error correction over the fragments received so far, retried with every new ready
+        let fragments = state.ready_fragments[&msg.hash].clone();
+        let content = match self.fec().map(|f| f.decode(vec![], fragments)) {
+            Some(Ok(content)) if do_hash(&content) == msg.hash => content,
+            Some(Ok(_)) => {
+                log::info!("Decoded message does not match the hash");
+                return (ready, None);
+            }
+            Some(Err(e)) => {
+                log::info!("Decoding failed with error: {}", e);
+                return (ready, None);
+            }
+            None => return (ready, None),
+        };
+        state.delivered = true;
+        // Every honest node decodes the same content, so a bad length is the sender's and nobody delivers
+        let length = content
+            .get(..LENGTH_PREFIX)
+            .and_then(|prefix| prefix.try_into().ok())
+            .map(|prefix| u64::from_le_bytes(prefix) as usize);
+        match length {
+            Some(length) if length <= content.len() - LENGTH_PREFIX => {
+                (ready, Some(content[LENGTH_PREFIX..LENGTH_PREFIX + length].to_vec()))
+            }
+            _ => {
+                log::warn!("Decoded message has an invalid length prefix");
+                (ready, None)
+            }
+        }
+    }
+
+    // This node's ready, at most one
+    fn ready_with(&self, state: &mut RBCState, hash: Hash, fragment: Share) -> Option<ShareMsg> {
+        if state.readied {
+            return None;
+        }
+        state.readied = true;
+        Some(ShareMsg {
+            share: fragment,
+            hash,
+            origin: self.myid,
+        })
+    }
+}
@@ -1,34 +1,50 @@
-use crate::{Context, Msg, ProtMsg};
+use crate::{Context, Msg, ProtMsg, ShareMsg};
+use types::WrapperMsg;
 
 impl Context {
-    // A function's input parameter needs to be borrowed as mutable only when
-    // we intend to modify the variable in the function. Otherwise, it need not be borrowed as mutable.
-    // In this example, the mut can (and must) be removed because we are not modifying the Context inside
-    // the function.
-
     // Dealer sending message to everybody
     pub async fn start_init(self: &mut Context, input_msg: Vec<u8>, instance_id: usize) {
         // Draft a message
         let msg = Msg {
-            content: input_msg.clone(),
+            content: input_msg,
             origin: self.myid,
         };
-        self.handle_init(msg.clone(), instance_id).await;
-        // Wrap the message in a type
-        // Use different types of messages like INIT, ECHO, .... for the Bracha's RBC implementation
-        let protocol_msg = ProtMsg::Init(msg, instance_id);
-        // Broadcast the message to everyone
-        self.broadcast(protocol_msg).await;
+        // Use different types of messages like INIT, ECHO, .... for the RBC implementation
+        self.broadcast(ProtMsg::Init(msg.clone(), instance_id)).await;
+        self.handle_init(msg, instance_id).await;
     }
 
     pub async fn handle_init(self: &mut Context, msg: Msg, instance_id: usize) {
-        //send echo
-        self.start_echo(msg.content.clone(), instance_id).await;
-
         log::info!(
-            "Received Init message {:?} from node {}.",
-            msg.content,
+            "Received Init message of {} bytes from node {}.",
+            msg.content.len(),
             msg.origin,
         );
+        let rbc_context = self.rbc_context.entry(instance_id).or_default();
+        if rbc_context.echoed {
+            return;
+        }
+        rbc_context.echoed = true;
+        let (hash, fragments) = match self.rbc.encode(msg.content) {
+            Some(encoded) => encoded,
+            None => return,
+        };
+        log::info!("Encoded {} shares for instance id {}", fragments.len(), instance_id);
+
+        // Echo to every node the encoding corresponding to the replica id
+        let sec_key_map = self.sec_key_map.clone();
+        for (replica, sec_key) in sec_key_map.into_iter() {
+            let msg = ShareMsg {
+                share: fragments[replica].clone(),
+                hash,
+                origin: self.myid,
+            };
+            if replica == self.myid {
+                self.handle_echo(msg, instance_id).await;
+                continue;
+            }
+            let wrapper_msg = WrapperMsg::new(ProtMsg::Echo(msg, instance_id), self.myid, sec_key.as_slice());
+            self.send(replica, wrapper_msg).await;
+        }
     }
 }
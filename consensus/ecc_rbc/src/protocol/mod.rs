@@ -4,5 +4,8 @@ mod echo;
 
 mod ready;
 
+mod erasure_rbc;
+pub use erasure_rbc::*;
+
 mod rbc_state;
 pub use rbc_state::*;
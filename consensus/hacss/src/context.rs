@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use common::{Event, Net};
+use config::Node;
+use consensus::HighThresholdSharing;
+use ecc_rbc::ErasureRBC;
+use tokio::sync::oneshot;
+use types::{RBCSyncMsg, Replica};
+
+use super::{ProtMsg, HACSSState};
+
+pub struct Context {
+    /// Networking context
+    pub net: Net<ProtMsg>,
+    /// Data context
+    pub num_nodes: usize,
+    pub myid: usize,
+    pub num_faults: usize,
+    pub(crate) byz: bool,
+
+    /// Bivariate sharing with threshold 2t + 1 for the secret and t + 1 for the columns, node i is party i + 1
+    pub hacss: HighThresholdSharing,
+    /// The steps of the erasure-coded RBC that delivers the commitments
+    pub rbc: ErasureRBC,
+    // Each sharing is associated with a Unique Identifier.
+    pub hacss_context: HashMap<usize, HACSSState>,
+}
+
+impl Context {
+    pub fn spawn(config: Node, byz: bool) -> anyhow::Result<oneshot::Sender<()>> {
+        let (net, exit_tx) = Net::spawn(&config, byz);
+
+        let hacss = HighThresholdSharing::new(2 * config.num_faults + 1, config.num_faults + 1, config.num_nodes)
+            .map_err(|e| anyhow!("Invalid HACSS parameters: {}", e))?;
+
+        tokio::spawn(async move {
+            let mut c = Context {
+                net,
+                num_nodes: config.num_nodes,
+                myid: config.id,
+                byz,
+                num_faults: config.num_faults,
+                hacss,
+                rbc: ErasureRBC::new(config.id, config.num_nodes, config.num_faults),
+                hacss_context: HashMap::default(),
+            };
+
+            // Run the consensus context
+            if let Err(e) = c.run().await {
+                log::error!("Consensus error: {}", e);
+            }
+        });
+
+        Ok(exit_tx)
+    }
+
+    pub async fn run(&mut self) -> Result<()> {
+        // The process starts listening to messages in this process.
+        // First, the node sends an alive message
+        self.net.alive().await;
+        loop {
+            match self.net.next().await? {
+                Event::Message(msg) => self.process_msg(msg).await,
+                Event::Start(value) => {
+                    // The syncer starts every node, the dealer shares the value of the START message
+                    if self.myid != self.dealer() {
+                        continue;
+                    }
+                    let hacss_msg: RBCSyncMsg = bincode::deserialize(&value)
+                        .expect("Unable to deserialize message received from the syncer");
+                    self.start_hacss(hacss_msg.id, hacss_msg.msg.into_bytes()).await;
+                }
+                Event::Stop => break,
+            }
+        }
+        Ok(())
+    }
+
+    /// The node that deals in every epoch.
+    pub fn dealer(&self) -> Replica {
+        0
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt::{self, Debug};
+
+use consensus::{Erase, Share};
+pub use ecc_rbc::{Msg, ShareMsg as FragmentMsg};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use serde::{Deserialize, Serialize};
+use types::Replica;
+
+/// What the dealer reliably broadcasts, the length of its input and the compressed commitments of every limb.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Dealing {
+    pub length: usize,
+    pub commitments: Vec<Vec<u8>>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RowMsg {
+    /// For every limb, the row's evaluations at 0, ..., t_h - 1 in canonical encoding
+    pub rows: Vec<Vec<Vec<u8>>>,
+    pub origin: Replica,
+}
+
+// A row is a share of the dealer's polynomial, so it is wiped like one and kept out of the logs
+impl Drop for RowMsg {
+    fn drop(&mut self) {
+        self.rows.erase();
+    }
+}
+
+impl Debug for RowMsg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RowMsg")
+            .field("rows", &"<redacted>")
+            .field("origin", &self.origin)
+            .finish()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PointMsg {
+    /// One point of the receiver's column (or row) per limb, as a share at the sender's index
+    pub points: Vec<Share<FrField>>,
+    pub origin: Replica,
+}
+
+/*
+this is how an epoch of the HACSS protocol works, with threshold t_h = 2t + 1 for the secret and t + 1 for columns
+1. The dealer shares every limb of its input with a bivariate polynomial phi(x, y) and reliably broadcasts the
+   commitments with Das et al.'s erasure-coded RBC from ecc_rbc
+   a. <init, M> to everybody
+   b. <echo, f_i(M), H(M)> to party i, f_i(M) is the i-th Reed-Solomon fragment of M
+   c. on n - t <echo, f_i, h> or on t + 1 <ready, h> and t + 1 <echo, f_i, h>, <ready, f_i, h> to everybody
+   d. on 2t + 1 <ready, h>, decode M from the fragments and output it if H(M) = h
+      M is the encoded commitments, prefixed with their length and zero-padded, and the output strips both
+2. The dealer sends party j its row phi(x, j) as the evaluations at 0, ..., t_h - 1
+3. Party j completes the row with fill_evaluation_at_all_points, checks it against the commitments and sends
+   <column point, phi(i, j)> to every party i
+4. On t + 1 valid column points, party i interpolates its column phi(i, y), its share is phi(i, 0), and sends
+   <row point, phi(i, j)> to every party j
+5. A party without a row interpolates it from 2t + 1 valid row points and continues with 3
+6. A party terminates with its share once it has n - t valid column points. At least t + 1 of them come from
+   honest parties that send column points to everybody, so every honest party gets its column, then its row,
+   and terminates as well
+*/
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum ProtMsg {
+    Init(Msg, usize), // Epoch
+    Echo(FragmentMsg, usize),
+    Ready(FragmentMsg, usize),
+    Row(RowMsg, usize),
+    ColumnPoint(PointMsg, usize),
+    RowPoint(PointMsg, usize),
+}
@@ -0,0 +1,178 @@
+use consensus::{ByteShare, Scheme, Secret, Share};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{FrElement, FrField};
+use lambdaworks_math::polynomial::Polynomial;
+use types::{ShareSyncMsg, WrapperMsg};
+
+use crate::{Context, PointMsg, ProtMsg};
+
+impl Context {
+    pub async fn handle_column_point(&mut self, msg: PointMsg, epoch: usize) {
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        if hacss_context.column_points.contains_key(&msg.origin) {
+            return;
+        }
+        hacss_context.pending_column_points.entry(msg.origin).or_insert(msg.points);
+        self.progress(epoch).await;
+    }
+
+    /// Moves on as far as the delivered dealing and the points received so far allow.
+    pub async fn progress(&mut self, epoch: usize) {
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        if hacss_context.terminated || hacss_context.dealing.is_none() {
+            return;
+        }
+        self.check_points(epoch);
+
+        // The points a node sends itself can complete its column right after its row, or its row after its column
+        for _ in 0..2 {
+            if self.hacss_context[&epoch].row.is_none() {
+                if let Some(row) = self.row_from_dealer(epoch).or_else(|| self.row_from_points(epoch)) {
+                    self.hacss_context.entry(epoch).or_default().row = Some(row.clone());
+                    self.send_column_points(row, epoch).await;
+                }
+            }
+            if self.hacss_context[&epoch].column.is_none() {
+                if let Some(column) = self.column_from_points(epoch) {
+                    log::info!("Interpolated the column for epoch {}", epoch);
+                    self.send_row_points(&column, epoch).await;
+                    self.hacss_context.entry(epoch).or_default().column = Some(column);
+                }
+            }
+        }
+
+        // n - t nodes hold their row, at least t + 1 honest ones send points to everybody
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        if hacss_context.column_points.len() < self.num_nodes - self.num_faults {
+            return;
+        }
+        let (length, column) = match (&hacss_context.dealing, &hacss_context.column) {
+            (Some((length, _)), Some(column)) => (*length, column),
+            _ => return,
+        };
+        let share = ByteShare {
+            index: self.myid as u64 + 1,
+            // All shares of one epoch belong to the same split
+            split_id: epoch as u64,
+            length,
+            values: column
+                .iter()
+                .map(|limb_column| limb_column.evaluate(&FrElement::zero()))
+                .collect(),
+            threshold: self.hacss.high.sss.threshold,
+            scheme: Scheme::Shamir,
+        };
+        hacss_context.terminated = true;
+        log::info!("Holds its share for epoch {}", epoch);
+        let sync_msg = ShareSyncMsg {
+            id: epoch,
+            share: share.to_bytes(),
+        };
+        self.net.terminate_shared(bincode::serialize(&sync_msg).expect("Failed to serialize share")).await;
+    }
+
+    // Checks the points received before against the delivered commitments, and drops the ones that don't match
+    fn check_points(&mut self, epoch: usize) {
+        let hacss = &self.hacss;
+        let party = self.myid as u64 + 1;
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        let commitments = match &hacss_context.dealing {
+            Some((_, commitments)) => commitments,
+            None => return,
+        };
+
+        // phi(i, j) on this node's column i, from node j's row
+        for (sender, points) in hacss_context.pending_column_points.drain() {
+            let sender_party = sender as u64 + 1;
+            let valid = points.len() == commitments.len()
+                && points
+                    .iter()
+                    .zip(commitments)
+                    .all(|(point, commitments)| hacss.verify_point(party, sender_party, &point.value, commitments));
+            if !valid {
+                log::warn!("Node {} sent invalid column points for epoch {}", sender, epoch);
+                continue;
+            }
+            let points = points
+                .into_iter()
+                .map(|point| Share {
+                    index: sender_party,
+                    value: point.value.clone(),
+                    threshold: hacss.low.threshold,
+                    scheme: Scheme::Shamir,
+                })
+                .collect();
+            hacss_context.column_points.insert(sender, points);
+        }
+
+        // phi(j, i) on this node's row i, from node j's column
+        for (sender, points) in hacss_context.pending_row_points.drain() {
+            let sender_party = sender as u64 + 1;
+            let valid = points.len() == commitments.len()
+                && points
+                    .iter()
+                    .zip(commitments)
+                    .all(|(point, commitments)| hacss.verify_point(sender_party, party, &point.value, commitments));
+            if !valid {
+                log::warn!("Node {} sent invalid row points for epoch {}", sender, epoch);
+                continue;
+            }
+            let points = points
+                .into_iter()
+                .map(|point| Share {
+                    index: sender_party,
+                    value: point.value.clone(),
+                    threshold: hacss.high.sss.threshold,
+                    scheme: Scheme::Shamir,
+                })
+                .collect();
+            hacss_context.row_points.insert(sender, points);
+        }
+    }
+
+    /// The column interpolated from t + 1 points on it, sent by nodes that hold their row.
+    fn column_from_points(&mut self, epoch: usize) -> Option<Vec<Secret<Polynomial<FrElement>>>> {
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        if hacss_context.column_points.len() < self.hacss.low.threshold {
+            return None;
+        }
+        let (_, commitments) = hacss_context.dealing.as_ref()?;
+        (0..commitments.len())
+            .map(|limb| {
+                let points: Vec<Share<FrField>> =
+                    hacss_context.column_points.values().map(|points| points[limb].clone()).collect();
+                self.hacss.interpolate_column(&points).ok()
+            })
+            .collect()
+    }
+
+    /// Sends every node j the point phi(i, j) of its row, from this node's column i.
+    async fn send_row_points(&mut self, column: &[Secret<Polynomial<FrElement>>], epoch: usize) {
+        let sec_key_map = self.net.sec_key_map.clone();
+        for (replica, sec_key) in sec_key_map.into_iter() {
+            let y = FrElement::from(replica as u64 + 1);
+            let points: Vec<Share<FrField>> = column
+                .iter()
+                .map(|limb_column| Share {
+                    index: self.myid as u64 + 1,
+                    value: limb_column.evaluate(&y),
+                    threshold: self.hacss.high.sss.threshold,
+                    scheme: Scheme::Shamir,
+                })
+                .collect();
+            if replica == self.myid {
+                self.hacss_context
+                    .entry(epoch)
+                    .or_default()
+                    .row_points
+                    .insert(self.myid, points);
+                continue;
+            }
+            let msg = PointMsg {
+                points,
+                origin: self.myid,
+            };
+            let wrapper_msg = WrapperMsg::new(ProtMsg::RowPoint(msg, epoch), self.myid, sec_key.as_slice());
+            self.net.send(replica, wrapper_msg).await;
+        }
+    }
+}
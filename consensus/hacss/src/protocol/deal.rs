@@ -0,0 +1,56 @@
+use consensus::{bytes_to_limbs, commitments_to_bytes, field_to_bytes, BivariateCommitments, Rows};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use types::WrapperMsg;
+
+use crate::{Context, Dealing, ProtMsg, RowMsg};
+
+impl Context {
+    pub async fn start_hacss(&mut self, epoch: usize, secret: Vec<u8>) {
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        if hacss_context.dealt {
+            return;
+        }
+        hacss_context.dealt = true;
+
+        let limbs = match bytes_to_limbs::<FrField>(&secret) {
+            Ok(limbs) => limbs,
+            Err(e) => {
+                log::error!("Failed to deal the secret for epoch {}: {}", epoch, e);
+                return;
+            }
+        };
+        let (rows, commitments): (Vec<Rows<FrField>>, Vec<BivariateCommitments<BLS12381Curve>>) =
+            limbs.into_iter().map(|limb| self.hacss.deal(limb)).unzip();
+        let dealing = Dealing {
+            length: secret.len(),
+            commitments: commitments
+                .iter()
+                .map(|limb_commitments| commitments_to_bytes(&limb_commitments.concat()))
+                .collect(),
+        };
+        log::info!("Dealt {} limbs for epoch {}", rows.len(), epoch);
+
+        // The commitments are the bulk of the dealing and go through the erasure-coded RBC
+        let content = bincode::serialize(&dealing).expect("Failed to serialize dealing");
+        self.start_rbc(content, epoch).await;
+
+        // Every node gets its own row, party j is node j - 1
+        let sec_key_map = self.net.sec_key_map.clone();
+        for (replica, sec_key) in sec_key_map.into_iter() {
+            let msg = RowMsg {
+                rows: rows
+                    .iter()
+                    .map(|limb_rows| limb_rows[replica].iter().map(field_to_bytes).collect())
+                    .collect(),
+                origin: self.myid,
+            };
+            if replica == self.myid {
+                self.handle_row(msg, epoch).await;
+                continue;
+            }
+            let wrapper_msg = WrapperMsg::new(ProtMsg::Row(msg, epoch), self.myid, sec_key.as_slice());
+            self.net.send(replica, wrapper_msg).await;
+        }
+    }
+}
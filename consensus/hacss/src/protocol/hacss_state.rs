@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use consensus::{BivariateCommitments, Secret, Share};
+use ecc_rbc::RBCState;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{FrElement, FrField};
+use lambdaworks_math::polynomial::Polynomial;
+use types::Replica;
+
+use crate::RowMsg;
+
+pub struct HACSSState {
+    /// Erasure-coded RBC of the dealing
+    pub rbc: RBCState,
+    /// The length of the dealer's input and the commitments of every limb, once the RBC delivered them
+    pub dealing: Option<(usize, Vec<BivariateCommitments<BLS12381Curve>>)>,
+
+    pub dealt: bool,
+    /// The row the dealer sent, kept until the dealing is delivered
+    pub dealt_row: Option<RowMsg>,
+    /// This node's row at 0..=n, for every limb
+    pub row: Option<Vec<Secret<Vec<FrElement>>>>,
+    /// This node's column, for every limb
+    pub column: Option<Vec<Secret<Polynomial<FrElement>>>>,
+
+    // Points by sender, checked once the dealing is delivered
+    pub pending_column_points: HashMap<Replica, Vec<Share<FrField>>>,
+    pub pending_row_points: HashMap<Replica, Vec<Share<FrField>>>,
+    pub column_points: HashMap<Replica, Vec<Share<FrField>>>,
+    pub row_points: HashMap<Replica, Vec<Share<FrField>>>,
+
+    pub terminated: bool,
+}
+
+impl HACSSState {
+    pub fn new() -> HACSSState {
+        HACSSState {
+            rbc: RBCState::default(),
+            dealing: None,
+
+            dealt: false,
+            dealt_row: None,
+            row: None,
+            column: None,
+
+            pending_column_points: HashMap::default(),
+            pending_row_points: HashMap::default(),
+            column_points: HashMap::default(),
+            row_points: HashMap::default(),
+
+            terminated: false,
+        }
+    }
+}
+
+impl Default for HACSSState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,10 @@
+mod deal;
+
+mod rbc;
+
+mod row;
+
+mod column;
+
+mod hacss_state;
+pub use hacss_state::*;
@@ -0,0 +1,101 @@
+use async_recursion::async_recursion;
+use consensus::{commitments_from_bytes, BivariateCommitments};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use types::WrapperMsg;
+
+use crate::{Context, Dealing, FragmentMsg, Msg, ProtMsg};
+
+impl Context {
+    /// Reliably broadcasts the dealing, starting with an <init> to everyone.
+    pub async fn start_rbc(&mut self, content: Vec<u8>, epoch: usize) {
+        let msg = Msg {
+            content,
+            origin: self.myid,
+        };
+        self.net.broadcast(ProtMsg::Init(msg.clone(), epoch)).await;
+        self.handle_init(msg, epoch).await;
+    }
+
+    pub async fn handle_init(&mut self, msg: Msg, epoch: usize) {
+        if msg.origin != self.dealer() {
+            log::warn!("Node {} is not the dealer of epoch {}", msg.origin, epoch);
+            return;
+        }
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        if hacss_context.rbc.echoed {
+            return;
+        }
+        hacss_context.rbc.echoed = true;
+        let (hash, fragments) = match self.rbc.encode(msg.content) {
+            Some(encoded) => encoded,
+            None => return,
+        };
+
+        // Echo to every node the fragment corresponding to its id
+        let sec_key_map = self.net.sec_key_map.clone();
+        for (replica, sec_key) in sec_key_map.into_iter() {
+            let msg = FragmentMsg {
+                share: fragments[replica].clone(),
+                hash,
+                origin: self.myid,
+            };
+            if replica == self.myid {
+                self.handle_echo(msg, epoch).await;
+                continue;
+            }
+            let wrapper_msg = WrapperMsg::new(ProtMsg::Echo(msg, epoch), self.myid, sec_key.as_slice());
+            self.net.send(replica, wrapper_msg).await;
+        }
+    }
+
+    pub async fn handle_echo(&mut self, msg: FragmentMsg, epoch: usize) {
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        if let Some(ready) = self.rbc.echo(&mut hacss_context.rbc, msg) {
+            self.send_ready(ready, epoch).await;
+        }
+    }
+
+    #[async_recursion]
+    pub async fn send_ready(&mut self, msg: FragmentMsg, epoch: usize) {
+        self.net.broadcast(ProtMsg::Ready(msg.clone(), epoch)).await;
+        self.handle_ready(msg, epoch).await;
+    }
+
+    #[async_recursion]
+    pub async fn handle_ready(&mut self, msg: FragmentMsg, epoch: usize) {
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        let (ready, content) = self.rbc.ready(&mut hacss_context.rbc, msg);
+        if let Some(ready) = ready {
+            self.send_ready(ready, epoch).await;
+        }
+        let content = match content {
+            Some(content) => content,
+            None => return,
+        };
+        match self.decode_dealing(&content) {
+            Some(dealing) => {
+                log::info!("Delivered the dealing of epoch {}", epoch);
+                self.hacss_context.entry(epoch).or_default().dealing = Some(dealing);
+                self.progress(epoch).await;
+            }
+            None => log::warn!("The dealer broadcast a malformed dealing for epoch {}", epoch),
+        }
+    }
+
+    // The length of the input and the commitments of every limb, t + 1 commitments of 2t + 1 each
+    fn decode_dealing(&self, content: &[u8]) -> Option<(usize, Vec<BivariateCommitments<BLS12381Curve>>)> {
+        let dealing: Dealing = bincode::deserialize(content).ok()?;
+        let (high, low) = (self.hacss.high.sss.threshold, self.hacss.low.threshold);
+        let commitments = dealing
+            .commitments
+            .iter()
+            .map(|bytes| match commitments_from_bytes(bytes) {
+                Ok(commitments) if commitments.len() == high * low => {
+                    Some(commitments.chunks(high).map(|commitment| commitment.to_vec()).collect())
+                }
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some((dealing.length, commitments))
+    }
+}
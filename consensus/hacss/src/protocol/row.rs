@@ -0,0 +1,122 @@
+use consensus::{field_from_bytes, Scheme, Secret, Share};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{FrElement, FrField};
+use types::WrapperMsg;
+
+use crate::{Context, PointMsg, ProtMsg, RowMsg};
+
+impl Context {
+    pub async fn handle_row(&mut self, msg: RowMsg, epoch: usize) {
+        if msg.origin != self.dealer() {
+            log::warn!("Node {} is not the dealer of epoch {}", msg.origin, epoch);
+            return;
+        }
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        if hacss_context.row.is_some() || hacss_context.dealt_row.is_some() {
+            return;
+        }
+        hacss_context.dealt_row = Some(msg);
+        self.progress(epoch).await;
+    }
+
+    pub async fn handle_row_point(&mut self, msg: PointMsg, epoch: usize) {
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        if hacss_context.row_points.contains_key(&msg.origin) {
+            return;
+        }
+        hacss_context.pending_row_points.entry(msg.origin).or_insert(msg.points);
+        self.progress(epoch).await;
+    }
+
+    /// The row the dealer sent, completed to all parties, if it matches the commitments.
+    pub(crate) fn row_from_dealer(&mut self, epoch: usize) -> Option<Vec<Secret<Vec<FrElement>>>> {
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        let msg = hacss_context.dealt_row.take()?;
+        let (_, commitments) = hacss_context.dealing.as_ref()?;
+        let party = self.myid as u64 + 1;
+        let row = if msg.rows.len() == commitments.len() {
+            msg.rows
+                .iter()
+                .zip(commitments)
+                .map(|(row, commitments)| {
+                    let row = Secret::new(
+                        row.iter()
+                            .map(|value| field_from_bytes::<FrField>(value))
+                            .collect::<Result<Vec<_>, _>>()
+                            .ok()?,
+                    );
+                    let row = self.hacss.complete_row(&row).ok()?;
+                    self.hacss.verify_row(party, &row, commitments).then_some(row)
+                })
+                .collect()
+        } else {
+            None
+        };
+        if row.is_none() {
+            log::warn!("The dealer sent an invalid row for epoch {}", epoch);
+        }
+        row
+    }
+
+    /// The row interpolated from 2t + 1 points on it, sent by nodes that know their column.
+    pub(crate) fn row_from_points(&mut self, epoch: usize) -> Option<Vec<Secret<Vec<FrElement>>>> {
+        let hacss_context = self.hacss_context.entry(epoch).or_default();
+        if hacss_context.row_points.len() < self.hacss.high.sss.threshold {
+            return None;
+        }
+        let (_, commitments) = hacss_context.dealing.as_ref()?;
+        let row: Option<Vec<Secret<Vec<FrElement>>>> = (0..commitments.len())
+            .map(|limb| {
+                let points: Vec<Share<FrField>> =
+                    hacss_context.row_points.values().map(|points| points[limb].clone()).collect();
+                self.hacss.interpolate_row(&points).ok()
+            })
+            .collect();
+        if row.is_some() {
+            log::info!("Interpolated the row from {} points for epoch {}", hacss_context.row_points.len(), epoch);
+        }
+        row
+    }
+
+    /// Sends every node the point phi(i, j) of its column, from this node's row j.
+    pub(crate) async fn send_column_points(&mut self, row: Vec<Secret<Vec<FrElement>>>, epoch: usize) {
+        let sec_key_map = self.net.sec_key_map.clone();
+        for (replica, sec_key) in sec_key_map.into_iter() {
+            let points: Vec<Share<FrField>> = row
+                .iter()
+                .map(|limb_row| Share {
+                    index: self.myid as u64 + 1,
+                    value: limb_row[replica + 1].clone(),
+                    threshold: self.hacss.low.threshold,
+                    scheme: Scheme::Shamir,
+                })
+                .collect();
+            if replica == self.myid {
+                // This node's own point is on its row, so it needs no check
+                self.hacss_context
+                    .entry(epoch)
+                    .or_default()
+                    .column_points
+                    .insert(self.myid, points);
+                continue;
+            }
+            let points = if self.byz {
+                // Sends points off the polynomial, which the commitments give away
+                points
+                    .into_iter()
+                    .map(|mut point| {
+                        point.value = &point.value + FrElement::one();
+                        point
+                    })
+                    .collect()
+            } else {
+                points
+            };
+            let msg = PointMsg {
+                points,
+                origin: self.myid,
+            };
+            let wrapper_msg = WrapperMsg::new(ProtMsg::ColumnPoint(msg, epoch), self.myid, sec_key.as_slice());
+            self.net.send(replica, wrapper_msg).await;
+        }
+    }
+}
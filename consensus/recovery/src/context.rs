@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use common::{Event, Net};
+use config::Node;
+use consensus::{Commitment, FeldmanVSS, Share};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use tokio::sync::oneshot;
+use types::{RBCSyncMsg, Replica};
+
+use super::{ProtMsg, RecoveryState};
+
+pub struct Context {
+    /// Networking context
+    pub net: Net<ProtMsg>,
+    /// Data context
+    pub num_nodes: usize,
+    pub myid: usize,
+    pub num_faults: usize,
+    pub(crate) byz: bool,
+
+    /// Feldman VSS with threshold t + 1 over the n nodes, node i holds the share at party index i + 1
+    pub vss: FeldmanVSS,
+    /// This node's share of the long-lived secret, none until the dealer's share arrived and while it is being
+    /// recovered
+    pub share: Option<Share<FrField>>,
+    /// Feldman commitments to the sharing polynomial, empty until the dealer sent them
+    pub commitments: Vec<Commitment<BLS12381Curve>>,
+    /// Epoch messages that arrived before the dealer's share
+    pub pending: Vec<ProtMsg>,
+    // Each recovery epoch is associated with a Unique Identifier.
+    pub recovery_context: HashMap<usize, RecoveryState>,
+}
+
+impl Context {
+    pub fn spawn(config: Node, byz: bool) -> anyhow::Result<oneshot::Sender<()>> {
+        let (net, exit_tx) = Net::spawn(&config, byz);
+
+        let vss = FeldmanVSS::new(config.num_faults + 1, config.num_nodes)
+            .map_err(|e| anyhow!("Invalid recovery parameters: {}", e))?;
+
+        tokio::spawn(async move {
+            let mut c = Context {
+                net,
+                num_nodes: config.num_nodes,
+                myid: config.id,
+                byz,
+                num_faults: config.num_faults,
+                vss,
+                share: None,
+                commitments: Vec::new(),
+                pending: Vec::new(),
+                recovery_context: HashMap::default(),
+            };
+
+            // The dealer shares the long-lived secret before the first epoch
+            if c.myid == c.dealer() {
+                c.deal_secret().await;
+            }
+
+            // Run the consensus context
+            if let Err(e) = c.run().await {
+                log::error!("Consensus error: {}", e);
+            }
+        });
+
+        Ok(exit_tx)
+    }
+
+    pub async fn run(&mut self) -> Result<()> {
+        // The process starts listening to messages in this process.
+        // First, the node sends an alive message
+        self.net.alive().await;
+        loop {
+            match self.net.next().await? {
+                Event::Message(msg) => self.process_msg(msg).await,
+                Event::Start(value) => {
+                    // Every START message from the syncer opens a recovery epoch, which this node
+                    // announces to the others
+                    let epoch_msg: RBCSyncMsg = bincode::deserialize(&value)
+                        .expect("Unable to deserialize message received from the syncer");
+                    self.net.broadcast(ProtMsg::Start(self.myid, epoch_msg.id)).await;
+                    self.start_recovery(epoch_msg.id).await;
+                }
+                Event::Stop => break,
+            }
+        }
+        Ok(())
+    }
+
+    /// The node that deals the long-lived secret.
+    pub fn dealer(&self) -> Replica {
+        0
+    }
+
+    /// The node that loses its share in an epoch.
+    pub fn lost_node(&self, epoch: usize) -> Replica {
+        epoch % self.num_nodes
+    }
+
+    /// The 2t + 1 nodes that follow the lost node help it recover its share, so t faulty ones can't stall it.
+    pub fn helpers(&self, epoch: usize) -> Vec<Replica> {
+        let lost = self.lost_node(epoch);
+        let helpers = (self.vss.sss.threshold + self.num_faults).min(self.num_nodes - 1);
+        (1..helpers + 1)
+            .map(|offset| (lost + offset) % self.num_nodes)
+            .collect()
+    }
+}
@@ -0,0 +1,10 @@
+mod context;
+pub use context::*;
+
+mod process;
+
+mod msg;
+pub use msg::*;
+
+mod protocol;
+pub use protocol::*;
@@ -0,0 +1,84 @@
+use std::fmt::Debug;
+
+use consensus::Share;
+use crypto::hash::Hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use serde::{Deserialize, Serialize};
+use types::Replica;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ShareMsg {
+    /// The receiver's share of the long-lived secret
+    pub share: Share<FrField>,
+    /// Compressed Feldman commitments to the sharing polynomial
+    pub commitments: Vec<u8>,
+    pub origin: Replica,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BlindingMsg {
+    /// The receiving helper's blinding sub-share, none for the recovering node
+    pub share: Option<Share<FrField>>,
+    /// Compressed Feldman commitments to the blinding polynomial
+    pub commitments: Vec<u8>,
+    pub origin: Replica,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HeldMsg {
+    /// The helper whose blinding sub-share the sender checked
+    pub blinder: Replica,
+    /// Hash of the commitments the sub-share matches
+    pub hash: Hash,
+    pub origin: Replica,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BlindersMsg {
+    /// The helpers whose blindings the answers to this list add up, t + 1 of them, with the hashes of their commitments
+    pub blinders: Vec<(Replica, Hash)>,
+    pub origin: Replica,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AnswerMsg {
+    /// The helper's share blinded with the listed blindings
+    pub share: Share<FrField>,
+    /// The list the answer adds up
+    pub blinders: Vec<(Replica, Hash)>,
+    pub origin: Replica,
+}
+
+/*
+before the first epoch, the dealer (node 0) shares a random secret with Feldman VSS.
+It sends <share, P(i + 1), commitments> to node i only
+
+this is how an epoch of the recovery protocol works
+Node r = epoch mod n loses its share P(r + 1), the helpers are the 2t + 1 nodes that follow it (at most n - 1)
+1. Every node that gets the START from the syncer sends <start> to everyone
+2. Node r drops its share and sends <request> to the helpers
+3. Every helper j deals a blinding polynomial B_j with B_j(r + 1) = 0 and sends <blinding, B_j(k + 1), commitments to B_j>
+   to every helper k, and the commitments alone to node r
+4. Helper k checks B_j(k + 1) against the commitments and sends <held, j, H(commitments_j)> to node r
+5. Every t + 1 helpers whose commitments open to zero at r + 1 and that hold each other's blindings make a list,
+   node r sends <blinders, [(j, H(commitments_j))]> to the listed helpers
+6. Helper k answers every list it is on, once it holds all the listed blindings, with
+   <answer, P(k + 1) + sum_j B_j(k + 1), list> to node r
+7. Node r checks every answer against the product of the commitments to P and to the listed B_j. Once t + 1 helpers
+   answered the same list it interpolates their answers at r + 1, checks the share against the commitments to P and
+   terminates
+A helper that keeps its blinding from the others, or acks and never answers, only stalls the lists it is on. The
+t + 1 honest helpers hold each other's blindings eventually and answer their list. A helper only answers lists it is
+on, so its own blinding is in every answer it sends and node r can't combine them into P(k + 1).
+Everybody reports g^{P(r + 1)}, which is public through the commitments
+*/
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum ProtMsg {
+    Share(ShareMsg),
+    Start(Replica, usize), // Origin, Epoch
+    Request(Replica, usize),
+    Blinding(BlindingMsg, usize),
+    Held(HeldMsg, usize),
+    Blinders(BlindersMsg, usize),
+    Answer(AnswerMsg, usize),
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use super::ProtMsg;
+use crate::context::Context;
+use async_recursion::async_recursion;
+use types::WrapperMsg;
+
+impl Context {
+    pub(crate) async fn process_msg(&mut self, wrapper_msg: WrapperMsg<ProtMsg>) {
+        log::debug!("Received protocol msg: {:?}", wrapper_msg);
+        let msg = Arc::new(wrapper_msg.clone());
+
+        // Verify the message's authenticity before proceeding
+        if !self.net.check_proposal(msg) {
+            log::warn!(
+                "MAC Verification failed for message {:?}",
+                wrapper_msg.protmsg
+            );
+            return;
+        }
+        let origin = match &wrapper_msg.protmsg {
+            ProtMsg::Share(msg) => msg.origin,
+            ProtMsg::Start(origin, _) | ProtMsg::Request(origin, _) => *origin,
+            ProtMsg::Blinding(msg, _) => msg.origin,
+            ProtMsg::Held(msg, _) => msg.origin,
+            ProtMsg::Blinders(msg, _) => msg.origin,
+            ProtMsg::Answer(msg, _) => msg.origin,
+        };
+        if origin != wrapper_msg.sender {
+            log::warn!("Node {} sent a message on behalf of node {}", wrapper_msg.sender, origin);
+            return;
+        }
+        if self.commitments.is_empty() && !matches!(wrapper_msg.protmsg, ProtMsg::Share(_)) {
+            // Epoch messages wait for the dealer's share
+            self.pending.push(wrapper_msg.protmsg);
+            return;
+        }
+        self.dispatch(wrapper_msg.protmsg).await;
+    }
+
+    #[async_recursion]
+    pub(crate) async fn dispatch(&mut self, protmsg: ProtMsg) {
+        match protmsg {
+            ProtMsg::Share(main_msg) => {
+                log::info!("Received Share from node : {:?}", main_msg.origin);
+                self.handle_share(main_msg).await;
+            }
+            ProtMsg::Start(origin, epoch) => {
+                log::info!("Received Start for epoch {} from node : {:?}", epoch, origin);
+                self.start_recovery(epoch).await;
+            }
+            ProtMsg::Request(origin, epoch) => {
+                log::info!("Received Request for epoch {} from node : {:?}", epoch, origin);
+                self.handle_request(origin, epoch).await;
+            }
+            ProtMsg::Blinding(main_msg, epoch) => {
+                log::info!("Received Blinding for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_blinding(main_msg, epoch).await;
+            }
+            ProtMsg::Held(main_msg, epoch) => {
+                log::info!("Received Held for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_held(main_msg, epoch).await;
+            }
+            ProtMsg::Blinders(main_msg, epoch) => {
+                log::info!("Received Blinders for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_blinders(main_msg, epoch).await;
+            }
+            ProtMsg::Answer(main_msg, epoch) => {
+                log::info!("Received Answer for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_answer(main_msg, epoch).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use consensus::{Commitment, ShamirSecretSharing, Share};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use types::WrapperMsg;
+
+use crate::{AnswerMsg, Context, ProtMsg};
+
+impl Context {
+    /// Sends the share blinded with every list this helper is on once the blindings of all listed helpers arrived.
+    /// Each answer adds up this helper's own blinding, so no two of them combine into its share.
+    pub async fn try_answer(&mut self, epoch: usize) {
+        let recovery_context = self.recovery_context.entry(epoch).or_default();
+        let share = match &self.share {
+            Some(share) => share,
+            None => return,
+        };
+        let first = recovery_context.answered.is_empty();
+        let mut answers = Vec::new();
+        for blinders in &recovery_context.blinders {
+            if recovery_context.answered.contains(blinders) {
+                continue;
+            }
+            let blindings: Option<Vec<Share<FrField>>> = blinders
+                .iter()
+                .map(|(helper, hash)| match recovery_context.blindings.get(helper) {
+                    Some((blinding_hash, blinding)) if blinding_hash == hash => Some(blinding.clone()),
+                    _ => None,
+                })
+                .collect();
+            // Wait for the listed blindings
+            let blindings = match blindings {
+                Some(blindings) => blindings,
+                None => continue,
+            };
+            match self.vss.sss.blind_share(share, &blindings) {
+                Ok(answer) => answers.push((blinders.clone(), answer)),
+                Err(e) => log::error!("Failed to blind the share in epoch {}: {}", epoch, e),
+            }
+        }
+        recovery_context
+            .answered
+            .extend(answers.iter().map(|(blinders, _)| blinders.clone()));
+        if answers.is_empty() {
+            return;
+        }
+
+        let lost = self.lost_node(epoch);
+        let sec_key = self.net.sec_key_map.get(&lost).expect("Secret key not available").clone();
+        for (blinders, mut answer) in answers {
+            if self.byz {
+                // Sends a wrong answer, which the lost node notices against the commitments
+                answer = answer.add_constant(&ShamirSecretSharing::<FrField>::rand_field_element());
+            }
+            let msg = AnswerMsg {
+                share: answer,
+                blinders,
+                origin: self.myid,
+            };
+            let wrapper_msg = WrapperMsg::new(ProtMsg::Answer(msg, epoch), self.myid, sec_key.as_slice());
+            self.net.send(lost, wrapper_msg).await;
+        }
+        if first {
+            self.finish(epoch).await;
+        }
+    }
+
+    /// Checks an answer on its own and recovers the share once t + 1 helpers answered the same list.
+    pub async fn handle_answer(&mut self, msg: AnswerMsg, epoch: usize) {
+        if self.lost_node(epoch) != self.myid || !self.helpers(epoch).contains(&msg.origin) {
+            log::warn!("Unexpected answer from node {} in epoch {}", msg.origin, epoch);
+            return;
+        }
+        let answer = self.share_of(msg.origin, &msg.share);
+        let threshold = self.vss.sss.threshold;
+        let recovery_context = self.recovery_context.entry(epoch).or_default();
+        if recovery_context.terminated {
+            return;
+        }
+        if !recovery_context.blinders.contains(&msg.blinders)
+            || msg.blinders.iter().all(|(helper, _)| *helper != msg.origin)
+        {
+            log::warn!("Node {} answered a list of epoch {} it is not on", msg.origin, epoch);
+            return;
+        }
+        let blinding_commitments: Vec<Vec<Commitment<BLS12381Curve>>> = msg
+            .blinders
+            .iter()
+            .filter_map(|(helper, _)| {
+                recovery_context
+                    .blinding_commitments
+                    .iter()
+                    .find(|(blinder, _, _)| blinder == helper)
+                    .map(|(_, _, commitments)| commitments.clone())
+            })
+            .collect();
+        let answers = recovery_context.answers.entry(msg.blinders).or_default();
+        if answers.contains_key(&msg.origin) {
+            return;
+        }
+        if !self.vss.verify_answer(&answer, &self.commitments, &blinding_commitments) {
+            log::warn!("The answer of node {} does not match the commitments in epoch {}", msg.origin, epoch);
+            return;
+        }
+        answers.insert(msg.origin, answer);
+        if answers.len() < threshold {
+            return;
+        }
+
+        let answers: Vec<Share<FrField>> = answers.values().cloned().collect();
+        let share = match self.vss.sss.recover_share(self.myid as u64 + 1, &answers) {
+            Ok(share) => share,
+            Err(e) => {
+                log::error!("Failed to recover the share in epoch {}: {}", epoch, e);
+                return;
+            }
+        };
+        if !self.vss.verify_share(&share, &self.commitments) {
+            log::error!("The recovered share does not match the commitments in epoch {}", epoch);
+            return;
+        }
+        log::info!("Recovered the share in epoch {}", epoch);
+        self.share = Some(share);
+        self.finish(epoch).await;
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::HashSet;
+
+use async_recursion::async_recursion;
+use consensus::{commitments_from_bytes, commitments_to_bytes, SecretSharingScheme, ShamirSecretSharing, Share};
+use crypto::hash::{do_hash, Hash};
+use lambdaworks_math::cyclic_group::IsGroup;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use types::{Replica, WrapperMsg};
+
+use crate::{Blinders, BlindersMsg, BlindingMsg, Context, HeldMsg, ProtMsg};
+
+impl Context {
+    /// A share of helper `replica`, at its own point whatever index and scheme the sender put in it.
+    pub(crate) fn share_of(&self, replica: Replica, share: &Share<FrField>) -> Share<FrField> {
+        <ShamirSecretSharing<FrField> as SecretSharingScheme>::make_share(
+            &self.vss.sss,
+            replica as u64 + 1,
+            share.value.clone(),
+        )
+    }
+
+    /// A helper deals its blinding polynomial once the lost node asked for help. The helpers get their
+    /// sub-shares and the commitments, the lost node only the commitments.
+    #[async_recursion]
+    pub async fn handle_request(&mut self, origin: Replica, epoch: usize) {
+        self.start_recovery(epoch).await;
+
+        let helpers = self.helpers(epoch);
+        if origin != self.lost_node(epoch) || !helpers.contains(&self.myid) {
+            log::warn!("Node {} can't ask this node for help in epoch {}", origin, epoch);
+            return;
+        }
+        let recovery_context = self.recovery_context.entry(epoch).or_default();
+        if recovery_context.dealt {
+            return;
+        }
+        recovery_context.dealt = true;
+
+        let parties: Vec<u64> = helpers.iter().map(|helper| *helper as u64 + 1).collect();
+        let (blindings, commitments) = match self.vss.deal_blinding(origin as u64 + 1, &parties) {
+            Ok(dealing) => dealing,
+            Err(e) => {
+                log::error!("Failed to deal the blinding polynomial in epoch {}: {}", epoch, e);
+                return;
+            }
+        };
+        let commitments = commitments_to_bytes(&commitments);
+        let receivers = std::iter::once((origin, None)).chain(helpers.into_iter().zip(blindings.into_iter().map(Some)));
+        for (receiver, blinding) in receivers {
+            let msg = BlindingMsg {
+                share: blinding,
+                commitments: commitments.clone(),
+                origin: self.myid,
+            };
+            if receiver == self.myid {
+                self.handle_blinding(msg, epoch).await;
+                continue;
+            }
+            let sec_key = self.net.sec_key_map.get(&receiver).expect("Secret key not available").clone();
+            let wrapper_msg = WrapperMsg::new(ProtMsg::Blinding(msg, epoch), self.myid, sec_key.as_slice());
+            self.net.send(receiver, wrapper_msg).await;
+        }
+    }
+
+    #[async_recursion]
+    pub async fn handle_blinding(&mut self, msg: BlindingMsg, epoch: usize) {
+        self.start_recovery(epoch).await;
+
+        let lost = self.lost_node(epoch);
+        if !self.helpers(epoch).contains(&msg.origin) {
+            log::warn!("Node {} is not a helper of epoch {}", msg.origin, epoch);
+            return;
+        }
+        let commitments = match commitments_from_bytes(&msg.commitments) {
+            Ok(commitments) => commitments,
+            Err(e) => {
+                log::warn!("Invalid blinding commitments from node {}: {}", msg.origin, e);
+                return;
+            }
+        };
+        let hash = do_hash(&msg.commitments);
+
+        if lost == self.myid {
+            // Only blindings that vanish at this node's point can be listed
+            if commitments.len() != self.vss.sss.threshold
+                || !self.vss.commitment_at(&commitments, lost as u64 + 1).is_neutral_element()
+            {
+                log::warn!("The blinding of node {} does not vanish at this node's point", msg.origin);
+                return;
+            }
+            let recovery_context = self.recovery_context.entry(epoch).or_default();
+            if recovery_context
+                .blinding_commitments
+                .iter()
+                .all(|(helper, _, _)| *helper != msg.origin)
+            {
+                recovery_context.blinding_commitments.push((msg.origin, hash, commitments));
+            }
+            self.propose_blinders(epoch).await;
+            return;
+        }
+
+        if !self.helpers(epoch).contains(&self.myid) {
+            log::warn!("Node {} sent a blinding to a node that doesn't help in epoch {}", msg.origin, epoch);
+            return;
+        }
+        let blinding = match &msg.share {
+            Some(share) => self.share_of(self.myid, share),
+            None => {
+                log::warn!("Node {} sent no blinding sub-share in epoch {}", msg.origin, epoch);
+                return;
+            }
+        };
+        if !self.vss.verify_blinding(&blinding, lost as u64 + 1, &commitments) {
+            log::warn!("The blinding sub-share of node {} does not match its commitments", msg.origin);
+            return;
+        }
+        let recovery_context = self.recovery_context.entry(epoch).or_default();
+        if recovery_context.blindings.contains_key(&msg.origin) {
+            return;
+        }
+        recovery_context.blindings.insert(msg.origin, (hash, blinding));
+
+        // The lost node only lists blindings the listed helpers hold
+        let held = HeldMsg {
+            blinder: msg.origin,
+            hash,
+            origin: self.myid,
+        };
+        let sec_key = self.net.sec_key_map.get(&lost).expect("Secret key not available").clone();
+        let wrapper_msg = WrapperMsg::new(ProtMsg::Held(held, epoch), self.myid, sec_key.as_slice());
+        self.net.send(lost, wrapper_msg).await;
+        self.try_answer(epoch).await;
+    }
+
+    pub async fn handle_held(&mut self, msg: HeldMsg, epoch: usize) {
+        self.start_recovery(epoch).await;
+
+        let helpers = self.helpers(epoch);
+        if self.lost_node(epoch) != self.myid || !helpers.contains(&msg.origin) || !helpers.contains(&msg.blinder) {
+            log::warn!("Unexpected held blinding from node {} in epoch {}", msg.origin, epoch);
+            return;
+        }
+        if !self
+            .recovery_context
+            .entry(epoch)
+            .or_default()
+            .held
+            .insert((msg.origin, msg.blinder, msg.hash))
+        {
+            return;
+        }
+        self.propose_blinders(epoch).await;
+    }
+
+    /// The lost node lists every t + 1 helpers that hold each other's blindings, as they match the commitments that
+    /// reached it. Only the listed helpers answer a list, and the honest helpers make one eventually, so a helper
+    /// that withholds its blinding or its answer can't stall the recovery.
+    pub async fn propose_blinders(&mut self, epoch: usize) {
+        let threshold = self.vss.sss.threshold;
+        let recovery_context = self.recovery_context.entry(epoch).or_default();
+        if recovery_context.terminated {
+            return;
+        }
+        let held = &recovery_context.held;
+        let mut candidates: Vec<(Replica, Hash)> = recovery_context
+            .blinding_commitments
+            .iter()
+            .map(|(helper, hash, _)| (*helper, *hash))
+            .filter(|(helper, hash)| held.contains(&(*helper, *helper, *hash)))
+            .collect();
+        candidates.sort();
+        let fit = |(a, a_hash): &(Replica, Hash), (b, b_hash): &(Replica, Hash)| {
+            held.contains(&(*a, *b, *b_hash)) && held.contains(&(*b, *a, *a_hash))
+        };
+        let lists: Vec<Blinders> = cliques(&candidates, threshold, &fit)
+            .into_iter()
+            .filter(|list| !recovery_context.blinders.contains(list))
+            .collect();
+        recovery_context.blinders.extend(lists.iter().cloned());
+
+        for blinders in lists {
+            log::info!("Blinders for epoch {}: {:?}", epoch, blinders);
+            let msg = BlindersMsg {
+                blinders: blinders.clone(),
+                origin: self.myid,
+            };
+            for (helper, _) in blinders {
+                let sec_key = self.net.sec_key_map.get(&helper).expect("Secret key not available").clone();
+                let wrapper_msg = WrapperMsg::new(ProtMsg::Blinders(msg.clone(), epoch), self.myid, sec_key.as_slice());
+                self.net.send(helper, wrapper_msg).await;
+            }
+        }
+    }
+
+    pub async fn handle_blinders(&mut self, msg: BlindersMsg, epoch: usize) {
+        self.start_recovery(epoch).await;
+
+        let helpers = self.helpers(epoch);
+        if msg.origin != self.lost_node(epoch) || !helpers.contains(&self.myid) {
+            log::warn!("Node {} can't pick the blinders of epoch {}", msg.origin, epoch);
+            return;
+        }
+        let distinct: HashSet<Replica> = msg.blinders.iter().map(|(helper, _)| *helper).collect();
+        if distinct.len() != msg.blinders.len()
+            || msg.blinders.len() != self.vss.sss.threshold
+            || !distinct.iter().all(|helper| helpers.contains(helper))
+            || !distinct.contains(&self.myid)
+        {
+            log::warn!("Node {} listed {:?}, not t + 1 distinct helpers with this node", msg.origin, msg.blinders);
+            return;
+        }
+        let recovery_context = self.recovery_context.entry(epoch).or_default();
+        if recovery_context.blinders.contains(&msg.blinders) {
+            return;
+        }
+        recovery_context.blinders.push(msg.blinders);
+        self.try_answer(epoch).await;
+    }
+}
+
+/// Every `size` candidates that fit pairwise, each in the order of `candidates`.
+fn cliques<T: Clone>(candidates: &[T], size: usize, fit: &impl Fn(&T, &T) -> bool) -> Vec<Vec<T>> {
+    let mut cliques = Vec::new();
+    extend_clique(candidates, size, fit, &mut Vec::with_capacity(size), &mut cliques);
+    cliques
+}
+
+fn extend_clique<T: Clone>(
+    candidates: &[T],
+    size: usize,
+    fit: &impl Fn(&T, &T) -> bool,
+    clique: &mut Vec<T>,
+    cliques: &mut Vec<Vec<T>>,
+) {
+    if clique.len() == size {
+        cliques.push(clique.clone());
+        return;
+    }
+    for (i, candidate) in candidates.iter().enumerate() {
+        if candidates.len() - i < size - clique.len() {
+            break;
+        }
+        if clique.iter().all(|member| fit(member, candidate)) {
+            clique.push(candidate.clone());
+            extend_clique(&candidates[i + 1..], size, fit, clique, cliques);
+            clique.pop();
+        }
+    }
+}
@@ -0,0 +1,10 @@
+mod share;
+
+mod request;
+
+mod blinding;
+
+mod answer;
+
+mod recovery_state;
+pub use recovery_state::*;
@@ -0,0 +1,52 @@
+use std::collections::{HashMap, HashSet};
+
+use consensus::{Commitment, Share};
+use crypto::hash::Hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use types::Replica;
+
+/// A list of helpers whose blindings an answer adds up, with the hashes of their commitments.
+pub type Blinders = Vec<(Replica, Hash)>;
+
+pub struct RecoveryState {
+    /// Blinding sub-shares this helper checked, by helper, with the hash of the commitments they match
+    pub blindings: HashMap<Replica, (Hash, Share<FrField>)>,
+    /// Commitments to the blinding polynomials the recovering node received, in the order they arrived
+    pub blinding_commitments: Vec<(Replica, Hash, Vec<Commitment<BLS12381Curve>>)>,
+    /// The blindings the helpers hold, as (helper, blinder, hash of the blinder's commitments)
+    pub held: HashSet<(Replica, Replica, Hash)>,
+    /// The lists this node made or got from the recovering node
+    pub blinders: Vec<Blinders>,
+    /// Answers the recovering node checked, by list and helper
+    pub answers: HashMap<Blinders, HashMap<Replica, Share<FrField>>>,
+
+    pub started: bool,
+    pub dealt: bool,
+    /// The lists this helper answered
+    pub answered: HashSet<Blinders>,
+    pub terminated: bool,
+}
+
+impl RecoveryState {
+    pub fn new() -> RecoveryState {
+        RecoveryState {
+            blindings: HashMap::default(),
+            blinding_commitments: Vec::new(),
+            held: HashSet::default(),
+            blinders: Vec::new(),
+            answers: HashMap::default(),
+
+            started: false,
+            dealt: false,
+            answered: HashSet::default(),
+            terminated: false,
+        }
+    }
+}
+
+impl Default for RecoveryState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
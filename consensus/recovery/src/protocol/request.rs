@@ -0,0 +1,44 @@
+use consensus::commitments_to_bytes;
+use crypto::hash::do_hash;
+use types::{RBCSyncMsg, WrapperMsg};
+
+use crate::{Context, ProtMsg};
+
+impl Context {
+    pub async fn start_recovery(&mut self, epoch: usize) {
+        let recovery_context = self.recovery_context.entry(epoch).or_default();
+        if recovery_context.started {
+            return;
+        }
+        recovery_context.started = true;
+
+        let helpers = self.helpers(epoch);
+        if self.lost_node(epoch) == self.myid {
+            // Simulates losing the disk, the share is only back once the helpers answered
+            self.share = None;
+            log::info!("Lost the share in epoch {}, asking nodes {:?} for help", epoch, helpers);
+            for helper in helpers {
+                let sec_key = self.net.sec_key_map.get(&helper).expect("Secret key not available").clone();
+                let wrapper_msg = WrapperMsg::new(ProtMsg::Request(self.myid, epoch), self.myid, sec_key.as_slice());
+                self.net.send(helper, wrapper_msg).await;
+            }
+        } else if !helpers.contains(&self.myid) {
+            // Nodes that don't help have nothing to do
+            self.finish(epoch).await;
+        }
+    }
+
+    /// Reports g^{P(r + 1)} for the lost node r to the syncer.
+    pub async fn finish(&mut self, epoch: usize) {
+        self.recovery_context.entry(epoch).or_default().terminated = true;
+        let lost_party = self.lost_node(epoch) as u64 + 1;
+        let commitment = self.vss.commitment_at(&self.commitments, lost_party);
+        let digest: String = do_hash(&commitments_to_bytes(&[commitment]))
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        let data = bincode::serialize(&RBCSyncMsg { id: epoch, msg: digest })
+            .expect("Failed to serialize recovery output");
+        self.net.terminate(data).await;
+    }
+}
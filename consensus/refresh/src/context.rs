@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use common::{Event, Net};
+use config::Node;
+use consensus::{Commitment, FeldmanVSS, Share};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use tokio::sync::oneshot;
+use types::{RBCSyncMsg, Replica};
+
+use super::{ProtMsg, RefreshState};
+
+pub struct Context {
+    /// Networking context
+    pub net: Net<ProtMsg>,
+    /// Data context
+    pub num_nodes: usize,
+    pub myid: usize,
+    pub num_faults: usize,
+    pub(crate) byz: bool,
+
+    /// Feldman VSS with threshold t + 1 over the n nodes, node i holds the share at party index i + 1
+    pub vss: FeldmanVSS,
+    /// This node's share of the long-lived secret, once the dealer's share reached it
+    pub share: Option<Share<FrField>>,
+    /// Feldman commitments to the current sharing polynomial
+    pub commitments: Vec<Commitment<BLS12381Curve>>,
+    /// The latest epoch this node has opened
+    pub epoch: usize,
+    // Each refresh epoch is associated with a Unique Identifier.
+    pub refresh_context: HashMap<usize, RefreshState>,
+}
+
+impl Context {
+    pub fn spawn(config: Node, byz: bool) -> anyhow::Result<oneshot::Sender<()>> {
+        let (net, exit_tx) = Net::spawn(&config, byz);
+
+        let vss = FeldmanVSS::new(config.num_faults + 1, config.num_nodes)
+            .map_err(|e| anyhow!("Invalid refresh parameters: {}", e))?;
+
+        tokio::spawn(async move {
+            let mut c = Context {
+                net,
+                num_nodes: config.num_nodes,
+                myid: config.id,
+                byz,
+                num_faults: config.num_faults,
+                vss,
+                share: None,
+                commitments: Vec::new(),
+                epoch: 0,
+                refresh_context: HashMap::default(),
+            };
+
+            // The dealer hands out the shares of the long-lived secret before the first epoch
+            if c.myid == c.dealer() {
+                c.deal_secret().await;
+            }
+
+            // Run the consensus context
+            if let Err(e) = c.run().await {
+                log::error!("Consensus error: {}", e);
+            }
+        });
+
+        Ok(exit_tx)
+    }
+
+    pub async fn run(&mut self) -> Result<()> {
+        // The process starts listening to messages in this process.
+        // First, the node sends an alive message
+        self.net.alive().await;
+        loop {
+            match self.net.next().await? {
+                Event::Message(msg) => self.process_msg(msg).await,
+                Event::Start(value) => {
+                    // Every START message from the syncer opens a refresh epoch, the syncer sends it to
+                    // every node
+                    let epoch_msg: RBCSyncMsg = bincode::deserialize(&value)
+                        .expect("Unable to deserialize message received from the syncer");
+                    self.start_refresh(epoch_msg.id).await;
+                }
+                Event::Stop => break,
+            }
+        }
+        Ok(())
+    }
+
+    /// The node that deals the long-lived secret.
+    pub fn dealer(&self) -> Replica {
+        0
+    }
+
+    /// The node that picks the qualified dealers of an epoch.
+    pub fn leader(&self, epoch: usize) -> Replica {
+        epoch % self.num_nodes
+    }
+}
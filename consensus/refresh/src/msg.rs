@@ -0,0 +1,74 @@
+use std::fmt::Debug;
+
+use consensus::Share;
+use crypto::hash::Hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use serde::{Deserialize, Serialize};
+use types::Replica;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ShareMsg {
+    /// The receiver's share of the long-lived secret
+    pub share: Share<FrField>,
+    /// Compressed Feldman commitments to the sharing polynomial
+    pub commitments: Vec<u8>,
+    pub origin: Replica,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DealMsg {
+    /// The receiver's sub-share of the dealer's zero-sharing
+    pub share: Share<FrField>,
+    /// Compressed Feldman commitments to the zero-sharing polynomial
+    pub commitments: Vec<u8>,
+    pub origin: Replica,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VoteMsg {
+    pub dealer: Replica,
+    /// Hash of the dealer's commitments the vote refers to
+    pub hash: Hash,
+    pub origin: Replica,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QualifiedMsg {
+    /// The dealers whose zero-sharings every node adds, with the hashes of their commitments
+    pub dealers: Vec<(Replica, Hash)>,
+    pub origin: Replica,
+}
+
+/*
+before the first epoch, the dealer (node 0) shares a random long-lived secret with Feldman VSS and sends
+<share, s_i, commitments> to party i only. Party i keeps the share if it checks out against the commitments,
+and nobody else learns it
+
+this is how an epoch of the refresh protocol works
+1. Every node deals a Feldman sharing of zero and sends <deal, Z_j(i), commitments> to party i
+2. Party i checks the sub-share against the commitments, and that they commit to Z_j(0) = 0, and
+   broadcasts <accept, j, H(commitments)> or <complaint, j, H(commitments)>
+3. Dealer j answers a complaint from party i by broadcasting <reveal, Z_j(i), commitments>. Everyone checks
+   the revealed sub-share, and party i takes it as its own if it is valid
+4. A dealer with 2t+1 accepts on the same commitments and every complaint against them answered is qualified
+5. The epoch leader (epoch mod n) broadcasts <qualified, [(j, H(commitments_j))]> once n - t dealers qualify
+6. Every node waits until its own votes back the leader's list, t+1 accepts on each listed commitment so an
+   honest node checked it, then adds the sub-shares of the listed dealers to its share, updates the
+   commitments and terminates
+A node opens an epoch when the syncer's START for it arrives, or once t+1 nodes dealt in it, so that at least
+one honest node opened it. It keeps the deals of an epoch it hasn't opened and votes on them when it opens it,
+and only terminates epochs it opened, so a faulty node can't walk the others into an epoch the syncer never
+started. Every node deals in an epoch before it sends anything else for it, so messages for epochs later than
+the latest opened one plus one come from a faulty node and are dropped.
+A dealer that sends different commitments to different nodes, or nothing at all to some, can still get 2t+1
+accepts. The nodes it left out wait for the epoch, they keep their share and can't use the next one.
+*/
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum ProtMsg {
+    Share(ShareMsg),
+    Deal(DealMsg, usize), // Epoch
+    Accept(VoteMsg, usize),
+    Complaint(VoteMsg, usize),
+    Reveal(DealMsg, usize),
+    Qualified(QualifiedMsg, usize),
+}
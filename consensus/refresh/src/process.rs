@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use super::ProtMsg;
+use crate::context::Context;
+use types::WrapperMsg;
+
+impl Context {
+    pub(crate) async fn process_msg(&mut self, wrapper_msg: WrapperMsg<ProtMsg>) {
+        log::debug!("Received protocol msg: {:?}", wrapper_msg);
+        let msg = Arc::new(wrapper_msg.clone());
+
+        // Verify the message's authenticity before proceeding
+        if !self.net.check_proposal(msg) {
+            log::warn!(
+                "MAC Verification failed for message {:?}",
+                wrapper_msg.protmsg
+            );
+            return;
+        }
+        // Votes are counted per origin, so a node may only speak for itself
+        let origin = match &wrapper_msg.protmsg {
+            ProtMsg::Share(msg) => msg.origin,
+            ProtMsg::Deal(msg, _) | ProtMsg::Reveal(msg, _) => msg.origin,
+            ProtMsg::Accept(msg, _) | ProtMsg::Complaint(msg, _) => msg.origin,
+            ProtMsg::Qualified(msg, _) => msg.origin,
+        };
+        if origin != wrapper_msg.sender {
+            log::warn!("Node {} sent a message on behalf of node {}", wrapper_msg.sender, origin);
+            return;
+        }
+        let epoch = match &wrapper_msg.protmsg {
+            ProtMsg::Share(_) => None,
+            ProtMsg::Deal(_, epoch) | ProtMsg::Reveal(_, epoch) => Some(*epoch),
+            ProtMsg::Accept(_, epoch) | ProtMsg::Complaint(_, epoch) | ProtMsg::Qualified(_, epoch) => Some(*epoch),
+        };
+        // A node deals in an epoch before it sends anything else for it, and opens it only on the syncer's START or
+        // on deals from t + 1 nodes, so an honest node's messages never run more than one epoch ahead of the latest
+        // one this node opened
+        if let Some(epoch) = epoch {
+            if epoch > self.epoch + 1 {
+                log::warn!("Node {} sent a message for epoch {}, ahead of epoch {}", origin, epoch, self.epoch);
+                return;
+            }
+        }
+        match wrapper_msg.protmsg {
+            ProtMsg::Share(main_msg) => {
+                log::info!("Received Share from node : {:?}", main_msg.origin);
+                self.handle_share(main_msg).await;
+            }
+            ProtMsg::Deal(main_msg, epoch) => {
+                log::info!("Received Deal for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_deal(main_msg, epoch).await;
+            }
+            ProtMsg::Accept(main_msg, epoch) => {
+                log::info!("Received Accept for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_accept(main_msg, epoch).await;
+            }
+            ProtMsg::Complaint(main_msg, epoch) => {
+                log::info!("Received Complaint for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_complaint(main_msg, epoch).await;
+            }
+            ProtMsg::Reveal(main_msg, epoch) => {
+                log::info!("Received Reveal for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_reveal(main_msg, epoch).await;
+            }
+            ProtMsg::Qualified(main_msg, epoch) => {
+                log::info!("Received Qualified for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_qualified(main_msg, epoch).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use async_recursion::async_recursion;
+use consensus::{commitments_from_bytes, commitments_to_bytes, SecretSharingScheme, ShamirSecretSharing};
+use crypto::hash::do_hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{
+    FrElement, FrField,
+};
+use types::WrapperMsg;
+
+use crate::{Context, DealMsg, ProtMsg, SubShare, VoteMsg};
+
+impl Context {
+    /// Opens the epoch: deals this node's zero-sharing and votes on the deals that were waiting for it.
+    #[async_recursion]
+    pub async fn start_refresh(&mut self, epoch: usize) {
+        let refresh_context = self.refresh_context.entry(epoch).or_default();
+        if refresh_context.dealing.is_some() {
+            return;
+        }
+
+        let dealing = if self.byz {
+            // Tries to shift the secret with a sharing of one, which the commitments give away
+            self.vss.deal(FrElement::one())
+        } else {
+            self.vss.deal_zero()
+        };
+        let (sub_shares, commitments) = match dealing {
+            Ok(dealing) => dealing,
+            Err(e) => {
+                log::error!("Failed to deal a zero-sharing for epoch {}: {}", epoch, e);
+                return;
+            }
+        };
+        let commitments = commitments_to_bytes(&commitments);
+        refresh_context.dealing = Some((sub_shares.clone(), commitments.clone()));
+        let pending = std::mem::take(&mut refresh_context.pending);
+        self.epoch = self.epoch.max(epoch);
+        log::info!("Dealt a zero-sharing for epoch {}", epoch);
+
+        // Every node gets its own sub-share, party i is node i - 1
+        let sec_key_map = self.net.sec_key_map.clone();
+        for (replica, sec_key) in sec_key_map.into_iter() {
+            let msg = DealMsg {
+                share: sub_shares[replica].clone(),
+                commitments: commitments.clone(),
+                origin: self.myid,
+            };
+            if replica == self.myid {
+                self.handle_deal(msg, epoch).await;
+                continue;
+            }
+            let wrapper_msg = WrapperMsg::new(ProtMsg::Deal(msg, epoch), self.myid, sec_key.as_slice());
+            self.net.send(replica, wrapper_msg).await;
+        }
+        for msg in pending.into_values() {
+            self.handle_deal(msg, epoch).await;
+        }
+    }
+
+    /// Checks a sub-share dealt to `party` against the dealer's commitments, which must commit to a sharing of zero.
+    pub(crate) fn check_sub_share(&self, msg: &DealMsg, party: usize) -> Option<SubShare> {
+        let commitments = match commitments_from_bytes(&msg.commitments) {
+            Ok(commitments) => commitments,
+            Err(e) => {
+                log::warn!("Invalid commitments from dealer {}: {}", msg.origin, e);
+                return None;
+            }
+        };
+        // The point is the receiver's, whatever index and scheme the dealer put in the share
+        let share = <ShamirSecretSharing<FrField> as SecretSharingScheme>::make_share(
+            &self.vss.sss,
+            party as u64 + 1,
+            msg.share.value.clone(),
+        );
+        if !self.vss.verify_zero_share(&share, &commitments) {
+            return None;
+        }
+        Some(SubShare {
+            hash: do_hash(&msg.commitments),
+            share,
+            commitments,
+        })
+    }
+
+    #[async_recursion]
+    pub async fn handle_deal(&mut self, msg: DealMsg, epoch: usize) {
+        let num_faults = self.num_faults;
+        let refresh_context = self.refresh_context.entry(epoch).or_default();
+        if refresh_context.dealing.is_none() {
+            // One node can't open an epoch for the others. Deals from t + 1 nodes mean an honest node opened it,
+            // so this node joins without waiting for its own START
+            refresh_context.pending.entry(msg.origin).or_insert(msg);
+            if refresh_context.pending.len() > num_faults {
+                self.start_refresh(epoch).await;
+            }
+            return;
+        }
+        if refresh_context.terminated || !refresh_context.voted.insert(msg.origin) {
+            return;
+        }
+
+        let hash = do_hash(&msg.commitments);
+        let vote = VoteMsg {
+            dealer: msg.origin,
+            hash,
+            origin: self.myid,
+        };
+        match self.check_sub_share(&msg, self.myid) {
+            Some(sub_share) => {
+                log::info!("Accepted the zero-sharing of dealer {} for epoch {}", msg.origin, epoch);
+                self.refresh_context
+                    .entry(epoch)
+                    .or_default()
+                    .sub_shares
+                    .insert(msg.origin, sub_share);
+                self.net.broadcast(ProtMsg::Accept(vote.clone(), epoch)).await;
+                self.handle_accept(vote, epoch).await;
+            }
+            None => {
+                log::warn!("Rejected the zero-sharing of dealer {} for epoch {}", msg.origin, epoch);
+                self.net.broadcast(ProtMsg::Complaint(vote.clone(), epoch)).await;
+                self.handle_complaint(vote, epoch).await;
+            }
+        }
+        self.try_finish(epoch).await;
+    }
+}
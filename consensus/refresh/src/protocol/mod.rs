@@ -0,0 +1,12 @@
+mod share;
+
+mod deal;
+
+mod vote;
+
+mod qualified;
+
+mod reveal;
+
+mod refresh_state;
+pub use refresh_state::*;
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use consensus::commitments_to_bytes;
+use crypto::hash::do_hash;
+use types::RBCSyncMsg;
+
+use crate::{Context, QualifiedMsg};
+
+impl Context {
+    pub async fn handle_qualified(&mut self, msg: QualifiedMsg, epoch: usize) {
+        if msg.origin != self.leader(epoch) {
+            log::warn!("Node {} is not the leader of epoch {}", msg.origin, epoch);
+            return;
+        }
+        let distinct: HashSet<_> = msg.dealers.iter().map(|(dealer, _)| *dealer).collect();
+        if distinct.len() != msg.dealers.len() {
+            log::warn!("The leader of epoch {} listed a dealer twice", epoch);
+            return;
+        }
+        if msg.dealers.len() < self.num_nodes - self.num_faults {
+            log::warn!("The leader of epoch {} listed {} dealers only", epoch, msg.dealers.len());
+            return;
+        }
+        let refresh_context = self.refresh_context.entry(epoch).or_default();
+        if refresh_context.dealers.is_some() {
+            return;
+        }
+        refresh_context.dealers = Some(msg.dealers);
+        self.try_finish(epoch).await;
+    }
+
+    /// Adds the sub-shares of the listed dealers once this node's votes back the list and it holds all of them.
+    pub async fn try_finish(&mut self, epoch: usize) {
+        let num_faults = self.num_faults;
+        let refresh_context = self.refresh_context.entry(epoch).or_default();
+        let dealers = match &refresh_context.dealers {
+            // Only an epoch this node opened terminates
+            Some(dealers) if refresh_context.dealing.is_some() && !refresh_context.terminated => dealers,
+            _ => return,
+        };
+        // Wait for the votes, and for the dealer's share of the secret
+        if !refresh_context.is_backed(dealers, num_faults) {
+            return;
+        }
+        let old_share = match &self.share {
+            Some(share) => share,
+            None => return,
+        };
+
+        let mut sub_shares = Vec::with_capacity(dealers.len());
+        let mut zero_commitments = Vec::with_capacity(dealers.len());
+        for (dealer, hash) in dealers {
+            match refresh_context.sub_shares.get(dealer) {
+                Some(sub_share) if sub_share.hash == *hash => {
+                    sub_shares.push(sub_share.share.clone());
+                    zero_commitments.push(sub_share.commitments.clone());
+                }
+                Some(_) => {
+                    log::error!("Dealer {} sent this node other commitments than the leader listed", dealer);
+                    return;
+                }
+                // Wait for the deal or the reveal
+                None => return,
+            }
+        }
+
+        let refreshed = self.vss.combine_commitments(&zero_commitments).and_then(|zero_commitments| {
+            let share = self.vss.refresh_share(old_share, &sub_shares)?;
+            let commitments = self
+                .vss
+                .combine_commitments(&[self.commitments.clone(), zero_commitments.clone()])?;
+            Ok((share, commitments, zero_commitments))
+        });
+        let (share, commitments, zero_commitments) = match refreshed {
+            Ok(refreshed) => refreshed,
+            Err(e) => {
+                log::error!("Failed to refresh the share in epoch {}: {}", epoch, e);
+                return;
+            }
+        };
+        if !self.vss.verify_share(&share, &commitments) {
+            log::error!("The refreshed share does not match the refreshed commitments in epoch {}", epoch);
+            return;
+        }
+        self.share = Some(share);
+        self.commitments = commitments;
+        self.refresh_context.entry(epoch).or_default().terminated = true;
+        log::info!("Refreshed the share in epoch {}", epoch);
+
+        // Nodes agree on the sum of the accepted zero-sharings, the shares themselves differ
+        let digest: String = do_hash(&commitments_to_bytes(&zero_commitments))
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        let data = bincode::serialize(&RBCSyncMsg { id: epoch, msg: digest })
+            .expect("Failed to serialize refresh output");
+        self.net.terminate(data).await;
+    }
+}
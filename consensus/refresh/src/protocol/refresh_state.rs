@@ -0,0 +1,82 @@
+use std::collections::{HashMap, HashSet};
+
+use consensus::{Commitment, Share};
+use crypto::hash::Hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use types::Replica;
+
+use crate::DealMsg;
+
+/// A sub-share that passed the Feldman checks, with the commitments it was checked against.
+pub struct SubShare {
+    pub hash: Hash,
+    pub share: Share<FrField>,
+    pub commitments: Vec<Commitment<BLS12381Curve>>,
+}
+
+pub struct RefreshState {
+    pub sub_shares: HashMap<Replica, SubShare>,
+    /// Dealers this node already voted on
+    pub voted: HashSet<Replica>,
+
+    // Votes are keyed by the dealer and the hash of its commitments
+    pub accept_senders: HashMap<(Replica, Hash), HashSet<Replica>>,
+    pub complaint_senders: HashMap<(Replica, Hash), HashSet<Replica>>,
+    /// Complainers whose sub-share the dealer revealed
+    pub revealed: HashMap<(Replica, Hash), HashSet<Replica>>,
+    /// The dealers chosen by the epoch leader
+    pub dealers: Option<Vec<(Replica, Hash)>>,
+    /// Deals that reached this node before it opened the epoch, one per dealer
+    pub pending: HashMap<Replica, DealMsg>,
+
+    /// This node's zero-sharing, all sub-shares and the compressed commitments. Set once the epoch is open
+    pub dealing: Option<(Vec<Share<FrField>>, Vec<u8>)>,
+    pub proposed: bool,
+    pub terminated: bool,
+}
+
+impl RefreshState {
+    pub fn new() -> RefreshState {
+        RefreshState {
+            sub_shares: HashMap::default(),
+            voted: HashSet::default(),
+
+            accept_senders: HashMap::default(),
+            complaint_senders: HashMap::default(),
+            revealed: HashMap::default(),
+            dealers: None,
+            pending: HashMap::default(),
+
+            dealing: None,
+            proposed: false,
+            terminated: false,
+        }
+    }
+
+    /// A dealer is qualified with 2t + 1 accepts on its commitments and every complaint against them answered.
+    pub fn is_qualified(&self, dealer: Replica, hash: Hash, num_faults: usize) -> bool {
+        let accepts = self.accept_senders.get(&(dealer, hash)).map_or(0, HashSet::len);
+        let answered = match (
+            self.complaint_senders.get(&(dealer, hash)),
+            self.revealed.get(&(dealer, hash)),
+        ) {
+            (None, _) => true,
+            (Some(complainers), Some(revealed)) => complainers.is_subset(revealed),
+            (Some(complainers), None) => complainers.is_empty(),
+        };
+        accepts > 2 * num_faults && answered
+    }
+
+    /// Checks the leader's list against this node's votes: t + 1 accepts on each listed commitment mean an honest
+    /// node checked it, and every honest node sees them eventually.
+    pub fn is_backed(&self, dealers: &[(Replica, Hash)], num_faults: usize) -> bool {
+        dealers.iter().all(|key| self.accept_senders.get(key).map_or(0, HashSet::len) > num_faults)
+    }
+}
+
+impl Default for RefreshState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
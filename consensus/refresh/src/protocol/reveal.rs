@@ -0,0 +1,38 @@
+use crypto::hash::do_hash;
+
+use crate::{Context, DealMsg};
+
+impl Context {
+    /// A dealer's answer to a complaint, the complainer's sub-share in the clear.
+    pub async fn handle_reveal(&mut self, msg: DealMsg, epoch: usize) {
+        let party = match msg.share.index {
+            index if index >= 1 && index <= self.num_nodes as u64 => index as usize - 1,
+            index => {
+                log::warn!("Dealer {} revealed a sub-share for unknown party {}", msg.origin, index);
+                return;
+            }
+        };
+        let sub_share = match self.check_sub_share(&msg, party) {
+            Some(sub_share) => sub_share,
+            None => {
+                log::warn!("Dealer {} revealed an invalid sub-share for party {}", msg.origin, party + 1);
+                return;
+            }
+        };
+
+        let refresh_context = self.refresh_context.entry(epoch).or_default();
+        if !refresh_context
+            .revealed
+            .entry((msg.origin, do_hash(&msg.commitments)))
+            .or_default()
+            .insert(party)
+        {
+            return;
+        }
+        if party == self.myid {
+            refresh_context.sub_shares.entry(msg.origin).or_insert(sub_share);
+        }
+        self.propose(epoch).await;
+        self.try_finish(epoch).await;
+    }
+}
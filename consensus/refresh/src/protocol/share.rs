@@ -0,0 +1,74 @@
+use consensus::{commitments_from_bytes, commitments_to_bytes, SecretSharingScheme, ShamirSecretSharing};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use types::WrapperMsg;
+
+use crate::{Context, ProtMsg, ShareMsg};
+
+impl Context {
+    /// The dealer shares a random secret and sends every node only its own share.
+    pub async fn deal_secret(&mut self) {
+        let secret = ShamirSecretSharing::<FrField>::rand_field_element();
+        let (shares, commitments) = match self.vss.deal(secret) {
+            Ok(dealing) => dealing,
+            Err(e) => {
+                log::error!("Failed to deal the long-lived secret: {}", e);
+                return;
+            }
+        };
+        let compressed = commitments_to_bytes(&commitments);
+        self.share = Some(shares[self.myid].clone());
+        self.commitments = commitments;
+        log::info!("Dealt the long-lived secret");
+
+        let sec_key_map = self.net.sec_key_map.clone();
+        for (replica, sec_key) in sec_key_map.into_iter() {
+            if replica == self.myid {
+                continue;
+            }
+            let msg = ShareMsg {
+                share: shares[replica].clone(),
+                commitments: compressed.clone(),
+                origin: self.myid,
+            };
+            let wrapper_msg = WrapperMsg::new(ProtMsg::Share(msg), self.myid, sec_key.as_slice());
+            self.net.send(replica, wrapper_msg).await;
+        }
+    }
+
+    /// Keeps the dealer's share of the long-lived secret if it matches the commitments, and finishes the epochs
+    /// that were waiting for it.
+    pub async fn handle_share(&mut self, msg: ShareMsg) {
+        if msg.origin != self.dealer() {
+            log::warn!("Node {} is not the dealer of the long-lived secret", msg.origin);
+            return;
+        }
+        if self.share.is_some() {
+            return;
+        }
+        let commitments = match commitments_from_bytes(&msg.commitments) {
+            Ok(commitments) => commitments,
+            Err(e) => {
+                log::error!("Invalid commitments from the dealer: {}", e);
+                return;
+            }
+        };
+        let share = <ShamirSecretSharing<FrField> as SecretSharingScheme>::make_share(
+            &self.vss.sss,
+            self.myid as u64 + 1,
+            msg.share.value.clone(),
+        );
+        if !self.vss.verify_share(&share, &commitments) {
+            log::error!("The dealer's share does not match its commitments");
+            return;
+        }
+        self.share = Some(share);
+        self.commitments = commitments;
+        log::info!("Received the share of the long-lived secret");
+
+        let mut epochs: Vec<usize> = self.refresh_context.keys().cloned().collect();
+        epochs.sort();
+        for epoch in epochs {
+            self.try_finish(epoch).await;
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crypto::hash::do_hash;
+
+use crate::{Context, DealMsg, ProtMsg, QualifiedMsg, VoteMsg};
+
+impl Context {
+    pub async fn handle_accept(&mut self, msg: VoteMsg, epoch: usize) {
+        let refresh_context = self.refresh_context.entry(epoch).or_default();
+        if !refresh_context
+            .accept_senders
+            .entry((msg.dealer, msg.hash))
+            .or_default()
+            .insert(msg.origin)
+        {
+            return;
+        }
+        self.propose(epoch).await;
+        self.try_finish(epoch).await;
+    }
+
+    pub async fn handle_complaint(&mut self, msg: VoteMsg, epoch: usize) {
+        let refresh_context = self.refresh_context.entry(epoch).or_default();
+        if !refresh_context
+            .complaint_senders
+            .entry((msg.dealer, msg.hash))
+            .or_default()
+            .insert(msg.origin)
+        {
+            return;
+        }
+        if msg.dealer != self.myid {
+            return;
+        }
+        // Answer with the complainer's sub-share, which only checks out against the commitments of an honest dealer
+        let reveal = match &refresh_context.dealing {
+            Some((sub_shares, commitments)) if do_hash(commitments) == msg.hash => DealMsg {
+                share: sub_shares[msg.origin].clone(),
+                commitments: commitments.clone(),
+                origin: self.myid,
+            },
+            _ => return,
+        };
+        self.net.broadcast(ProtMsg::Reveal(reveal.clone(), epoch)).await;
+        self.handle_reveal(reveal, epoch).await;
+    }
+
+    /// The epoch leader lists the qualified dealers once there are n - t of them.
+    pub async fn propose(&mut self, epoch: usize) {
+        if self.leader(epoch) != self.myid {
+            return;
+        }
+        let num_faults = self.num_faults;
+        let refresh_context = self.refresh_context.entry(epoch).or_default();
+        if refresh_context.proposed {
+            return;
+        }
+        let mut dealers: Vec<_> = refresh_context
+            .accept_senders
+            .keys()
+            .filter(|(dealer, hash)| refresh_context.is_qualified(*dealer, *hash, num_faults))
+            .cloned()
+            .collect();
+        if dealers.len() < self.num_nodes - num_faults {
+            return;
+        }
+        refresh_context.proposed = true;
+        dealers.sort();
+        log::info!("Qualified dealers for epoch {}: {:?}", epoch, dealers);
+
+        let msg = QualifiedMsg {
+            dealers,
+            origin: self.myid,
+        };
+        self.net.broadcast(ProtMsg::Qualified(msg.clone(), epoch)).await;
+        self.handle_qualified(msg, epoch).await;
+    }
+}
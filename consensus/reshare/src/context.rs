@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use common::{Event, Net};
+use config::Node;
+use consensus::{Commitment, FeldmanVSS, Resharing, Share};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use tokio::sync::oneshot;
+use types::{RBCSyncMsg, Replica};
+
+use super::{ProtMsg, ReshareState};
+
+pub struct Context {
+    /// Networking context
+    pub net: Net<ProtMsg>,
+    /// Data context
+    pub num_nodes: usize,
+    pub myid: usize,
+    pub num_faults: usize,
+    pub(crate) byz: bool,
+
+    /// The nodes of the old and the new committee, party i is the node at position i - 1
+    pub old_members: Vec<Replica>,
+    pub new_members: Vec<Replica>,
+    /// This node's party index in each committee, if it is a member
+    pub old_party: Option<u64>,
+    pub new_party: Option<u64>,
+    /// Feldman VSS of the old (t, n) and the new (t', n') committee
+    pub old_vss: FeldmanVSS,
+    pub new_vss: FeldmanVSS,
+    pub resharing: Resharing<FrField>,
+    /// This node's share in the old committee, once the dealer's share reached it
+    pub share: Option<Share<FrField>>,
+    /// Feldman commitments to the old sharing polynomial, empty until the dealer sent them
+    pub commitments: Vec<Commitment<BLS12381Curve>>,
+    /// Sub-shares and reveals that arrived before the old commitments
+    pub pending: Vec<ProtMsg>,
+    // Each resharing epoch is associated with a Unique Identifier.
+    pub reshare_context: HashMap<usize, ReshareState>,
+}
+
+impl Context {
+    pub fn spawn(
+        config: Node,
+        old_committee: Node,
+        new_committee: Node,
+        byz: bool,
+    ) -> anyhow::Result<oneshot::Sender<()>> {
+        let (net, exit_tx) = Net::spawn(&config, byz);
+
+        let old_members = members(&config, &old_committee)?;
+        let new_members = members(&config, &new_committee)?;
+        let party = |members: &Vec<Replica>| {
+            members
+                .iter()
+                .position(|replica| *replica == config.id)
+                .map(|position| position as u64 + 1)
+        };
+        let (old_party, new_party) = (party(&old_members), party(&new_members));
+        let old_vss = FeldmanVSS::new(old_committee.num_faults + 1, old_committee.num_nodes)
+            .map_err(|e| anyhow!("Invalid old committee: {}", e))?;
+        let new_vss = FeldmanVSS::new(new_committee.num_faults + 1, new_committee.num_nodes)
+            .map_err(|e| anyhow!("Invalid new committee: {}", e))?;
+        let resharing = Resharing::new(old_vss.sss.clone(), new_vss.sss.clone());
+
+        tokio::spawn(async move {
+            let mut c = Context {
+                net,
+                num_nodes: config.num_nodes,
+                myid: config.id,
+                byz,
+                num_faults: config.num_faults,
+                old_members,
+                new_members,
+                old_party,
+                new_party,
+                old_vss,
+                new_vss,
+                resharing,
+                share: None,
+                commitments: Vec::new(),
+                pending: Vec::new(),
+                reshare_context: HashMap::default(),
+            };
+
+            // The dealer shares the secret among the old committee before the first epoch
+            if c.myid == c.dealer() {
+                c.deal_secret().await;
+            }
+
+            // Run the consensus context
+            if let Err(e) = c.run().await {
+                log::error!("Consensus error: {}", e);
+            }
+        });
+
+        Ok(exit_tx)
+    }
+
+    pub async fn run(&mut self) -> Result<()> {
+        // The process starts listening to messages in this process.
+        // First, the node sends an alive message
+        self.net.alive().await;
+        loop {
+            match self.net.next().await? {
+                Event::Message(msg) => self.process_msg(msg).await,
+                Event::Start(value) => {
+                    // Every START message from the syncer opens a resharing epoch, which this node
+                    // announces to the others
+                    let epoch_msg: RBCSyncMsg = bincode::deserialize(&value)
+                        .expect("Unable to deserialize message received from the syncer");
+                    self.net.broadcast(ProtMsg::Start(self.myid, epoch_msg.id)).await;
+                    self.start_reshare(epoch_msg.id).await;
+                }
+                Event::Stop => break,
+            }
+        }
+        Ok(())
+    }
+
+    /// The node that deals the secret among the old committee.
+    pub fn dealer(&self) -> Replica {
+        0
+    }
+
+    /// The node of the new committee that picks the dealers of an epoch.
+    pub fn leader(&self, epoch: usize) -> Replica {
+        self.new_members[epoch % self.new_members.len()]
+    }
+
+    /// The number of faults the new committee tolerates, t' - 1.
+    pub fn new_faults(&self) -> usize {
+        self.new_vss.sss.threshold - 1
+    }
+}
+
+/// The nodes of a committee, matched to the network's nodes by address.
+pub fn members(config: &Node, committee: &Node) -> Result<Vec<Replica>> {
+    (0..committee.num_nodes)
+        .map(|party| {
+            let address = committee
+                .net_map
+                .get(&party)
+                .ok_or_else(|| anyhow!("Committee member {} has no address", party))?;
+            (0..config.num_nodes)
+                .find(|replica| config.net_map.get(replica) == Some(address))
+                .ok_or_else(|| anyhow!("Committee member {} at {} is not a node of the network", party, address))
+        })
+        .collect()
+}
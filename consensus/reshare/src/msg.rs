@@ -0,0 +1,77 @@
+use std::fmt::Debug;
+
+use consensus::Share;
+use crypto::hash::Hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use serde::{Deserialize, Serialize};
+use types::Replica;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ShareMsg {
+    /// The receiver's share of the secret in the old committee, none for nodes outside it
+    pub share: Option<Share<FrField>>,
+    /// Compressed Feldman commitments to the old sharing polynomial
+    pub commitments: Vec<u8>,
+    pub origin: Replica,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SubShareMsg {
+    /// The receiver's sub-share of the dealer's old share
+    pub share: Share<FrField>,
+    /// Compressed Feldman commitments to the polynomial the dealer reshared its share with
+    pub commitments: Vec<u8>,
+    pub origin: Replica,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VoteMsg {
+    /// The old party the vote is about
+    pub dealer: u64,
+    /// Hash of the dealer's commitments the vote refers to
+    pub hash: Hash,
+    pub origin: Replica,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DealersMsg {
+    /// The old parties whose sub-shares every new party combines, t of them, with the hashes of their commitments
+    pub dealers: Vec<(u64, Hash)>,
+    /// The compressed commitments of the listed dealers, in the same order
+    pub commitments: Vec<Vec<u8>>,
+    pub origin: Replica,
+}
+
+/*
+before the first epoch, the dealer (node 0) shares a random secret among the old committee with Feldman VSS.
+It sends <share, P(i), commitments> to old party i only, and the commitments to every other node
+
+this is how an epoch of the resharing protocol works
+The old committee holds the secret in a (t, n) Feldman sharing, the new committee gets a (t', n') one
+1. Every node that gets the START from the syncer sends <start> to everyone
+2. Every old party i reshares its share P(i) with a polynomial Q_i of degree t' - 1 and sends
+   <subshare, Q_i(j), commitments to Q_i> to new party j
+3. New party j checks Q_i(j) against the commitments, and that g^{Q_i(0)} = g^{P(i)} from the old commitments,
+   and broadcasts <accept, i, H(commitments)> or <complaint, i, H(commitments)>
+4. Old party i answers a complaint from new party j by broadcasting <reveal, Q_i(j), commitments>. Everyone
+   checks the revealed sub-share, and new party j takes it as its own if it is valid
+5. An old party with 2t'+1 accepts on the same commitments and every complaint against them answered is
+   qualified. The leader of the new committee (epoch mod n') broadcasts <dealers, [(i, H(commitments_i))],
+   [commitments_i]> once t old parties qualify
+6. Every node waits until its own votes back the leader's list, t'+1 accepts on each listed commitment so an
+   honest new party checked it. It combines the listed commitments with the Lagrange weights of the dealers,
+   checks that they keep g^{P(0)} and terminates, whether or not it is a member of either committee
+7. New party j combines the sub-shares of the listed dealers once it holds all of them. A listed dealer that
+   got its accepts without ever sending j a sub-share leaves j without a share, to be recovered from the others
+Everybody reports g^{P(0)}, which the resharing must not change
+*/
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum ProtMsg {
+    Share(ShareMsg),
+    Start(Replica, usize), // Origin, Epoch
+    SubShare(SubShareMsg, usize),
+    Accept(VoteMsg, usize),
+    Complaint(VoteMsg, usize),
+    Reveal(SubShareMsg, usize),
+    Dealers(DealersMsg, usize),
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use super::ProtMsg;
+use crate::context::Context;
+use types::WrapperMsg;
+
+impl Context {
+    pub(crate) async fn process_msg(&mut self, wrapper_msg: WrapperMsg<ProtMsg>) {
+        log::debug!("Received protocol msg: {:?}", wrapper_msg);
+        let msg = Arc::new(wrapper_msg.clone());
+
+        // Verify the message's authenticity before proceeding
+        if !self.net.check_proposal(msg) {
+            log::warn!(
+                "MAC Verification failed for message {:?}",
+                wrapper_msg.protmsg
+            );
+            return;
+        }
+        let origin = match &wrapper_msg.protmsg {
+            ProtMsg::Share(msg) => msg.origin,
+            ProtMsg::Start(origin, _) => *origin,
+            ProtMsg::SubShare(msg, _) => msg.origin,
+            ProtMsg::Accept(msg, _) => msg.origin,
+            ProtMsg::Complaint(msg, _) => msg.origin,
+            ProtMsg::Reveal(msg, _) => msg.origin,
+            ProtMsg::Dealers(msg, _) => msg.origin,
+        };
+        if origin != wrapper_msg.sender {
+            log::warn!("Node {} sent a message on behalf of node {}", wrapper_msg.sender, origin);
+            return;
+        }
+        match wrapper_msg.protmsg {
+            ProtMsg::Share(main_msg) => {
+                log::info!("Received Share from node : {:?}", main_msg.origin);
+                self.handle_share(main_msg).await;
+            }
+            ProtMsg::Start(origin, epoch) => {
+                log::info!("Received Start for epoch {} from node : {:?}", epoch, origin);
+                self.start_reshare(epoch).await;
+            }
+            ProtMsg::SubShare(main_msg, epoch) => {
+                log::info!("Received SubShare for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_sub_share(main_msg, epoch).await;
+            }
+            ProtMsg::Accept(main_msg, epoch) => {
+                log::info!("Received Accept for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_accept(main_msg, epoch).await;
+            }
+            ProtMsg::Complaint(main_msg, epoch) => {
+                log::info!("Received Complaint for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_complaint(main_msg, epoch).await;
+            }
+            ProtMsg::Reveal(main_msg, epoch) => {
+                log::info!("Received Reveal for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_reveal(main_msg, epoch).await;
+            }
+            ProtMsg::Dealers(main_msg, epoch) => {
+                log::info!("Received Dealers for epoch {} from node : {:?}", epoch, main_msg.origin);
+                self.handle_dealers(main_msg, epoch).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+
+use async_recursion::async_recursion;
+use consensus::{commitments_from_bytes, commitments_to_bytes, Commitment};
+use crypto::hash::{do_hash, Hash};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+
+use crate::{Context, DealersMsg, ProtMsg};
+
+impl Context {
+    /// The leader of the new committee picks the first t qualified old parties, one set of commitments each.
+    #[async_recursion]
+    pub async fn propose(&mut self, epoch: usize) {
+        if self.leader(epoch) != self.myid {
+            return;
+        }
+        let threshold = self.resharing.old.threshold;
+        let new_faults = self.new_faults();
+        let reshare_context = self.reshare_context.entry(epoch).or_default();
+        if reshare_context.proposed {
+            return;
+        }
+        let mut qualified: Vec<(u64, Hash)> = reshare_context
+            .dealings
+            .keys()
+            .filter(|(dealer, hash)| reshare_context.is_qualified(*dealer, *hash, new_faults))
+            .cloned()
+            .collect();
+        qualified.sort();
+        // A dealer that got two sets of commitments qualified is listed with the first
+        qualified.dedup_by_key(|(dealer, _)| *dealer);
+        if qualified.len() < threshold {
+            return;
+        }
+        qualified.truncate(threshold);
+        reshare_context.proposed = true;
+        let commitments = qualified
+            .iter()
+            .map(|key| commitments_to_bytes(&reshare_context.dealings[key]))
+            .collect();
+        log::info!("Dealers for epoch {}: {:?}", epoch, qualified);
+
+        let msg = DealersMsg {
+            dealers: qualified,
+            commitments,
+            origin: self.myid,
+        };
+        self.net.broadcast(ProtMsg::Dealers(msg.clone(), epoch)).await;
+        self.handle_dealers(msg, epoch).await;
+    }
+
+    #[async_recursion]
+    pub async fn handle_dealers(&mut self, msg: DealersMsg, epoch: usize) {
+        if msg.origin != self.leader(epoch) {
+            log::warn!("Node {} is not the leader of epoch {}", msg.origin, epoch);
+            return;
+        }
+        let distinct: HashSet<u64> = msg.dealers.iter().map(|(dealer, _)| *dealer).collect();
+        if distinct.len() != msg.dealers.len()
+            || msg.dealers.len() != self.resharing.old.threshold
+            || msg.commitments.len() != msg.dealers.len()
+        {
+            log::warn!("The leader of epoch {} listed {:?}, not t distinct old parties", epoch, msg.dealers);
+            return;
+        }
+        let mut dealer_commitments = Vec::with_capacity(msg.dealers.len());
+        for ((dealer, hash), bytes) in msg.dealers.iter().zip(&msg.commitments) {
+            if do_hash(bytes) != *hash {
+                log::warn!("The leader of epoch {} sent commitments of old party {} that do not match their hash", epoch, dealer);
+                return;
+            }
+            match commitments_from_bytes(bytes) {
+                Ok(commitments) => dealer_commitments.push(commitments),
+                Err(e) => {
+                    log::warn!("Invalid commitments of old party {} from the leader: {}", dealer, e);
+                    return;
+                }
+            }
+        }
+        let reshare_context = self.reshare_context.entry(epoch).or_default();
+        if reshare_context.dealers.is_some() {
+            return;
+        }
+        reshare_context.dealers = Some(msg.dealers);
+        reshare_context.dealer_commitments = dealer_commitments;
+        self.try_finish(epoch).await;
+    }
+
+    /// Terminates once this node's votes back the leader's list and the listed commitments keep the secret.
+    /// Every node gets here, members of either committee or not.
+    #[async_recursion]
+    pub async fn try_finish(&mut self, epoch: usize) {
+        if self.commitments.is_empty() {
+            return;
+        }
+        let new_faults = self.new_faults();
+        let reshare_context = self.reshare_context.entry(epoch).or_default();
+        let dealers = match &reshare_context.dealers {
+            Some(dealers) if reshare_context.is_backed(dealers, new_faults) => dealers.clone(),
+            _ => return,
+        };
+        if !reshare_context.terminated {
+            let indices: Vec<u64> = dealers.iter().map(|(dealer, _)| *dealer).collect();
+            let combined = self.resharing.combiner(&indices).and_then(|combiner| {
+                self.new_vss
+                    .combine_reshared_commitments(&combiner, &reshare_context.dealer_commitments)
+            });
+            let commitments = match combined {
+                Ok(commitments) => commitments,
+                Err(e) => {
+                    log::error!("Failed to combine the commitments in epoch {}: {}", epoch, e);
+                    return;
+                }
+            };
+            let reshares_old_share = indices
+                .iter()
+                .zip(&reshare_context.dealer_commitments)
+                .all(|(dealer, dealing)| dealing[0] == self.new_vss.commitment_at(&self.commitments, *dealer));
+            if !reshares_old_share || commitments[0] != self.commitments[0] {
+                log::error!("The dealers of epoch {} do not reshare the secret", epoch);
+                return;
+            }
+            let missing: Vec<u64> = dealers
+                .iter()
+                .filter(|(dealer, hash)| {
+                    reshare_context.sub_shares.get(dealer).map(|sub_share| sub_share.hash) != Some(*hash)
+                })
+                .map(|(dealer, _)| *dealer)
+                .collect();
+            if self.new_party.is_some() && !missing.is_empty() {
+                log::warn!(
+                    "No sub-shares from listed old parties {:?} in epoch {}, new party {:?} waits for them or recovers its share",
+                    missing,
+                    epoch,
+                    self.new_party
+                );
+            }
+            let public_key: Commitment<BLS12381Curve> = commitments[0].clone();
+            reshare_context.commitments = commitments;
+            self.finish(epoch, &public_key).await;
+        }
+        self.try_combine(epoch);
+    }
+
+    /// A new party combines the sub-shares of the listed dealers once it holds all of them.
+    pub fn try_combine(&mut self, epoch: usize) {
+        if self.new_party.is_none() {
+            return;
+        }
+        let reshare_context = self.reshare_context.entry(epoch).or_default();
+        let dealers = match &reshare_context.dealers {
+            Some(dealers) if reshare_context.terminated && reshare_context.share.is_none() => dealers,
+            _ => return,
+        };
+
+        let mut sub_shares = Vec::with_capacity(dealers.len());
+        for (dealer, hash) in dealers {
+            match reshare_context.sub_shares.get(dealer) {
+                Some(sub_share) if sub_share.hash == *hash => sub_shares.push(sub_share.share.clone()),
+                // Wait for the dealer's sub-share, or its reveal
+                _ => return,
+            }
+        }
+        let indices: Vec<u64> = dealers.iter().map(|(dealer, _)| *dealer).collect();
+        let share = match self
+            .resharing
+            .combiner(&indices)
+            .and_then(|combiner| self.resharing.combine(&combiner, &sub_shares))
+        {
+            Ok(share) => share,
+            Err(e) => {
+                log::error!("Failed to combine the sub-shares in epoch {}: {}", epoch, e);
+                return;
+            }
+        };
+        if !self.new_vss.verify_share(&share, &reshare_context.commitments) {
+            log::error!("The new share does not match the new commitments in epoch {}", epoch);
+            return;
+        }
+        log::info!("Received the share of new party {:?} in epoch {}", self.new_party, epoch);
+        reshare_context.share = Some(share);
+    }
+}
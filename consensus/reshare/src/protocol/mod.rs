@@ -0,0 +1,12 @@
+mod share;
+
+mod reshare;
+
+mod vote;
+
+mod reveal;
+
+mod dealers;
+
+mod reshare_state;
+pub use reshare_state::*;
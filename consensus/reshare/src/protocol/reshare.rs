@@ -0,0 +1,170 @@
+use async_recursion::async_recursion;
+use consensus::{commitments_from_bytes, commitments_to_bytes, Commitment, SecretSharingScheme, ShamirSecretSharing};
+use crypto::hash::do_hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use types::{RBCSyncMsg, WrapperMsg};
+
+use crate::{Context, ProtMsg, SubShare, SubShareMsg, VoteMsg};
+
+impl Context {
+    #[async_recursion]
+    pub async fn start_reshare(&mut self, epoch: usize) {
+        let reshare_context = self.reshare_context.entry(epoch).or_default();
+        if reshare_context.started {
+            return;
+        }
+        reshare_context.started = true;
+        self.deal_sub_shares(epoch).await;
+    }
+
+    /// An old party reshares its share among the new committee, once it holds it.
+    #[async_recursion]
+    pub async fn deal_sub_shares(&mut self, epoch: usize) {
+        let share = match self.share.clone() {
+            Some(share) => share,
+            None => return,
+        };
+        let reshare_context = self.reshare_context.entry(epoch).or_default();
+        if !reshare_context.started || reshare_context.dealing.is_some() {
+            return;
+        }
+        let secret = if self.byz {
+            // Tries to pass off another value as its share, which the constant commitment gives away
+            ShamirSecretSharing::<FrField>::rand_field_element()
+        } else {
+            share.value.clone()
+        };
+        let (sub_shares, commitments) = match self.new_vss.deal(secret) {
+            Ok(dealing) => dealing,
+            Err(e) => {
+                log::error!("Failed to reshare for epoch {}: {}", epoch, e);
+                return;
+            }
+        };
+        let commitments = commitments_to_bytes(&commitments);
+        reshare_context.dealing = Some((sub_shares.clone(), commitments.clone()));
+        log::info!("Reshared the share of old party {:?} for epoch {}", self.old_party, epoch);
+
+        // New party j is the node at position j - 1 of the new committee
+        for (sub_share, replica) in sub_shares.into_iter().zip(self.new_members.clone()) {
+            let msg = SubShareMsg {
+                share: sub_share,
+                commitments: commitments.clone(),
+                origin: self.myid,
+            };
+            if replica == self.myid {
+                self.handle_sub_share(msg, epoch).await;
+                continue;
+            }
+            let sec_key = self.net.sec_key_map.get(&replica).expect("Secret key not available").clone();
+            let wrapper_msg = WrapperMsg::new(ProtMsg::SubShare(msg, epoch), self.myid, sec_key.as_slice());
+            self.net.send(replica, wrapper_msg).await;
+        }
+    }
+
+    /// Checks a sub-share dealt by old party `dealer` to new party `party` against the dealer's commitments, which
+    /// must reshare the dealer's old share.
+    pub(crate) fn check_sub_share(
+        &self,
+        msg: &SubShareMsg,
+        dealer: u64,
+        party: u64,
+    ) -> Option<(SubShare, Vec<Commitment<BLS12381Curve>>)> {
+        let commitments = match commitments_from_bytes(&msg.commitments) {
+            Ok(commitments) => commitments,
+            Err(e) => {
+                log::warn!("Invalid commitments from old party {}: {}", dealer, e);
+                return None;
+            }
+        };
+        // The point is the receiver's, whatever index and scheme the dealer put in the share
+        let share = <ShamirSecretSharing<FrField> as SecretSharingScheme>::make_share(
+            &self.new_vss.sss,
+            party,
+            msg.share.value.clone(),
+        );
+        if !self
+            .new_vss
+            .verify_sub_share(&share, dealer, &commitments, &self.commitments)
+        {
+            return None;
+        }
+        let sub_share = SubShare {
+            hash: do_hash(&msg.commitments),
+            share,
+        };
+        Some((sub_share, commitments))
+    }
+
+    /// The old party index of a node, if it is a member of the old committee.
+    pub(crate) fn old_party_of(&self, replica: usize) -> Option<u64> {
+        self.old_members
+            .iter()
+            .position(|member| *member == replica)
+            .map(|position| position as u64 + 1)
+    }
+
+    #[async_recursion]
+    pub async fn handle_sub_share(&mut self, msg: SubShareMsg, epoch: usize) {
+        // A node that hears of an epoch for the first time reshares its own share too
+        self.start_reshare(epoch).await;
+
+        let new_party = match self.new_party {
+            Some(new_party) => new_party,
+            None => {
+                log::warn!("Node {} sent a sub-share to a node outside the new committee", msg.origin);
+                return;
+            }
+        };
+        let dealer = match self.old_party_of(msg.origin) {
+            Some(dealer) => dealer,
+            None => {
+                log::warn!("Node {} is not a member of the old committee", msg.origin);
+                return;
+            }
+        };
+        // Sub-shares are checked against the old commitments
+        if self.commitments.is_empty() {
+            self.pending.push(ProtMsg::SubShare(msg, epoch));
+            return;
+        }
+        // A sub-share that arrives after the epoch terminated may still complete this party's new share
+        if !self.reshare_context.entry(epoch).or_default().voted.insert(dealer) {
+            return;
+        }
+
+        let vote = VoteMsg {
+            dealer,
+            hash: do_hash(&msg.commitments),
+            origin: self.myid,
+        };
+        match self.check_sub_share(&msg, dealer, new_party) {
+            Some((sub_share, commitments)) => {
+                log::info!("Accepted the sub-share of old party {} for epoch {}", dealer, epoch);
+                let reshare_context = self.reshare_context.entry(epoch).or_default();
+                reshare_context.dealings.insert((dealer, sub_share.hash), commitments);
+                reshare_context.sub_shares.insert(dealer, sub_share);
+                self.net.broadcast(ProtMsg::Accept(vote.clone(), epoch)).await;
+                self.handle_accept(vote, epoch).await;
+            }
+            None => {
+                log::warn!("Rejected the sub-share of old party {} for epoch {}", dealer, epoch);
+                self.net.broadcast(ProtMsg::Complaint(vote.clone(), epoch)).await;
+                self.handle_complaint(vote, epoch).await;
+            }
+        }
+    }
+
+    /// Reports g^{P(0)} to the syncer, the same for every node if the resharing kept the secret.
+    pub async fn finish(&mut self, epoch: usize, public_key: &Commitment<BLS12381Curve>) {
+        self.reshare_context.entry(epoch).or_default().terminated = true;
+        let digest: String = do_hash(&commitments_to_bytes(std::slice::from_ref(public_key)))
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        let data = bincode::serialize(&RBCSyncMsg { id: epoch, msg: digest })
+            .expect("Failed to serialize resharing output");
+        self.net.terminate(data).await;
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::{HashMap, HashSet};
+
+use consensus::{Commitment, Share};
+use crypto::hash::Hash;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+
+/// A sub-share that passed the Feldman checks, with the hash of the commitments it was checked against.
+pub struct SubShare {
+    pub hash: Hash,
+    pub share: Share<FrField>,
+}
+
+pub struct ReshareState {
+    /// Sub-shares of this new party by old party
+    pub sub_shares: HashMap<u64, SubShare>,
+    /// The commitments of every old party's resharing this node has seen, by old party and hash
+    pub dealings: HashMap<(u64, Hash), Vec<Commitment<BLS12381Curve>>>,
+    /// Old parties this node already voted on
+    pub voted: HashSet<u64>,
+
+    // Votes are keyed by the old party and the hash of its commitments, voters by their new party index
+    pub accept_senders: HashMap<(u64, Hash), HashSet<u64>>,
+    pub complaint_senders: HashMap<(u64, Hash), HashSet<u64>>,
+    /// New parties whose sub-share the old party revealed
+    pub revealed: HashMap<(u64, Hash), HashSet<u64>>,
+    /// The old parties chosen by the leader of the new committee, with their commitments
+    pub dealers: Option<Vec<(u64, Hash)>>,
+    pub dealer_commitments: Vec<Vec<Commitment<BLS12381Curve>>>,
+
+    /// This node's resharing of its old share, all sub-shares and the compressed commitments
+    pub dealing: Option<(Vec<Share<FrField>>, Vec<u8>)>,
+    /// This node's share in the new committee and the commitments of the new sharing
+    pub share: Option<Share<FrField>>,
+    pub commitments: Vec<Commitment<BLS12381Curve>>,
+
+    pub started: bool,
+    pub proposed: bool,
+    pub terminated: bool,
+}
+
+impl ReshareState {
+    pub fn new() -> ReshareState {
+        ReshareState {
+            sub_shares: HashMap::default(),
+            dealings: HashMap::default(),
+            voted: HashSet::default(),
+
+            accept_senders: HashMap::default(),
+            complaint_senders: HashMap::default(),
+            revealed: HashMap::default(),
+            dealers: None,
+            dealer_commitments: Vec::new(),
+
+            dealing: None,
+            share: None,
+            commitments: Vec::new(),
+
+            started: false,
+            proposed: false,
+            terminated: false,
+        }
+    }
+
+    /// An old party is qualified with 2t' + 1 accepts on its commitments and every complaint against them answered.
+    pub fn is_qualified(&self, dealer: u64, hash: Hash, new_faults: usize) -> bool {
+        let accepts = self.accept_senders.get(&(dealer, hash)).map_or(0, HashSet::len);
+        let answered = match (
+            self.complaint_senders.get(&(dealer, hash)),
+            self.revealed.get(&(dealer, hash)),
+        ) {
+            (None, _) => true,
+            (Some(complainers), Some(revealed)) => complainers.is_subset(revealed),
+            (Some(complainers), None) => complainers.is_empty(),
+        };
+        accepts > 2 * new_faults && answered
+    }
+
+    /// Checks the leader's list against this node's votes: t' + 1 accepts on each listed commitment mean an
+    /// honest new party checked it, and every node sees them eventually.
+    pub fn is_backed(&self, dealers: &[(u64, Hash)], new_faults: usize) -> bool {
+        dealers.iter().all(|key| self.accept_senders.get(key).map_or(0, HashSet::len) > new_faults)
+    }
+}
+
+impl Default for ReshareState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{Context, ProtMsg, SubShareMsg};
+
+impl Context {
+    /// An old party's answer to a complaint, the complainer's sub-share in the clear.
+    pub async fn handle_reveal(&mut self, msg: SubShareMsg, epoch: usize) {
+        let dealer = match self.old_party_of(msg.origin) {
+            Some(dealer) => dealer,
+            None => {
+                log::warn!("Node {} is not a member of the old committee", msg.origin);
+                return;
+            }
+        };
+        let party = match msg.share.index {
+            index if index >= 1 && index <= self.new_members.len() as u64 => index,
+            index => {
+                log::warn!("Old party {} revealed a sub-share for unknown new party {}", dealer, index);
+                return;
+            }
+        };
+        // Revealed sub-shares are checked against the old commitments
+        if self.commitments.is_empty() {
+            self.pending.push(ProtMsg::Reveal(msg, epoch));
+            return;
+        }
+        let (sub_share, commitments) = match self.check_sub_share(&msg, dealer, party) {
+            Some(checked) => checked,
+            None => {
+                log::warn!("Old party {} revealed an invalid sub-share for new party {}", dealer, party);
+                return;
+            }
+        };
+
+        let reshare_context = self.reshare_context.entry(epoch).or_default();
+        if !reshare_context
+            .revealed
+            .entry((dealer, sub_share.hash))
+            .or_default()
+            .insert(party)
+        {
+            return;
+        }
+        reshare_context.dealings.insert((dealer, sub_share.hash), commitments);
+        if Some(party) == self.new_party {
+            reshare_context.sub_shares.entry(dealer).or_insert(sub_share);
+        }
+        self.propose(epoch).await;
+        self.try_finish(epoch).await;
+    }
+}
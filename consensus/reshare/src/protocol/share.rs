@@ -0,0 +1,100 @@
+use consensus::{commitments_from_bytes, commitments_to_bytes, SecretSharingScheme, ShamirSecretSharing};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use types::WrapperMsg;
+
+use crate::{Context, ProtMsg, ShareMsg};
+
+impl Context {
+    /// The dealer shares a random secret among the old committee. Old parties get their own share, every node
+    /// gets the commitments.
+    pub async fn deal_secret(&mut self) {
+        let secret = ShamirSecretSharing::<FrField>::rand_field_element();
+        let (shares, commitments) = match self.old_vss.deal(secret) {
+            Ok(dealing) => dealing,
+            Err(e) => {
+                log::error!("Failed to deal the secret among the old committee: {}", e);
+                return;
+            }
+        };
+        let compressed = commitments_to_bytes(&commitments);
+        log::info!("Dealt the secret among the old committee");
+
+        let sec_key_map = self.net.sec_key_map.clone();
+        for (replica, sec_key) in sec_key_map.into_iter() {
+            // Old party i is the node at position i - 1 of the old committee
+            let share = self
+                .old_members
+                .iter()
+                .position(|member| *member == replica)
+                .map(|position| shares[position].clone());
+            if replica == self.myid {
+                self.share = share;
+                self.commitments = commitments.clone();
+                continue;
+            }
+            let msg = ShareMsg {
+                share,
+                commitments: compressed.clone(),
+                origin: self.myid,
+            };
+            let wrapper_msg = WrapperMsg::new(ProtMsg::Share(msg), self.myid, sec_key.as_slice());
+            self.net.send(replica, wrapper_msg).await;
+        }
+    }
+
+    /// Keeps the old commitments, and the dealer's share if this node is an old party and it matches them.
+    /// Then handles what was waiting for them.
+    pub async fn handle_share(&mut self, msg: ShareMsg) {
+        if msg.origin != self.dealer() {
+            log::warn!("Node {} is not the dealer of the secret", msg.origin);
+            return;
+        }
+        if !self.commitments.is_empty() {
+            return;
+        }
+        let commitments = match commitments_from_bytes(&msg.commitments) {
+            Ok(commitments) if commitments.len() == self.old_vss.sss.threshold => commitments,
+            Ok(_) => {
+                log::error!("The dealer committed to a polynomial of the wrong degree");
+                return;
+            }
+            Err(e) => {
+                log::error!("Invalid commitments from the dealer: {}", e);
+                return;
+            }
+        };
+        if let Some(old_party) = self.old_party {
+            // The point is this node's, whatever index and scheme the dealer put in the share
+            let share = msg.share.map(|share| {
+                <ShamirSecretSharing<FrField> as SecretSharingScheme>::make_share(
+                    &self.old_vss.sss,
+                    old_party,
+                    share.value.clone(),
+                )
+            });
+            match share {
+                Some(share) if self.old_vss.verify_share(&share, &commitments) => self.share = Some(share),
+                _ => {
+                    log::error!("The dealer sent old party {} no valid share", old_party);
+                    return;
+                }
+            }
+        }
+        self.commitments = commitments;
+        log::info!("Received the old commitments and share {:?}", self.old_party);
+
+        for msg in std::mem::take(&mut self.pending) {
+            match msg {
+                ProtMsg::SubShare(msg, epoch) => self.handle_sub_share(msg, epoch).await,
+                ProtMsg::Reveal(msg, epoch) => self.handle_reveal(msg, epoch).await,
+                _ => {}
+            }
+        }
+        let mut epochs: Vec<usize> = self.reshare_context.keys().cloned().collect();
+        epochs.sort();
+        for epoch in epochs {
+            self.deal_sub_shares(epoch).await;
+            self.try_finish(epoch).await;
+        }
+    }
+}
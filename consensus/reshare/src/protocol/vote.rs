@@ -0,0 +1,66 @@
+use crypto::hash::do_hash;
+
+use crate::{Context, ProtMsg, SubShareMsg, VoteMsg};
+
+impl Context {
+    /// The new party index of a voter, votes only count from members of the new committee.
+    fn voter(&self, msg: &VoteMsg) -> Option<u64> {
+        let voter = self
+            .new_members
+            .iter()
+            .position(|member| *member == msg.origin)
+            .map(|position| position as u64 + 1);
+        if voter.is_none() {
+            log::warn!("Node {} voted but is not a member of the new committee", msg.origin);
+        }
+        voter
+    }
+
+    pub async fn handle_accept(&mut self, msg: VoteMsg, epoch: usize) {
+        let voter = match self.voter(&msg) {
+            Some(voter) => voter,
+            None => return,
+        };
+        let reshare_context = self.reshare_context.entry(epoch).or_default();
+        if !reshare_context
+            .accept_senders
+            .entry((msg.dealer, msg.hash))
+            .or_default()
+            .insert(voter)
+        {
+            return;
+        }
+        self.propose(epoch).await;
+        self.try_finish(epoch).await;
+    }
+
+    pub async fn handle_complaint(&mut self, msg: VoteMsg, epoch: usize) {
+        let voter = match self.voter(&msg) {
+            Some(voter) => voter,
+            None => return,
+        };
+        let reshare_context = self.reshare_context.entry(epoch).or_default();
+        if !reshare_context
+            .complaint_senders
+            .entry((msg.dealer, msg.hash))
+            .or_default()
+            .insert(voter)
+        {
+            return;
+        }
+        if self.old_party != Some(msg.dealer) {
+            return;
+        }
+        // Answer with the complainer's sub-share, which only checks out against the commitments of an honest dealer
+        let reveal = match &reshare_context.dealing {
+            Some((sub_shares, commitments)) if do_hash(commitments) == msg.hash => SubShareMsg {
+                share: sub_shares[voter as usize - 1].clone(),
+                commitments: commitments.clone(),
+                origin: self.myid,
+            },
+            _ => return,
+        };
+        self.net.broadcast(ProtMsg::Reveal(reveal.clone(), epoch)).await;
+        self.handle_reveal(reveal, epoch).await;
+    }
+}
@@ -0,0 +1,164 @@
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsField;
+
+use crate::{Share, SssError};
+
+/**
+ * Local arithmetic on shares
+ * Parties holding shares P(x_i) and Q(x_i) of the same points get shares of P + Q, P - Q, c * P and P + c
+ * without talking to each other. The product P(x_i) * Q(x_i) is a share of P * Q, whose degree is the sum
+ * of the degrees, so twice as many shares are needed to reconstruct it.
+ * The degree is tracked through `threshold`, which is always degree + 1.
+ */
+impl<F: IsField> Share<F> {
+    /// Degree of the polynomial this share lies on, fails for a threshold of 0.
+    pub fn degree(&self) -> Result<usize, SssError> {
+        self.threshold.checked_sub(1).ok_or(SssError::ZeroThreshold)
+    }
+
+    /// Shares can only be combined if they sit at the same point of the same domain.
+    pub fn check_compatible(&self, other: &Share<F>) -> Result<(), SssError> {
+        if self.index != other.index || self.scheme != other.scheme {
+            return Err(SssError::Incompatible);
+        }
+        Ok(())
+    }
+
+    /// Share of P + Q.
+    pub fn add_share(&self, other: &Share<F>) -> Result<Share<F>, SssError> {
+        self.check_compatible(other)?;
+        Ok(self.with_value(&self.value + &other.value, self.threshold.max(other.threshold)))
+    }
+
+    /// Share of P - Q.
+    pub fn sub_share(&self, other: &Share<F>) -> Result<Share<F>, SssError> {
+        self.check_compatible(other)?;
+        Ok(self.with_value(&self.value - &other.value, self.threshold.max(other.threshold)))
+    }
+
+    /// Share of P * Q, of degree deg P + deg Q. Two degree t sharings multiply to degree 2t.
+    pub fn mul_share(&self, other: &Share<F>) -> Result<Share<F>, SssError> {
+        self.check_compatible(other)?;
+        Ok(self.with_value(&self.value * &other.value, self.degree()? + other.degree()? + 1))
+    }
+
+    /// Share of c * P for a public c.
+    pub fn scale(&self, scalar: &FieldElement<F>) -> Share<F> {
+        self.with_value(&self.value * scalar, self.threshold)
+    }
+
+    /// Share of P + c for a public c, every party adds c to its value.
+    pub fn add_constant(&self, constant: &FieldElement<F>) -> Share<F> {
+        self.with_value(&self.value + constant, self.threshold)
+    }
+
+    fn with_value(&self, value: FieldElement<F>, threshold: usize) -> Share<F> {
+        Share {
+            index: self.index,
+            value,
+            threshold,
+            scheme: self.scheme,
+        }
+    }
+}
+
+/// Share of sum_k coefficients[k] * P_k from one share of every P_k, all held by the same party.
+pub fn linear_combination<F: IsField>(
+    shares: &[Share<F>],
+    coefficients: &[FieldElement<F>],
+) -> Result<Share<F>, SssError> {
+    let first = match shares.first() {
+        Some(first) => first,
+        None => return Err(SssError::NotEnoughShares(1, 0)),
+    };
+    if shares.len() != coefficients.len() {
+        return Err(SssError::Incompatible);
+    }
+
+    let mut combination = first.scale(&coefficients[0]);
+    for (share, coefficient) in shares.iter().zip(coefficients).skip(1) {
+        combination = combination.add_share(&share.scale(coefficient))?;
+    }
+    Ok(combination)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{linear_combination, LargeField, ShamirSecretSharing, ShamirSecretSharingFFT, Share, SssError};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+
+    fn combine(a: &[Share], b: &[Share], op: fn(&Share, &Share) -> Result<Share, SssError>) -> Vec<Share> {
+        a.iter().zip(b).map(|(a, b)| op(a, b).unwrap()).collect()
+    }
+
+    #[test]
+    fn shares_add_subtract_and_scale() {
+        let sss = ShamirSecretSharing::new(3, 7).unwrap();
+        let (x, y) = (LargeField::from(20u64), LargeField::from(22u64));
+        let (x_shares, y_shares) = (sss.split(x).unwrap(), sss.split(y).unwrap());
+
+        let sum = combine(&x_shares, &y_shares, Share::add_share);
+        assert_eq!(sss.recover(&sum[..3]).unwrap(), x + y);
+        let difference = combine(&x_shares, &y_shares, Share::sub_share);
+        assert_eq!(sss.recover(&difference[2..5]).unwrap(), x - y);
+
+        let five = LargeField::from(5u64);
+        let affine: Vec<Share> = x_shares.iter().map(|s| s.scale(&five).add_constant(&y)).collect();
+        assert_eq!(sss.recover(&affine[4..]).unwrap(), five * x + y);
+        assert_eq!(affine[0].degree(), Ok(2));
+    }
+
+    #[test]
+    fn multiplying_shares_doubles_the_degree() {
+        let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(3, 8).unwrap();
+        let x = FieldElement::<Babybear31PrimeField>::from(6u64);
+        let y = FieldElement::<Babybear31PrimeField>::from(7u64);
+
+        let product: Vec<_> = sss
+            .split(x.clone())
+            .unwrap()
+            .iter()
+            .zip(sss.split(y.clone()).unwrap().iter())
+            .map(|(a, b)| a.mul_share(b).unwrap())
+            .collect();
+        // Degree 2 times degree 2 is degree 4, 5 shares reconstruct it
+        assert_eq!(product[0].degree(), Ok(4));
+        assert_eq!(product[0].threshold, 5);
+        assert_eq!(sss.recover(&product[..5]), Err(SssError::Incompatible));
+        let product_sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(5, 8).unwrap();
+        assert_eq!(product_sss.recover(&product[..5]).unwrap(), x * y);
+    }
+
+    #[test]
+    fn linear_combinations_and_mismatched_operands() {
+        let sss = ShamirSecretSharing::new(2, 4).unwrap();
+        let secrets = [LargeField::from(1u64), LargeField::from(2u64), LargeField::from(3u64)];
+        let sharings: Vec<Vec<Share>> = secrets.iter().map(|secret| sss.split(*secret).unwrap()).collect();
+        let coefficients = [LargeField::from(100u64), LargeField::from(10u64), LargeField::from(1u64)];
+
+        let combined: Vec<Share> = (0..4)
+            .map(|i| {
+                let party_shares: Vec<Share> = sharings.iter().map(|sharing| sharing[i].clone()).collect();
+                linear_combination(&party_shares, &coefficients).unwrap()
+            })
+            .collect();
+        assert_eq!(sss.recover(&combined[1..3]).unwrap(), LargeField::from(123u64));
+
+        // Different parties, or the same party on another domain, don't combine
+        let fft_shares = ShamirSecretSharingFFT::new(2, 4).unwrap().split(LargeField::from(1u64)).unwrap();
+        assert_eq!(sharings[0][0].add_share(&sharings[1][1]), Err(SssError::Incompatible));
+        assert_eq!(sharings[0][0].mul_share(&fft_shares[0]), Err(SssError::Incompatible));
+        assert_eq!(
+            linear_combination(&sharings[0][..2], &coefficients),
+            Err(SssError::Incompatible)
+        );
+
+        // A threshold of 0 has no degree, and such shares don't come off the wire
+        let mut empty = sharings[0][0].clone();
+        empty.threshold = 0;
+        assert_eq!(empty.degree(), Err(SssError::ZeroThreshold));
+        assert_eq!(empty.mul_share(&sharings[1][0]), Err(SssError::ZeroThreshold));
+        assert!(<Share>::from_bytes(&empty.to_bytes()).is_err());
+    }
+}
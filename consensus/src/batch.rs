@@ -0,0 +1,260 @@
+use std::fmt;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, IsField, IsPrimeField};
+use rand_core::{CryptoRng, OsRng, RngCore};
+use rayon::prelude::*;
+
+use crate::{Erase, Scheme, ShamirSecretSharing, ShamirSecretSharingFFT, Share};
+
+/**
+ * Batch dealing
+ * Shares of m secrets for the same n parties in one allocation, party by party: party i's shares of the m
+ * secrets are `values[(i - 1) * m..i * m]`, so each party's vector is a single contiguous slice.
+ * Dealing is a matrix product. With the m sharing polynomials as the rows of an m x t coefficient matrix C
+ * and the n x t Vandermonde matrix V of the parties' points, party i's vector is row i of V * C^T.
+ * Rows (or, on a roots-of-unity domain, the FFTs of the polynomials) are computed in parallel with rayon.
+ */
+#[derive(Clone)]
+pub struct BatchShares<F: IsField = Stark252PrimeField> {
+    /// the number of shares needed to recover each secret.
+    pub threshold: usize,
+    pub scheme: Scheme,
+    share_amount: usize,
+    batch_size: usize,
+    values: Vec<FieldElement<F>>,
+}
+
+impl<F: IsField> Drop for BatchShares<F> {
+    fn drop(&mut self) {
+        self.values.erase();
+    }
+}
+
+impl<F: IsField> fmt::Debug for BatchShares<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchShares")
+            .field("threshold", &self.threshold)
+            .field("scheme", &self.scheme)
+            .field("share_amount", &self.share_amount)
+            .field("batch_size", &self.batch_size)
+            .field("values", &"<redacted>")
+            .finish()
+    }
+}
+
+impl<F: IsField> BatchShares<F> {
+    pub fn share_amount(&self) -> usize {
+        self.share_amount
+    }
+
+    /// The number of secrets m.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// All n * m values, party by party.
+    pub fn as_slice(&self) -> &[FieldElement<F>] {
+        &self.values
+    }
+
+    /// Party `index`'s (1-based) shares of the m secrets, `None` for an unknown party.
+    pub fn party(&self, index: u64) -> Option<&[FieldElement<F>]> {
+        if index == 0 || index > self.share_amount as u64 {
+            return None;
+        }
+        let start = (index as usize - 1) * self.batch_size;
+        Some(&self.values[start..start + self.batch_size])
+    }
+
+    /// Party `index`'s share of the k-th secret.
+    pub fn share(&self, index: u64, k: usize) -> Option<Share<F>> {
+        if k >= self.batch_size {
+            return None;
+        }
+        let value = self.party(index)?[k].clone();
+        Some(Share {
+            index,
+            value,
+            threshold: self.threshold,
+            scheme: self.scheme,
+        })
+    }
+
+    /// The n shares of the k-th secret, in the layout `split` returns them.
+    pub fn shares_of(&self, k: usize) -> Option<Vec<Share<F>>> {
+        (1..self.share_amount as u64 + 1).map(|index| self.share(index, k)).collect()
+    }
+}
+
+// The coefficient matrix C, secret by secret: row k is secrets[k] followed by threshold - 1 random values.
+// The rng isn't shared across threads, so sampling stays sequential.
+fn sample_coefficients<F: IsFFTField + IsPrimeField, R: CryptoRng + RngCore>(
+    secrets: &[FieldElement<F>],
+    threshold: usize,
+    rng: &mut R,
+) -> Vec<FieldElement<F>> {
+    let mut coefficients = Vec::with_capacity(secrets.len() * threshold);
+    for secret in secrets {
+        coefficients.push(secret.clone());
+        for _ in 1..threshold {
+            coefficients.push(ShamirSecretSharing::<F>::rand_field_element_with_rng(rng));
+        }
+    }
+    coefficients
+}
+
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharing<F>
+where
+    FieldElement<F>: Send + Sync,
+{
+    /// Splits m secrets at once, see `BatchShares` for the layout.
+    pub fn split_batch(&self, secrets: &[FieldElement<F>]) -> BatchShares<F> {
+        self.split_batch_with_rng(secrets, &mut OsRng)
+    }
+
+    /// Same as `split_batch`, with the random coefficients drawn from `rng`.
+    pub fn split_batch_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        secrets: &[FieldElement<F>],
+        rng: &mut R,
+    ) -> BatchShares<F> {
+        let threshold = self.threshold;
+        let batch_size = secrets.len();
+        let mut coefficients = sample_coefficients(secrets, threshold, rng);
+        let mut values = vec![FieldElement::<F>::zero(); self.share_amount * batch_size];
+        values
+            .par_chunks_mut(batch_size.max(1))
+            .enumerate()
+            .for_each(|(i, row)| {
+                // Row i of V: 1, x, ..., x^(t-1) at x = i + 1
+                let x = FieldElement::<F>::from(i as u64 + 1);
+                let mut powers = Vec::with_capacity(threshold);
+                let mut power = FieldElement::<F>::one();
+                for _ in 0..threshold {
+                    powers.push(power.clone());
+                    power = &power * &x;
+                }
+                for (value, polynomial) in row.iter_mut().zip(coefficients.chunks(threshold)) {
+                    *value = polynomial
+                        .iter()
+                        .zip(powers.iter())
+                        .fold(FieldElement::zero(), |acc, (coefficient, power)| acc + coefficient * power);
+                }
+            });
+        coefficients.erase();
+        BatchShares {
+            threshold,
+            scheme: Scheme::Shamir,
+            share_amount: self.share_amount,
+            batch_size,
+            values,
+        }
+    }
+}
+
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharingFFT<F>
+where
+    FieldElement<F>: Send + Sync,
+{
+    /// Splits m secrets at once, see `BatchShares` for the layout.
+    pub fn split_batch(&self, secrets: &[FieldElement<F>]) -> BatchShares<F> {
+        self.split_batch_with_rng(secrets, &mut OsRng)
+    }
+
+    /// Same as `split_batch`, with the random coefficients drawn from `rng`. Every polynomial is evaluated
+    /// with one in-place FFT of the precomputed plan, then the m x N evaluations are transposed into
+    /// the party-by-party layout.
+    pub fn split_batch_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        secrets: &[FieldElement<F>],
+        rng: &mut R,
+    ) -> BatchShares<F> {
+        let threshold = self.threshold;
+        let batch_size = secrets.len();
+        let plan = self.plan();
+        let domain_size = plan.size();
+        let mut coefficients = sample_coefficients(secrets, threshold, rng);
+
+        let mut evaluations = vec![FieldElement::<F>::zero(); domain_size * batch_size];
+        evaluations
+            .par_chunks_mut(domain_size)
+            .zip(coefficients.par_chunks(threshold))
+            .for_each(|(buffer, polynomial)| {
+                buffer[..threshold].clone_from_slice(polynomial);
+                plan.forward_in_place(buffer);
+            });
+        coefficients.erase();
+
+        let mut values = vec![FieldElement::<F>::zero(); self.share_amount * batch_size];
+        values
+            .par_chunks_mut(batch_size.max(1))
+            .enumerate()
+            .for_each(|(i, row)| {
+                for (k, value) in row.iter_mut().enumerate() {
+                    *value = evaluations[k * domain_size + i].clone();
+                }
+            });
+        evaluations.erase();
+        BatchShares {
+            threshold,
+            scheme: Scheme::ShamirFFT {
+                share_amount: self.share_amount,
+            },
+            share_amount: self.share_amount,
+            batch_size,
+            values,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ShamirSecretSharing, ShamirSecretSharingFFT};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    type FE = FieldElement<Babybear31PrimeField>;
+
+    fn secrets(amount: u64) -> Vec<FE> {
+        (0..amount).map(|k| FE::from(100 + k)).collect()
+    }
+
+    #[test]
+    fn batch_shares_recover_every_secret() {
+        let sss = ShamirSecretSharing::<Babybear31PrimeField>::new(3, 7).unwrap();
+        let secrets = secrets(20);
+        let batch = sss.split_batch_with_rng(&secrets, &mut ChaCha20Rng::seed_from_u64(3));
+        assert_eq!(batch.batch_size(), 20);
+        assert_eq!(batch.as_slice().len(), 7 * 20);
+        assert_eq!(batch.party(4).unwrap()[9], batch.share(4, 9).unwrap().value);
+        assert!(batch.party(0).is_none() && batch.party(8).is_none() && batch.share(1, 20).is_none());
+
+        for (k, secret) in secrets.iter().enumerate() {
+            let shares = batch.shares_of(k).unwrap();
+            assert_eq!(*sss.recover(&shares[4..]).unwrap(), *secret);
+            let values: Vec<FE> = shares.iter().map(|share| share.value.clone()).collect();
+            assert_eq!(sss.verify_degree(&values), Ok(true));
+        }
+    }
+
+    #[test]
+    fn fft_batch_shares_recover_every_secret() {
+        // 6 parties on a domain of 8
+        let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(4, 6).unwrap();
+        let secrets = secrets(33);
+        let batch = sss.split_batch(&secrets);
+        assert_eq!(batch.as_slice().len(), 6 * 33);
+
+        for (k, secret) in secrets.iter().enumerate() {
+            let shares = batch.shares_of(k).unwrap();
+            assert_eq!(*sss.recover(&shares[2..]).unwrap(), *secret);
+            let values: Vec<FE> = shares.iter().map(|share| share.value.clone()).collect();
+            assert_eq!(sss.verify_degree(&values), Ok(true));
+        }
+        assert_eq!(sss.split_batch(&[]).as_slice().len(), 0);
+    }
+}
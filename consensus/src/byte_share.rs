@@ -0,0 +1,356 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, IsField, IsPrimeField};
+use lambdaworks_math::traits::ByteConversion;
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{field_from_bytes, field_to_bytes, Erase, Scheme, Secret, Share, SssError};
+
+/// A party's share of a byte string. The secret is cut into limbs that each fit below the
+/// field modulus, and every limb is shared under the same party index.
+#[derive(Clone)]
+pub struct ByteShare<F: IsField = Stark252PrimeField> {
+    pub index: u64,
+    /// random tag shared by all shares of one split, so shares of different secrets are never mixed
+    pub split_id: u64,
+    /// the length of the original secret in bytes
+    pub length: usize,
+    /// one value per limb of the secret
+    pub values: Vec<FieldElement<F>>,
+    pub threshold: usize,
+    pub scheme: Scheme,
+}
+
+impl<F: IsField> PartialEq for ByteShare<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+            && self.split_id == other.split_id
+            && self.length == other.length
+            && self.values == other.values
+            && self.threshold == other.threshold
+            && self.scheme == other.scheme
+    }
+}
+
+impl<F: IsField> Drop for ByteShare<F> {
+    fn drop(&mut self) {
+        self.values.erase();
+    }
+}
+
+impl<F: IsField> fmt::Debug for ByteShare<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteShare")
+            .field("index", &self.index)
+            .field("split_id", &self.split_id)
+            .field("length", &self.length)
+            .field("values", &"<redacted>")
+            .field("threshold", &self.threshold)
+            .field("scheme", &self.scheme)
+            .finish()
+    }
+}
+
+/// Number of secret bytes packed into one field element. Limbs are shorter than the modulus
+/// in bits, so every limb is below it (31 bytes for Stark 252).
+pub fn limb_size<F: IsField>() -> usize
+where
+    FieldElement<F>: ByteConversion,
+{
+    let max = (-FieldElement::<F>::one()).to_bytes_be();
+    let leading_zeros: u32 = max
+        .iter()
+        .position(|b| *b != 0)
+        .map(|i| i as u32 * 8 + max[i].leading_zeros())
+        .unwrap_or(0);
+    let bits = max.len() * 8 - leading_zeros as usize;
+    (bits - 1) / 8
+}
+
+/// Cuts a byte string into big-endian limbs of `limb_size` bytes, the last limb may be shorter.
+/// Fails on fields too small to hold a whole byte below the modulus.
+pub fn bytes_to_limbs<F: IsField>(bytes: &[u8]) -> Result<Vec<FieldElement<F>>, SssError>
+where
+    FieldElement<F>: ByteConversion,
+{
+    let size = limb_size::<F>();
+    if size == 0 {
+        return Err(SssError::OutOfRange);
+    }
+    let width = FieldElement::<F>::zero().to_bytes_be().len();
+    bytes
+        .chunks(size)
+        .map(|chunk| {
+            let mut padded = Vec::with_capacity(width);
+            padded.resize(width - chunk.len(), 0);
+            padded.extend_from_slice(chunk);
+            let limb = field_from_bytes(&padded);
+            padded.erase();
+            limb
+        })
+        .collect()
+}
+
+/// Inverse of `bytes_to_limbs`. Fails if the limbs don't describe exactly `length` bytes.
+pub fn limbs_to_bytes<F: IsField>(limbs: &[FieldElement<F>], length: usize) -> Result<Secret<Vec<u8>>, SssError>
+where
+    FieldElement<F>: ByteConversion,
+{
+    let size = limb_size::<F>();
+    let limb_count = length.div_ceil(size);
+    if limbs.len() != limb_count {
+        return Err(SssError::InvalidLength(limb_count, limbs.len()));
+    }
+
+    let mut bytes = Secret::new(Vec::with_capacity(length));
+    for (i, limb) in limbs.iter().enumerate() {
+        let chunk_len = size.min(length - i * size);
+        let mut encoded = field_to_bytes(limb);
+        let (padding, chunk) = encoded.split_at(encoded.len() - chunk_len);
+        // A limb that doesn't fit its chunk means the shares were inconsistent
+        let fits = padding.iter().all(|b| *b == 0);
+        if fits {
+            bytes.extend_from_slice(chunk);
+        }
+        encoded.erase();
+        if !fits {
+            return Err(SssError::OutOfRange);
+        }
+    }
+    Ok(bytes)
+}
+
+impl<F: IsField> ByteShare<F> {
+    /// Regroups per-limb shares (one `Vec` per limb, one entry per party) into one byte share per party.
+    pub(crate) fn deal(
+        limb_shares: Vec<Vec<Share<F>>>,
+        length: usize,
+        threshold: usize,
+        scheme: Scheme,
+        share_amount: usize,
+    ) -> Vec<ByteShare<F>> {
+        let split_id = rand::random::<u64>();
+        let mut shares: Vec<ByteShare<F>> = (1..share_amount as u64 + 1)
+            .map(|index| ByteShare {
+                index,
+                split_id,
+                length,
+                values: Vec::with_capacity(limb_shares.len()),
+                threshold,
+                scheme,
+            })
+            .collect();
+        for limb in limb_shares {
+            for (share, limb_share) in shares.iter_mut().zip(limb) {
+                share.values.push(limb_share.value.clone());
+            }
+        }
+        shares
+    }
+}
+
+impl<F: IsFFTField + IsPrimeField> ByteShare<F> {
+    /// Checks that the shares come from the same split of the recovering scheme, with its `threshold`
+    /// and `scheme`, and returns them as per-limb shares. The shares' own fields are not trusted.
+    pub(crate) fn limb_shares(
+        shares: &[ByteShare<F>],
+        threshold: usize,
+        scheme: Scheme,
+    ) -> Result<Vec<Vec<Share<F>>>, SssError> {
+        let first = match shares.first() {
+            Some(first) => first,
+            None => return Err(SssError::NotEnoughShares(threshold.max(1), 0)),
+        };
+        if shares.len() < threshold {
+            return Err(SssError::NotEnoughShares(threshold, shares.len()));
+        }
+
+        let mut indices = HashSet::new();
+        for share in shares {
+            if share.threshold != threshold || share.scheme != scheme {
+                return Err(SssError::Incompatible);
+            }
+            if share.split_id != first.split_id
+                || share.length != first.length
+                || share.values.len() != first.values.len()
+            {
+                return Err(SssError::MixedSplits);
+            }
+            scheme.check_index::<F>(share.index)?;
+            if !indices.insert(share.index) {
+                return Err(SssError::DuplicateIndex(share.index));
+            }
+        }
+
+        Ok((0..first.values.len())
+            .map(|limb| {
+                shares
+                    .iter()
+                    .map(|share| Share {
+                        index: share.index,
+                        value: share.values[limb].clone(),
+                        threshold,
+                        scheme,
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+}
+
+// Wire format of a byte share, values are kept in their canonical encoding
+#[derive(Serialize, Deserialize)]
+struct ByteShareRepr {
+    index: u64,
+    split_id: u64,
+    length: usize,
+    values: Vec<Vec<u8>>,
+    threshold: usize,
+    scheme: Scheme,
+}
+
+impl<F: IsFFTField + IsPrimeField> ByteShare<F>
+where
+    FieldElement<F>: ByteConversion,
+{
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("Failed to serialize byte share")
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, bincode::Error> {
+        bincode::deserialize(bytes)
+    }
+}
+
+impl<F: IsField> Serialize for ByteShare<F>
+where
+    FieldElement<F>: ByteConversion,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ByteShareRepr {
+            index: self.index,
+            split_id: self.split_id,
+            length: self.length,
+            values: self.values.iter().map(field_to_bytes).collect(),
+            threshold: self.threshold,
+            scheme: self.scheme,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: IsFFTField + IsPrimeField> Deserialize<'de> for ByteShare<F>
+where
+    FieldElement<F>: ByteConversion,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = ByteShareRepr::deserialize(deserializer)?;
+        repr.scheme.check_index::<F>(repr.index).map_err(D::Error::custom)?;
+        if repr.threshold == 0 {
+            return Err(D::Error::custom(SssError::ZeroThreshold));
+        }
+        let values = repr
+            .values
+            .iter()
+            .map(|value| field_from_bytes(value))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(D::Error::custom)?;
+        Ok(ByteShare {
+            index: repr.index,
+            split_id: repr.split_id,
+            length: repr.length,
+            values,
+            threshold: repr.threshold,
+            scheme: repr.scheme,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{limb_size, ByteShare, Scheme, ShamirSecretSharing, ShamirSecretSharingFFT, SssError};
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+    use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+
+    #[test]
+    fn byte_secrets_of_any_length_roundtrip() {
+        assert_eq!(limb_size::<Stark252PrimeField>(), 31);
+        let sss = ShamirSecretSharing::<Stark252PrimeField>::new(3, 6).unwrap();
+        let sss_fft = ShamirSecretSharingFFT::<Stark252PrimeField>::new(3, 6).unwrap();
+
+        for length in [0usize, 1, 30, 31, 32, 62, 100] {
+            let secret: Vec<u8> = (0..length).map(|i| (i * 7 + 255) as u8).collect();
+
+            let shares = sss.split_bytes(&secret).unwrap();
+            assert_eq!(shares.len(), 6);
+            assert_eq!(sss.recover_bytes(&shares[1..4]).unwrap(), secret);
+
+            let shares = sss_fft.split_bytes(&secret).unwrap();
+            assert_eq!(sss_fft.recover_bytes(&shares[3..6]).unwrap(), secret);
+        }
+
+        // Leading zero bytes survive the trip
+        let secret = [0u8, 0, 1, 0];
+        let shares = sss.split_bytes(&secret).unwrap();
+        assert_eq!(sss.recover_bytes(&shares[..3]).unwrap(), secret.to_vec());
+    }
+
+    #[test]
+    fn byte_secrets_roundtrip_over_babybear() {
+        let sss = ShamirSecretSharing::<Babybear31PrimeField>::new(2, 4).unwrap();
+        let secret = b"correct horse battery staple";
+        let shares = sss.split_bytes(secret).unwrap();
+        assert_eq!(shares[0].values.len(), secret.len().div_ceil(limb_size::<Babybear31PrimeField>()));
+
+        let decoded: Vec<ByteShare<Babybear31PrimeField>> = shares
+            .iter()
+            .map(|share| ByteShare::from_bytes(&share.to_bytes()).unwrap())
+            .collect();
+        assert_eq!(decoded, shares);
+        assert_eq!(sss.recover_bytes(&decoded[2..]).unwrap(), secret.to_vec());
+    }
+
+    #[test]
+    fn shares_from_different_splits_are_rejected() {
+        let sss = ShamirSecretSharing::<Stark252PrimeField>::new(2, 4).unwrap();
+        let first = sss.split_bytes(b"first secret").unwrap();
+        let second = sss.split_bytes(b"second secret").unwrap();
+
+        let mixed = vec![first[0].clone(), second[1].clone()];
+        assert_eq!(sss.recover_bytes(&mixed), Err(SssError::MixedSplits));
+
+        let duplicated = vec![first[0].clone(), first[0].clone()];
+        assert_eq!(sss.recover_bytes(&duplicated), Err(SssError::DuplicateIndex(1)));
+
+        assert_eq!(sss.recover_bytes(&first[..1]), Err(SssError::NotEnoughShares(2, 1)));
+        assert_eq!(sss.recover_bytes(&[]), Err(SssError::NotEnoughShares(2, 0)));
+    }
+
+    #[test]
+    fn shares_are_recovered_with_the_configured_threshold_and_scheme() {
+        let sss = ShamirSecretSharing::<Stark252PrimeField>::new(3, 4).unwrap();
+        let shares = sss.split_bytes(b"secret").unwrap();
+
+        // Shares claiming a lower threshold don't lower the number needed
+        let mut forged = shares[..2].to_vec();
+        for share in forged.iter_mut() {
+            share.threshold = 2;
+        }
+        assert_eq!(sss.recover_bytes(&forged), Err(SssError::NotEnoughShares(3, 2)));
+        forged.push(shares[2].clone());
+        forged[2].threshold = 2;
+        assert_eq!(sss.recover_bytes(&forged), Err(SssError::Incompatible));
+
+        // Nor do shares claiming another scheme
+        let mut forged = shares[..3].to_vec();
+        for share in forged.iter_mut() {
+            share.scheme = Scheme::ShamirFFT { share_amount: 4 };
+        }
+        assert_eq!(sss.recover_bytes(&forged), Err(SssError::Incompatible));
+        assert_eq!(*sss.recover_bytes(&shares[1..]).unwrap(), b"secret".to_vec());
+    }
+}
@@ -0,0 +1,185 @@
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
+use lambdaworks_math::polynomial::Polynomial;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{evaluate_at_points, interpolate_points, ShamirSecretSharing, ShamirSecretSharingFFT, SssError};
+
+/**
+ * Low-degree tests
+ * `verify_degree` checks that the n evaluations at the parties' points lie on a polynomial of degree
+ * below `threshold`, by interpolating all of them (an inverse FFT on a full roots-of-unity domain).
+ *
+ * `DualCodeCheck` does the same SCRAPE-style: the evaluations of degree < k polynomials at x_1, ..., x_n
+ * form a Reed-Solomon code whose dual is { (v_1 f(x_1), ..., v_n f(x_n)) : deg f < n - k } with
+ * v_i = 1 / prod_{j != i} (x_i - x_j). A vector is a codeword iff it is orthogonal to the whole dual
+ * code, and a word outside the code is orthogonal to a random dual codeword with probability 1/|F|.
+ * The dual codeword is sampled once, after which every check is a single inner product.
+ */
+#[derive(Clone, Debug)]
+pub struct DualCodeCheck<F: IsFFTField = Stark252PrimeField> {
+    threshold: usize,
+    codeword: Vec<FieldElement<F>>,
+}
+
+impl<F: IsFFTField + IsPrimeField> DualCodeCheck<F> {
+    /// Samples a random dual codeword for evaluations at `points` of polynomials with `threshold` coefficients.
+    pub fn new<R: CryptoRng + RngCore>(
+        points: &[FieldElement<F>],
+        threshold: usize,
+        rng: &mut R,
+    ) -> Result<Self, SssError> {
+        let n = points.len();
+        if threshold == 0 || threshold > n {
+            return Err(SssError::InvalidThreshold(threshold, n));
+        }
+        let mut weights: Vec<FieldElement<F>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .filter(|&j| j != i)
+                    .fold(FieldElement::one(), |acc, j| acc * (&points[i] - &points[j]))
+            })
+            .collect();
+        FieldElement::inplace_batch_inverse(&mut weights).map_err(|_| SssError::SingularMatrix)?;
+        Ok(Self::with_weights(weights, points, threshold, rng))
+    }
+
+    // With the weights v_i known, the dual codeword only costs the evaluations of a random f
+    fn with_weights<R: CryptoRng + RngCore>(
+        weights: Vec<FieldElement<F>>,
+        points: &[FieldElement<F>],
+        threshold: usize,
+        rng: &mut R,
+    ) -> Self {
+        let coefficients: Vec<FieldElement<F>> = (threshold..points.len())
+            .map(|_| ShamirSecretSharing::<F>::rand_field_element_with_rng(rng))
+            .collect();
+        let evaluations = evaluate_at_points(&Polynomial::new(&coefficients), points);
+        let codeword = weights
+            .iter()
+            .zip(evaluations.iter())
+            .map(|(weight, evaluation)| weight * evaluation)
+            .collect();
+        DualCodeCheck { threshold, codeword }
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Whether the evaluations lie on a polynomial of degree < threshold, wrong with probability 1/|F|.
+    pub fn check(&self, values: &[FieldElement<F>]) -> Result<bool, SssError> {
+        if values.len() != self.codeword.len() {
+            return Err(SssError::WrongShareCount(self.codeword.len(), values.len()));
+        }
+        let inner_product = values
+            .iter()
+            .zip(self.codeword.iter())
+            .fold(FieldElement::<F>::zero(), |acc, (value, dual)| acc + value * dual);
+        Ok(inner_product == FieldElement::zero())
+    }
+}
+
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharing<F> {
+    /// Checks that the evaluations at the parties 1..=n lie on a polynomial of degree < threshold.
+    pub fn verify_degree(&self, values: &[FieldElement<F>]) -> Result<bool, SssError> {
+        if values.len() != self.share_amount {
+            return Err(SssError::WrongShareCount(self.share_amount, values.len()));
+        }
+        let polynomial = interpolate_points(&self.points(), values).ok_or(SssError::SingularMatrix)?;
+        Ok(polynomial.coeff_len() <= self.threshold)
+    }
+
+    /// A SCRAPE-style check for the evaluations at the parties 1..=n.
+    pub fn dual_code_check<R: CryptoRng + RngCore>(&self, rng: &mut R) -> Result<DualCodeCheck<F>, SssError> {
+        DualCodeCheck::new(&self.points(), self.threshold, rng)
+    }
+
+    fn points(&self) -> Vec<FieldElement<F>> {
+        (1..self.share_amount as u64 + 1).map(FieldElement::from).collect()
+    }
+}
+
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharingFFT<F> {
+    /// Checks that the evaluations at the parties' roots of unity w^0, ..., w^(n-1) lie on a polynomial of
+    /// degree < threshold. When n fills the domain this is an inverse FFT and a check that the high
+    /// coefficients vanish.
+    pub fn verify_degree(&self, values: &[FieldElement<F>]) -> Result<bool, SssError> {
+        if values.len() != self.share_amount {
+            return Err(SssError::WrongShareCount(self.share_amount, values.len()));
+        }
+        let polynomial = if self.share_amount.is_power_of_two() {
+            self.plan().inverse(values)?
+        } else {
+            interpolate_points(self.points(), values).ok_or(SssError::SingularMatrix)?
+        };
+        Ok(polynomial.coeff_len() <= self.threshold)
+    }
+
+    /// A SCRAPE-style check for the evaluations at the parties' roots of unity.
+    pub fn dual_code_check<R: CryptoRng + RngCore>(&self, rng: &mut R) -> Result<DualCodeCheck<F>, SssError> {
+        if !self.share_amount.is_power_of_two() {
+            return DualCodeCheck::new(self.points(), self.threshold, rng);
+        }
+        if self.threshold == 0 || self.threshold > self.share_amount {
+            return Err(SssError::InvalidThreshold(self.threshold, self.share_amount));
+        }
+        // On the whole domain prod (X - x_j) = X^n - 1, so 1 / prod_{j != i} (x_i - x_j) = x_i / n
+        let n_inverse = FieldElement::<F>::from(self.share_amount as u64)
+            .inv()
+            .map_err(|_| SssError::UnsupportedFftSize(self.share_amount))?;
+        let weights = self.points().iter().map(|x| x * &n_inverse).collect();
+        Ok(DualCodeCheck::with_weights(weights, self.points(), self.threshold, rng))
+    }
+
+    fn points(&self) -> &[FieldElement<F>] {
+        &self.plan().domain()[..self.share_amount]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LargeField, ShamirSecretSharing, ShamirSecretSharingFFT, SssError};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    type FE = FieldElement<Babybear31PrimeField>;
+
+    #[test]
+    fn low_degree_evaluations_pass_on_integer_points() {
+        let sss = ShamirSecretSharing::new(3, 7).unwrap();
+        let mut values = sss.evaluations(&sss.sample_polynomial(LargeField::from(9u64)));
+        let check = sss.dual_code_check(&mut ChaCha20Rng::seed_from_u64(1)).unwrap();
+        assert_eq!(sss.verify_degree(&values), Ok(true));
+        assert_eq!(check.check(&values), Ok(true));
+
+        values[4] += LargeField::one();
+        assert_eq!(sss.verify_degree(&values), Ok(false));
+        assert_eq!(check.check(&values), Ok(false));
+        assert_eq!(sss.verify_degree(&values[..6]), Err(SssError::WrongShareCount(7, 6)));
+    }
+
+    #[test]
+    fn low_degree_evaluations_pass_on_roots_of_unity() {
+        // 8 parties fill the domain and use the inverse FFT, 6 parties interpolate
+        for share_amount in [8, 6] {
+            let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(3, share_amount).unwrap();
+            let polynomial = sss.sample_polynomial(FE::from(9u64));
+            let mut values = sss.evaluations(&polynomial);
+            values.truncate(share_amount);
+            let check = sss.dual_code_check(&mut ChaCha20Rng::seed_from_u64(2)).unwrap();
+            assert_eq!(sss.verify_degree(&values), Ok(true));
+            assert_eq!(check.check(&values), Ok(true));
+
+            // Degree 3 is one too many
+            let high = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(4, share_amount).unwrap();
+            let mut values = high.evaluations(&high.sample_polynomial(FE::from(9u64)));
+            values.truncate(share_amount);
+            assert_eq!(sss.verify_degree(&values), Ok(false));
+            assert_eq!(check.check(&values), Ok(false));
+        }
+    }
+}
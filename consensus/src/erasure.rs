@@ -0,0 +1,104 @@
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{FftPlan, SssError, SubproductTree};
+
+/**
+ * Erasure decoding on a roots-of-unity domain
+ * Recovers P with deg P < k from its evaluations at k positions of the domain H = { w^0, ..., w^(N-1) }.
+ * Z = prod_{missing e} (X - w^e) vanishes exactly where the evaluations are unknown, so the evaluations of
+ * P * Z are known on all of H (zero at the missing points) and one inverse FFT gives P * Z, of degree < N.
+ * P is the quotient by Z, taken pointwise on a coset g * H where Z has no roots.
+ * Five FFTs of size N and a product over the missing points, O(N log N + m log^2 m) for m missing points
+ * instead of O(k^2) for Lagrange interpolation.
+ * Positions are exponents of w and may be anywhere in the domain; callers that decode shares bound them to
+ * their n parties first, as `ShamirSecretSharingFFT::interpolate_on_domain` does.
+ */
+pub fn erasure_decode<F: IsFFTField>(
+    plan: &FftPlan<F>,
+    positions: &[usize],
+    values: &[FieldElement<F>],
+) -> Result<Polynomial<FieldElement<F>>, SssError> {
+    let domain = plan.domain();
+    let domain_size = plan.size();
+    if positions.len() != values.len() {
+        return Err(SssError::WrongShareCount(positions.len(), values.len()));
+    }
+    if positions.is_empty() {
+        return Err(SssError::NotEnoughShares(1, 0));
+    }
+    let mut known = vec![false; domain_size];
+    for &position in positions {
+        match known.get_mut(position) {
+            None => return Err(SssError::InvalidIndex(position as u64 + 1)),
+            Some(true) => return Err(SssError::DuplicateIndex(position as u64 + 1)),
+            Some(seen) => *seen = true,
+        }
+    }
+    let missing: Vec<FieldElement<F>> = (0..domain_size)
+        .filter(|&e| !known[e])
+        .map(|e| domain[e].clone())
+        .collect();
+    let vanishing = if missing.is_empty() {
+        Polynomial::new(&[FieldElement::one()])
+    } else {
+        SubproductTree::new(&missing).root().clone()
+    };
+
+    // (P * Z)(w^e) = P(w^e) Z(w^e) where P is known and 0 where Z vanishes
+    let vanishing_evals = plan.forward(vanishing.coefficients());
+    let mut product_evals = vec![FieldElement::<F>::zero(); domain_size];
+    for (&e, value) in positions.iter().zip(values) {
+        product_evals[e] = value * &vanishing_evals[e];
+    }
+    let product = plan.inverse(&product_evals)?;
+
+    let offset = coset_offset(domain_size);
+    let product_coset = plan.forward_coset(product.coefficients(), &offset);
+    let mut vanishing_coset = plan.forward_coset(vanishing.coefficients(), &offset);
+    FieldElement::inplace_batch_inverse(&mut vanishing_coset).map_err(|_| SssError::SingularMatrix)?;
+    let quotient: Vec<FieldElement<F>> = product_coset
+        .iter()
+        .zip(vanishing_coset.iter())
+        .map(|(p, z)| p * z)
+        .collect();
+    plan.inverse_coset(&quotient, &offset)
+}
+
+// The smallest g >= 2 outside the subgroup of order n, g^n != 1
+fn coset_offset<F: IsFFTField>(n: usize) -> FieldElement<F> {
+    let mut offset = FieldElement::<F>::from(2u64);
+    while offset.pow(n as u64) == FieldElement::one() {
+        offset += FieldElement::<F>::one();
+    }
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{erasure_decode, FftPlan, ShamirSecretSharingFFT, SssError};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+
+    type FE = FieldElement<Babybear31PrimeField>;
+
+    #[test]
+    fn decodes_from_any_positions() {
+        let plan = FftPlan::<Babybear31PrimeField>::new(64).unwrap();
+        let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(20, 64).unwrap();
+        let polynomial = sss.sample_polynomial(FE::from(5u64));
+        let evaluations = sss.evaluations(&polynomial);
+
+        // Every third position, and a contiguous block at the end
+        let scattered: Vec<usize> = (0..64).step_by(3).take(20).collect();
+        let block: Vec<usize> = (44..64).collect();
+        for positions in [scattered, block, (0..64).collect()] {
+            let values: Vec<FE> = positions.iter().map(|&e| evaluations[e].clone()).collect();
+            assert_eq!(erasure_decode(&plan, &positions, &values).unwrap(), *polynomial);
+        }
+
+        assert_eq!(erasure_decode(&plan, &[1, 64], &evaluations[..2]), Err(SssError::InvalidIndex(65)));
+        assert_eq!(erasure_decode(&plan, &[3, 3], &evaluations[..2]), Err(SssError::DuplicateIndex(4)));
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+
+/// Everything that can go wrong when dealing, parsing or recovering shares. Shares usually come from
+/// the network, so malformed input is reported here instead of panicking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SssError {
+    /// A field element must be encoded with exactly this many bytes (expected, got)
+    InvalidLength(usize, usize),
+    /// The encoded integer is not below the field modulus
+    OutOfRange,
+    /// Party ids start at 1 and must fit in the evaluation domain
+    InvalidIndex(u64),
+    /// Party id 0 would evaluate the polynomial at x = 0, the secret itself
+    ZeroIndex,
+    /// Fewer shares than the threshold (needed, got)
+    NotEnoughShares(usize, usize),
+    /// Exactly this many shares or evaluations are expected (expected, got)
+    WrongShareCount(usize, usize),
+    /// The same party index appears twice
+    DuplicateIndex(u64),
+    /// The threshold must be between 1 and the number of shares (threshold, share amount)
+    InvalidThreshold(usize, usize),
+    /// The field has no FFT domain of this size, or the size is not a power of two
+    UnsupportedFftSize(usize),
+    /// The points of a Vandermonde system are not distinct
+    SingularMatrix,
+    /// The precomputed matrix could not be loaded
+    MatrixFile(String),
+    /// The shares were not produced by the same split
+    MixedSplits,
+    /// More corrupted shares than the decoder can correct
+    TooManyErrors,
+    /// Shares at different points or from different schemes can't be combined
+    Incompatible,
+    /// The scheme has fewer nonzero evaluation points than shares (maximum, requested)
+    TooManyShares(usize, usize),
+    /// The bytes don't encode a point of the commitment group
+    InvalidCommitment,
+    /// A share with threshold 0 lies on no polynomial
+    ZeroThreshold,
+    /// The polynomial has more coefficients than the threshold allows (threshold, coefficients)
+    DegreeTooHigh(usize, usize),
+}
+
+impl fmt::Display for SssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SssError::InvalidLength(exp, got) => {
+                write!(f, "expected a {} byte field element, got {} bytes", exp, got)
+            }
+            SssError::OutOfRange => write!(f, "field element encoding is not below the modulus"),
+            SssError::InvalidIndex(index) => write!(f, "invalid share index {}", index),
+            SssError::ZeroIndex => write!(f, "share index 0 is the secret's evaluation point"),
+            SssError::NotEnoughShares(needed, got) => {
+                write!(f, "need at least {} shares, got {}", needed, got)
+            }
+            SssError::WrongShareCount(expected, got) => {
+                write!(f, "expected exactly {} shares, got {}", expected, got)
+            }
+            SssError::DuplicateIndex(index) => write!(f, "share index {} appears twice", index),
+            SssError::InvalidThreshold(threshold, share_amount) => {
+                write!(f, "threshold {} is not between 1 and {}", threshold, share_amount)
+            }
+            SssError::UnsupportedFftSize(size) => write!(f, "no FFT domain of size {}", size),
+            SssError::SingularMatrix => write!(f, "the interpolation points are not distinct"),
+            SssError::MatrixFile(reason) => write!(f, "failed to load the matrix: {}", reason),
+            SssError::MixedSplits => write!(f, "shares come from different splits"),
+            SssError::TooManyErrors => write!(f, "too many corrupted shares to decode"),
+            SssError::Incompatible => write!(f, "shares are at different points or from different schemes"),
+            SssError::TooManyShares(max, requested) => {
+                write!(f, "at most {} shares are supported, requested {}", max, requested)
+            }
+            SssError::InvalidCommitment => write!(f, "commitment is not a point of the group"),
+            SssError::ZeroThreshold => write!(f, "share threshold 0 describes no polynomial"),
+            SssError::DegreeTooHigh(threshold, coefficients) => {
+                write!(f, "a polynomial with {} coefficients can't be shared with threshold {}", coefficients, threshold)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SssError {}
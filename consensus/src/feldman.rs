@@ -0,0 +1,231 @@
+use lambdaworks_math::cyclic_group::IsGroup;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use lambdaworks_math::elliptic_curve::traits::IsEllipticCurve;
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
+use lambdaworks_math::polynomial::Polynomial;
+use lambdaworks_math::traits::ByteConversion;
+
+use crate::{bytes_to_limbs, ByteShare, Scheme, ShamirSecretSharing, Share, SssError};
+
+/// An elliptic curve together with the prime field its scalars live in. Shares dealt over
+/// `ScalarField` can be checked against commitments in the curve's group.
+pub trait FeldmanCurve: IsEllipticCurve {
+    type ScalarField: IsFFTField + IsPrimeField;
+}
+
+impl FeldmanCurve for BLS12381Curve {
+    type ScalarField = FrField;
+}
+
+pub type Commitment<C> = <C as IsEllipticCurve>::PointRepresentation;
+
+/// The shares of parties 1..=n and the commitments to the polynomial.
+pub type Dealing<C> = (Vec<Share<<C as FeldmanCurve>::ScalarField>>, Vec<Commitment<C>>);
+
+/// Commitments to a byte string secret, one vector of commitments per limb.
+pub type ByteCommitments<C> = Vec<Vec<Commitment<C>>>;
+
+/// The byte shares of parties 1..=n and the commitments of every limb.
+pub type ByteDealing<C> = (Vec<ByteShare<<C as FeldmanCurve>::ScalarField>>, ByteCommitments<C>);
+
+/**
+ * Feldman's Verifiable Secret Sharing
+ * The dealer shares the secret with ShamirSecretSharing over the scalar field of the curve and
+ * publishes g^{a_i} for every coefficient a_i of the sharing polynomial.
+ * Party i accepts its share P(i) iff g^{P(i)} = prod_j (g^{a_j})^{i^j}.
+ * Note that g^{a_0} = g^{secret} is public, so the secret must have enough entropy.
+ */
+#[derive(Clone, Debug)]
+pub struct FeldmanVSS<C: FeldmanCurve = BLS12381Curve> {
+    pub sss: ShamirSecretSharing<C::ScalarField>,
+}
+
+impl<C: FeldmanCurve> FeldmanVSS<C> {
+    pub fn new(threshold: usize, share_amount: usize) -> Result<Self, SssError> {
+        Ok(FeldmanVSS {
+            sss: ShamirSecretSharing::new(threshold, share_amount)?,
+        })
+    }
+
+    /// g^{a_i} for every coefficient, padded with the identity up to `threshold` commitments.
+    pub fn commit(&self, polynomial: &Polynomial<FieldElement<C::ScalarField>>) -> Vec<Commitment<C>> {
+        let generator = C::generator();
+        let mut commitments: Vec<Commitment<C>> = polynomial
+            .coefficients()
+            .iter()
+            .map(|coefficient| generator.operate_with_self(coefficient.representative()))
+            .collect();
+        commitments.resize(self.sss.threshold, Commitment::<C>::neutral_element());
+        commitments
+    }
+
+    /// Splits the secret and returns the shares along with the public commitments.
+    pub fn deal(
+        &self,
+        secret: FieldElement<C::ScalarField>,
+    ) -> Result<Dealing<C>, SssError> {
+        let polynomial = self.sss.sample_polynomial(secret);
+        let commitments = self.commit(&polynomial);
+        Ok((self.sss.generating_shares(&polynomial)?, commitments))
+    }
+
+    /// Checks g^{share} against the commitments evaluated in the exponent at the share's point.
+    pub fn verify_share(&self, share: &Share<C::ScalarField>, commitments: &[Commitment<C>]) -> bool {
+        // The commitments are to a polynomial evaluated at the integer points of `Shamir`
+        if share.scheme != Scheme::Shamir
+            || commitments.len() != self.sss.threshold
+            || share.threshold != self.sss.threshold
+        {
+            return false;
+        }
+        let x = share.x();
+        let powers: Vec<FieldElement<C::ScalarField>> = (0..commitments.len())
+            .map(|j| x.pow(j as u64))
+            .collect();
+        let expected = evaluate_in_exponent::<C>(commitments, &powers);
+        C::generator().operate_with_self(share.value.representative()) == expected
+    }
+
+    /// Verifies many shares against the same commitments at roughly the cost of one verification.
+    /// The checks are combined with random weights r_k, g^{sum r_k P(x_k)} = prod_j (g^{a_j})^{sum r_k x_k^j},
+    /// so a bad share only passes with probability 1/|F|.
+    pub fn batch_verify_shares(&self, shares: &[Share<C::ScalarField>], commitments: &[Commitment<C>]) -> bool {
+        if commitments.len() != self.sss.threshold
+            || shares
+                .iter()
+                .any(|share| share.scheme != Scheme::Shamir || share.threshold != self.sss.threshold)
+        {
+            return false;
+        }
+
+        let mut weighted_value = FieldElement::<C::ScalarField>::zero();
+        let mut weighted_powers = vec![FieldElement::<C::ScalarField>::zero(); commitments.len()];
+        for share in shares {
+            let r = ShamirSecretSharing::<C::ScalarField>::rand_field_element();
+            weighted_value += &r * &share.value;
+
+            let x = share.x();
+            let mut power = r;
+            for weighted_power in weighted_powers.iter_mut() {
+                *weighted_power = &*weighted_power + &power;
+                power *= &x;
+            }
+        }
+
+        let expected = evaluate_in_exponent::<C>(commitments, &weighted_powers);
+        C::generator().operate_with_self(weighted_value.representative()) == expected
+    }
+}
+
+// Byte string secrets
+impl<C: FeldmanCurve> FeldmanVSS<C>
+where
+    FieldElement<C::ScalarField>: ByteConversion,
+{
+    /// Shares a byte string limb by limb like `split_bytes`, with the commitments of every limb.
+    pub fn deal_bytes(&self, secret: &[u8]) -> Result<ByteDealing<C>, SssError> {
+        let (limb_shares, commitments) = bytes_to_limbs::<C::ScalarField>(secret)?
+            .into_iter()
+            .map(|limb| self.deal(limb))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+        let shares = ByteShare::deal(
+            limb_shares,
+            secret.len(),
+            self.sss.threshold,
+            Scheme::Shamir,
+            self.sss.share_amount,
+        );
+        Ok((shares, commitments))
+    }
+
+    /// Checks every limb of a byte share against the commitments of that limb.
+    pub fn verify_byte_share(&self, share: &ByteShare<C::ScalarField>, commitments: &[Vec<Commitment<C>>]) -> bool {
+        share.scheme == Scheme::Shamir
+            && share.values.len() == commitments.len()
+            && share.values.iter().zip(commitments).all(|(value, limb_commitments)| {
+                let limb = Share {
+                    index: share.index,
+                    value: value.clone(),
+                    threshold: share.threshold,
+                    scheme: share.scheme,
+                };
+                self.verify_share(&limb, limb_commitments)
+            })
+    }
+}
+
+// prod_j commitments[j]^{exponents[j]}
+pub(crate) fn evaluate_in_exponent<C: FeldmanCurve>(
+    commitments: &[Commitment<C>],
+    exponents: &[FieldElement<C::ScalarField>],
+) -> Commitment<C> {
+    commitments
+        .iter()
+        .zip(exponents)
+        .fold(Commitment::<C>::neutral_element(), |acc, (commitment, exponent)| {
+            acc.operate_with(&commitment.operate_with_self(exponent.representative()))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FeldmanVSS, Scheme};
+    use lambdaworks_math::cyclic_group::IsGroup;
+    use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrElement;
+
+    #[test]
+    fn dealt_shares_verify() {
+        let vss: FeldmanVSS = FeldmanVSS::new(3, 5).unwrap();
+        let secret = FrElement::from(1234u64);
+        let (shares, commitments) = vss.deal(secret.clone()).unwrap();
+
+        assert_eq!(commitments.len(), 3);
+        for share in &shares {
+            assert!(vss.verify_share(share, &commitments));
+        }
+        assert!(vss.batch_verify_shares(&shares, &commitments));
+        assert_eq!(vss.sss.recover(&shares[1..4]).unwrap(), secret);
+    }
+
+    #[test]
+    fn tampered_shares_are_rejected() {
+        let vss: FeldmanVSS = FeldmanVSS::new(3, 5).unwrap();
+        let (mut shares, commitments) = vss.deal(FrElement::from(42u64)).unwrap();
+
+        shares[2].value = &shares[2].value + FrElement::one();
+        assert!(!vss.verify_share(&shares[2], &commitments));
+        assert!(!vss.batch_verify_shares(&shares, &commitments));
+        assert!(vss.batch_verify_shares(&shares[..2], &commitments));
+
+        // Commitments to another polynomial don't match
+        let (other_shares, mut other_commitments) = vss.deal(FrElement::from(42u64)).unwrap();
+        assert!(!vss.verify_share(&shares[0], &other_commitments));
+        other_commitments[1] = other_commitments[1].neg();
+        assert!(!vss.batch_verify_shares(&other_shares, &other_commitments));
+
+        // Party 1 sits at x = 1 in both schemes, only the scheme tells the shares apart
+        let mut relabeled = shares[0].clone();
+        relabeled.scheme = Scheme::ShamirFFT { share_amount: 5 };
+        assert!(!vss.verify_share(&relabeled, &commitments));
+        assert!(!vss.batch_verify_shares(&[relabeled], &commitments));
+    }
+
+    #[test]
+    fn byte_shares_verify_limb_by_limb() {
+        let vss: FeldmanVSS = FeldmanVSS::new(2, 4).unwrap();
+        let secret = b"a secret longer than one limb of the scalar field".to_vec();
+        let (mut shares, commitments) = vss.deal_bytes(&secret).unwrap();
+        assert_eq!(commitments.len(), shares[0].values.len());
+        assert!(commitments.len() > 1);
+        assert!(shares.iter().all(|share| vss.verify_byte_share(share, &commitments)));
+        assert_eq!(*vss.sss.recover_bytes(&shares[2..]).unwrap(), secret);
+
+        shares[1].values[1] = &shares[1].values[1] + FrElement::one();
+        assert!(!vss.verify_byte_share(&shares[1], &commitments));
+        assert!(!vss.verify_byte_share(&shares[0], &commitments[1..]));
+    }
+}
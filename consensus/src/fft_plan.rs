@@ -0,0 +1,167 @@
+use lambdaworks_math::fft::cpu::bit_reversing::in_place_bit_reverse_permute;
+use lambdaworks_math::fft::cpu::fft::in_place_nr_2radix_fft;
+use lambdaworks_math::fft::cpu::roots_of_unity::{get_powers_of_primitive_root, get_twiddles};
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, RootsConfig};
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::SssError;
+
+/**
+ * FFT plan for a fixed domain size N
+ * `Polynomial::evaluate_fft` and `interpolate_fft` recompute the twiddle factors on every call. The plan
+ * computes the bit-reversed twiddles for the forward and the inverse transform once, together with the
+ * domain w^0, ..., w^(N-1) and 1 / N, and runs the in-place radix-2 FFT on caller-provided buffers.
+ */
+#[derive(Clone, Debug)]
+pub struct FftPlan<F: IsFFTField = Stark252PrimeField> {
+    domain: Vec<FieldElement<F>>,
+    twiddles: Vec<FieldElement<F>>,
+    inverse_twiddles: Vec<FieldElement<F>>,
+    size_inverse: FieldElement<F>,
+}
+
+impl<F: IsFFTField> FftPlan<F> {
+    /// Plan for the domain of size n.next_power_of_two(), fails if the field has no such domain.
+    pub fn new(n: usize) -> Result<Self, SssError> {
+        let size = n.next_power_of_two();
+        let order = size.trailing_zeros() as u64;
+        let unsupported = |_| SssError::UnsupportedFftSize(size);
+        Ok(FftPlan {
+            domain: get_powers_of_primitive_root::<F>(order, size, RootsConfig::Natural).map_err(unsupported)?,
+            twiddles: get_twiddles::<F>(order, RootsConfig::BitReverse).map_err(unsupported)?,
+            inverse_twiddles: get_twiddles::<F>(order, RootsConfig::BitReverseInversed).map_err(unsupported)?,
+            size_inverse: FieldElement::<F>::from(size as u64).inv().map_err(|_| SssError::UnsupportedFftSize(size))?,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.domain.len()
+    }
+
+    /// w^0, ..., w^(N-1)
+    pub fn domain(&self) -> &[FieldElement<F>] {
+        &self.domain
+    }
+
+    /// Evaluations at the domain of the polynomial with these coefficients, written to `output`.
+    /// Coefficients beyond N wrap around, since X^N = 1 on the domain.
+    pub fn forward_into(&self, coefficients: &[FieldElement<F>], output: &mut Vec<FieldElement<F>>) {
+        output.clear();
+        output.resize(self.size(), FieldElement::zero());
+        for (i, coefficient) in coefficients.iter().enumerate() {
+            output[i % self.size()] += coefficient.clone();
+        }
+        self.forward_in_place(output);
+    }
+
+    pub fn forward(&self, coefficients: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
+        let mut output = Vec::with_capacity(self.size());
+        self.forward_into(coefficients, &mut output);
+        output
+    }
+
+    /// Replaces N coefficients by the evaluations at the domain.
+    pub fn forward_in_place(&self, buffer: &mut [FieldElement<F>]) {
+        debug_assert_eq!(buffer.len(), self.size());
+        in_place_nr_2radix_fft(buffer, &self.twiddles);
+        in_place_bit_reverse_permute(buffer);
+    }
+
+    /// Replaces the N evaluations at the domain by the coefficients.
+    pub fn inverse_in_place(&self, buffer: &mut [FieldElement<F>]) {
+        debug_assert_eq!(buffer.len(), self.size());
+        in_place_nr_2radix_fft(buffer, &self.inverse_twiddles);
+        in_place_bit_reverse_permute(buffer);
+        for value in buffer.iter_mut() {
+            *value = &*value * &self.size_inverse;
+        }
+    }
+
+    /// The polynomial of degree < N through the evaluations at the domain.
+    pub fn inverse(&self, evaluations: &[FieldElement<F>]) -> Result<Polynomial<FieldElement<F>>, SssError> {
+        if evaluations.len() != self.size() {
+            return Err(SssError::WrongShareCount(self.size(), evaluations.len()));
+        }
+        let mut coefficients = evaluations.to_vec();
+        self.inverse_in_place(&mut coefficients);
+        Ok(Polynomial::new(&coefficients))
+    }
+
+    /// Evaluations at the coset offset * w^0, ..., offset * w^(N-1).
+    pub fn forward_coset(&self, coefficients: &[FieldElement<F>], offset: &FieldElement<F>) -> Vec<FieldElement<F>> {
+        let mut power = FieldElement::<F>::one();
+        let scaled: Vec<FieldElement<F>> = coefficients
+            .iter()
+            .map(|coefficient| {
+                let scaled = coefficient * &power;
+                power = &power * offset;
+                scaled
+            })
+            .collect();
+        self.forward(&scaled)
+    }
+
+    /// The polynomial of degree < N through the evaluations at the coset offset * w^0, ..., offset * w^(N-1).
+    pub fn inverse_coset(
+        &self,
+        evaluations: &[FieldElement<F>],
+        offset: &FieldElement<F>,
+    ) -> Result<Polynomial<FieldElement<F>>, SssError> {
+        let scaled = self.inverse(evaluations)?;
+        let offset_inverse = offset.inv().map_err(|_| SssError::SingularMatrix)?;
+        let mut power = FieldElement::<F>::one();
+        let coefficients: Vec<FieldElement<F>> = scaled
+            .coefficients()
+            .iter()
+            .map(|coefficient| {
+                let unscaled = coefficient * &power;
+                power = &power * &offset_inverse;
+                unscaled
+            })
+            .collect();
+        Ok(Polynomial::new(&coefficients))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FftPlan, ShamirSecretSharing, SssError};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+    use lambdaworks_math::polynomial::Polynomial;
+
+    type FE = FieldElement<Babybear31PrimeField>;
+
+    fn random_coefficients(length: usize) -> Vec<FE> {
+        (0..length)
+            .map(|_| ShamirSecretSharing::<Babybear31PrimeField>::rand_field_element())
+            .collect()
+    }
+
+    #[test]
+    fn plan_matches_lambdaworks_fft() {
+        let plan = FftPlan::<Babybear31PrimeField>::new(12).unwrap();
+        assert_eq!(plan.size(), 16);
+
+        let polynomial = Polynomial::new(&random_coefficients(10));
+        let evaluations = plan.forward(polynomial.coefficients());
+        assert_eq!(evaluations, Polynomial::evaluate_fft::<Babybear31PrimeField>(&polynomial, 1, Some(16)).unwrap());
+        assert_eq!(plan.inverse(&evaluations).unwrap(), polynomial);
+        assert_eq!(plan.inverse(&evaluations[..8]), Err(SssError::WrongShareCount(16, 8)));
+
+        let offset = FE::from(3u64);
+        let on_coset = plan.forward_coset(polynomial.coefficients(), &offset);
+        assert_eq!(on_coset[5], polynomial.evaluate(&(&offset * &plan.domain()[5])));
+        assert_eq!(plan.inverse_coset(&on_coset, &offset).unwrap(), polynomial);
+    }
+
+    #[test]
+    fn long_polynomials_wrap_around_the_domain() {
+        let plan = FftPlan::<Babybear31PrimeField>::new(8).unwrap();
+        let polynomial = Polynomial::new(&random_coefficients(21));
+        let expected: Vec<FE> = plan.domain().iter().map(|x| polynomial.evaluate(x)).collect();
+        assert_eq!(plan.forward(polynomial.coefficients()), expected);
+    }
+}
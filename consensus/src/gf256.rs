@@ -0,0 +1,265 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use rand_core::{CryptoRng, OsRng, RngCore};
+
+use crate::{Erase, Secret, SssError};
+
+/**
+ * Shamir secret sharing over GF(2^8)
+ * Every byte of the secret is shared on its own with a random polynomial of degree `threshold - 1` whose
+ * constant term is that byte, and a share holds the evaluations of all of them at one nonzero x.
+ * The field is the AES field GF(2)[X] / (X^8 + X^4 + X^3 + X + 1), the one HashiCorp Vault's `shamir`
+ * package uses, so shares can be exchanged with it. `reed_solomon_erasure::galois_8` reduces by
+ * X^8 + X^4 + X^3 + X^2 + 1 instead, its products differ and it can't be used for Vault's shares.
+ * The arithmetic is constant time: no tables indexed by secret bytes and no secret-dependent branches.
+ */
+#[derive(Clone, Debug)]
+pub struct Gf256SecretSharing {
+    /// the threshold of shares to recover the secret.
+    pub threshold: usize,
+    /// the total number of shares to generate from the secret, at most 255.
+    pub share_amount: usize,
+}
+
+/// A party's share of a byte string over GF(2^8): one evaluation per byte of the secret, at x = `index`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Gf256Share {
+    pub index: u8,
+    pub value: Vec<u8>,
+}
+
+impl Drop for Gf256Share {
+    fn drop(&mut self) {
+        self.value.erase();
+    }
+}
+
+impl fmt::Debug for Gf256Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gf256Share")
+            .field("index", &self.index)
+            .field("value", &"<redacted>")
+            .finish()
+    }
+}
+
+impl Gf256Share {
+    /// Vault's encoding: the evaluations followed by the x-coordinate byte.
+    pub fn to_vault_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.value.len() + 1);
+        bytes.extend_from_slice(&self.value);
+        bytes.push(self.index);
+        bytes
+    }
+
+    /// Parses a share in Vault's encoding, at least one evaluation and a nonzero x-coordinate.
+    pub fn from_vault_bytes(bytes: &[u8]) -> Result<Self, SssError> {
+        match bytes.split_last() {
+            Some((&0, _)) => Err(SssError::ZeroIndex),
+            Some((&index, value)) if !value.is_empty() => Ok(Gf256Share {
+                index,
+                value: value.to_vec(),
+            }),
+            _ => Err(SssError::InvalidLength(2, bytes.len())),
+        }
+    }
+}
+
+// Carry-less multiplication reduced by X^8 + X^4 + X^3 + X + 1, bit by bit with masks instead of branches
+fn gf256_mul(a: u8, b: u8) -> u8 {
+    let mut product = 0u8;
+    for i in (0..8).rev() {
+        let reduce = 0u8.wrapping_sub(product >> 7);
+        let add = 0u8.wrapping_sub((b >> i) & 1);
+        product = (product << 1) ^ (reduce & 0x1b) ^ (add & a);
+    }
+    product
+}
+
+// a^254 = a^-1 for a != 0, and 0 for a = 0
+fn gf256_inv(a: u8) -> u8 {
+    let mut result = 1u8;
+    for i in (0..8).rev() {
+        result = gf256_mul(result, result);
+        if (254u8 >> i) & 1 == 1 {
+            result = gf256_mul(result, a);
+        }
+    }
+    result
+}
+
+impl Gf256SecretSharing {
+    /// GF(2^8) has 255 nonzero points, so at most 255 shares.
+    pub const MAX_SHARES: usize = 255;
+
+    pub fn new(threshold: usize, share_amount: usize) -> Result<Self, SssError> {
+        if share_amount > Self::MAX_SHARES {
+            return Err(SssError::TooManyShares(Self::MAX_SHARES, share_amount));
+        }
+        if threshold == 0 || threshold > share_amount {
+            return Err(SssError::InvalidThreshold(threshold, share_amount));
+        }
+        Ok(Gf256SecretSharing {
+            threshold,
+            share_amount,
+        })
+    }
+
+    /// Shares every byte of the secret, party i gets the evaluations at x = i.
+    pub fn split(&self, secret: &[u8]) -> Vec<Gf256Share> {
+        self.split_with_rng(secret, &mut OsRng)
+    }
+
+    /// Same as `split`, with the random coefficients drawn from `rng`.
+    pub fn split_with_rng<R: CryptoRng + RngCore>(&self, secret: &[u8], rng: &mut R) -> Vec<Gf256Share> {
+        let mut shares: Vec<Gf256Share> = (1..self.share_amount + 1)
+            .map(|index| Gf256Share {
+                index: index as u8,
+                value: Vec::with_capacity(secret.len()),
+            })
+            .collect();
+        let mut coefficients = vec![0u8; self.threshold];
+        for &byte in secret {
+            coefficients[0] = byte;
+            rng.fill_bytes(&mut coefficients[1..]);
+            for share in shares.iter_mut() {
+                // Horner at x = index
+                let value = coefficients
+                    .iter()
+                    .rev()
+                    .fold(0u8, |acc, &coefficient| gf256_mul(acc, share.index) ^ coefficient);
+                share.value.push(value);
+            }
+        }
+        coefficients.erase();
+        shares
+    }
+
+    /// Interpolates every byte at x = 0 from all the given shares, which may come from Vault and sit at any
+    /// distinct nonzero points.
+    pub fn recover(&self, shares: &[Gf256Share]) -> Result<Secret<Vec<u8>>, SssError> {
+        if shares.len() < self.threshold || shares.is_empty() {
+            return Err(SssError::NotEnoughShares(self.threshold.max(1), shares.len()));
+        }
+        let length = shares[0].value.len();
+        let mut indices = HashSet::new();
+        for share in shares {
+            if share.index == 0 {
+                return Err(SssError::ZeroIndex);
+            }
+            if !indices.insert(share.index) {
+                return Err(SssError::DuplicateIndex(share.index as u64));
+            }
+            if share.value.len() != length {
+                return Err(SssError::MixedSplits);
+            }
+        }
+
+        // Lagrange weights at 0: l_j = prod_{m != j} x_m / (x_m - x_j), subtraction is xor
+        let weights: Vec<u8> = shares
+            .iter()
+            .map(|share| {
+                shares
+                    .iter()
+                    .filter(|other| other.index != share.index)
+                    .fold(1u8, |acc, other| {
+                        gf256_mul(acc, gf256_mul(other.index, gf256_inv(other.index ^ share.index)))
+                    })
+            })
+            .collect();
+        let secret = (0..length)
+            .map(|position| {
+                shares
+                    .iter()
+                    .zip(weights.iter())
+                    .fold(0u8, |acc, (share, &weight)| acc ^ gf256_mul(weight, share.value[position]))
+            })
+            .collect();
+        Ok(Secret::new(secret))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gf256::{gf256_inv, gf256_mul};
+    use crate::{Gf256SecretSharing, Gf256Share, SssError};
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    #[test]
+    fn arithmetic_is_the_aes_field() {
+        // FIPS-197 examples: {57} * {83} = {c1}, {57} * {13} = {fe} and {53}^-1 = {ca}
+        assert_eq!(gf256_mul(0x57, 0x83), 0xc1);
+        assert_eq!(gf256_mul(0x57, 0x13), 0xfe);
+        assert_eq!(gf256_inv(0x53), 0xca);
+        assert!((1..=255u8).all(|a| gf256_mul(a, gf256_inv(a)) == 1));
+    }
+
+    #[test]
+    fn any_threshold_subset_recovers_the_secret() {
+        let sss = Gf256SecretSharing::new(3, 255).unwrap();
+        let secret = b"correct horse battery staple".to_vec();
+        let shares = sss.split_with_rng(&secret, &mut ChaCha20Rng::seed_from_u64(4));
+        assert_eq!(shares.len(), 255);
+        assert_eq!(*sss.recover(&shares[..3]).unwrap(), secret);
+        assert_eq!(*sss.recover(&[shares[254].clone(), shares[7].clone(), shares[100].clone()]).unwrap(), secret);
+        assert_eq!(*sss.recover(&shares[40..50]).unwrap(), secret);
+
+        // Two shares are not enough, and alone they tell nothing about the secret
+        assert_eq!(sss.recover(&shares[..2]), Err(SssError::NotEnoughShares(3, 2)));
+        assert_eq!(
+            sss.recover(&[shares[0].clone(), shares[0].clone(), shares[1].clone()]),
+            Err(SssError::DuplicateIndex(1))
+        );
+        assert_eq!(Gf256SecretSharing::new(3, 256).unwrap_err(), SssError::TooManyShares(255, 256));
+    }
+
+    #[test]
+    fn vault_encoding_round_trips() {
+        let sss = Gf256SecretSharing::new(2, 5).unwrap();
+        let shares = sss.split(b"vault");
+        let encoded: Vec<Vec<u8>> = shares.iter().map(Gf256Share::to_vault_bytes).collect();
+        assert_eq!(encoded[3].len(), 6);
+        assert_eq!(encoded[3][5], 4);
+
+        // Vault picks random distinct x-coordinates, the points need not be 1..=n
+        let mut imported: Vec<Gf256Share> = encoded[1..3]
+            .iter()
+            .map(|bytes| Gf256Share::from_vault_bytes(bytes).unwrap())
+            .collect();
+        assert_eq!(*sss.recover(&imported).unwrap(), b"vault".to_vec());
+        imported[0].index = 200;
+        assert_ne!(*sss.recover(&imported).unwrap(), b"vault".to_vec());
+
+        assert_eq!(Gf256Share::from_vault_bytes(&[7]), Err(SssError::InvalidLength(2, 1)));
+        assert_eq!(Gf256Share::from_vault_bytes(&[7, 0]), Err(SssError::ZeroIndex));
+    }
+
+    #[test]
+    fn recovers_shares_in_vault_split_layout() {
+        // The output of Split([]byte("vault"), 5, 3) from Vault's shamir package, computed outside this crate by
+        // following Split step by step: x-coordinates from a random permutation of 1..=255, evaluations with its
+        // mult and Horner loop, and the x-coordinate appended to every share
+        let vault_shares: [[u8; 6]; 5] = [
+            [0x90, 0xc7, 0x30, 0x75, 0x2e, 0x39],
+            [0x87, 0x43, 0x78, 0xfd, 0x47, 0x9d],
+            [0xef, 0xe1, 0xbf, 0x56, 0x3f, 0x9a],
+            [0x0f, 0x62, 0xb2, 0xf9, 0xab, 0xb2],
+            [0x16, 0xba, 0xa4, 0xe4, 0x2c, 0x7b],
+        ];
+        let shares: Vec<Gf256Share> = vault_shares
+            .iter()
+            .map(|bytes| Gf256Share::from_vault_bytes(bytes).unwrap())
+            .collect();
+        assert_eq!(shares[0].index, 0x39);
+
+        let sss = Gf256SecretSharing::new(3, 5).unwrap();
+        for subset in [&shares[..3], &shares[2..], &shares[..]] {
+            assert_eq!(*sss.recover(subset).unwrap(), b"vault".to_vec());
+        }
+        let reordered = [shares[4].clone(), shares[0].clone(), shares[3].clone()];
+        assert_eq!(*sss.recover(&reordered).unwrap(), b"vault".to_vec());
+        assert_eq!(shares[1].to_vault_bytes(), vault_shares[1].to_vec());
+    }
+}
@@ -0,0 +1,257 @@
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::polynomial::Polynomial;
+use rand_core::{CryptoRng, OsRng, RngCore};
+
+use crate::feldman::evaluate_in_exponent;
+use crate::{Commitment, Erase, FeldmanCurve, FeldmanVSS, Scheme, Secret, ShamirSecretSharing, Share, SssError};
+
+/// Commitments to a bivariate polynomial, `commitments[l]` commits to the coefficient of y^l, a polynomial in x.
+pub type BivariateCommitments<C> = Vec<Vec<Commitment<C>>>;
+
+/// The rows dealt to parties 1..=n, each as its evaluations at 0, ..., t_h - 1.
+pub type Rows<F> = Vec<Secret<Vec<FieldElement<F>>>>;
+
+/**
+ * High-threshold asynchronous complete secret sharing (HACSS)
+ * The dealer samples phi(x, y) of degree < t_h in x and < t_l in y with phi(0, 0) = s. The secret is shared
+ * by p(x) = phi(x, 0) with threshold t_h, which may be above n/3, and party i's share is p(i).
+ * Party j gets its row r_j(x) = phi(x, j) as the evaluations at 0, ..., t_h - 1 and completes it with
+ * `fill_evaluation_at_all_points`. It sends phi(i, j) to party i, a point on i's column c_i(y) = phi(i, y), and
+ * t_l such points give i its column and c_i(0) = p(i), whether or not the dealer sent i anything. A party
+ * without a row interpolates it from t_h points phi(j, i) sent by parties that know their column.
+ * Every evaluation is checked against Feldman commitments g^{a_kl} to the coefficients of phi.
+ */
+#[derive(Clone, Debug)]
+pub struct HighThresholdSharing<C: FeldmanCurve = BLS12381Curve> {
+    /// the rows and the sharing of the secret, threshold t_h
+    pub high: FeldmanVSS<C>,
+    /// the columns, threshold t_l
+    pub low: ShamirSecretSharing<C::ScalarField>,
+}
+
+impl<C: FeldmanCurve> HighThresholdSharing<C> {
+    pub fn new(high_threshold: usize, low_threshold: usize, share_amount: usize) -> Result<Self, SssError> {
+        Ok(HighThresholdSharing {
+            high: FeldmanVSS::new(high_threshold, share_amount)?,
+            low: ShamirSecretSharing::new(low_threshold, share_amount)?,
+        })
+    }
+
+    /// The rows of parties 1..=n in compact form, and the commitments.
+    pub fn deal(&self, secret: FieldElement<C::ScalarField>) -> (Rows<C::ScalarField>, BivariateCommitments<C>) {
+        self.deal_with_rng(secret, &mut OsRng)
+    }
+
+    /// Same as `deal`, with the random coefficients drawn from `rng`.
+    pub fn deal_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        secret: FieldElement<C::ScalarField>,
+        rng: &mut R,
+    ) -> (Rows<C::ScalarField>, BivariateCommitments<C>) {
+        // phi(x, y) = sum_l A_l(x) y^l, the constant of A_0 is the secret
+        let mut constant = secret;
+        let coefficients: Vec<Secret<Polynomial<FieldElement<C::ScalarField>>>> = (0..self.low.threshold)
+            .map(|_| {
+                let polynomial = self.high.sss.sample_polynomial_with_rng(constant.clone(), rng);
+                constant = ShamirSecretSharing::<C::ScalarField>::rand_field_element_with_rng(rng);
+                polynomial
+            })
+            .collect();
+        let commitments = coefficients.iter().map(|polynomial| self.high.commit(polynomial)).collect();
+
+        let rows = (1..self.high.sss.share_amount as u64 + 1)
+            .map(|party| {
+                let y = Scheme::Shamir.point::<C::ScalarField>(party);
+                let mut power = FieldElement::one();
+                let mut row = Secret::new(Polynomial::zero());
+                for polynomial in &coefficients {
+                    let term = Secret::new(&**polynomial * &power);
+                    let sum = ShamirSecretSharing::add_polynomials(&row, &term);
+                    std::mem::replace(&mut *row, sum).erase();
+                    power = &power * &y;
+                }
+                Secret::new(
+                    (0..self.high.sss.threshold as u64)
+                        .map(|x| row.evaluate(&FieldElement::from(x)))
+                        .collect(),
+                )
+            })
+            .collect();
+        (rows, commitments)
+    }
+
+    /// A row at 0..=n from its evaluations at 0, ..., t_h - 1.
+    pub fn complete_row(
+        &self,
+        row: &[FieldElement<C::ScalarField>],
+    ) -> Result<Secret<Vec<FieldElement<C::ScalarField>>>, SssError> {
+        if row.len() != self.high.sss.threshold {
+            return Err(SssError::WrongShareCount(self.high.sss.threshold, row.len()));
+        }
+        let mut evaluations = Secret::new(row.to_vec());
+        self.high.sss.fill_evaluation_at_all_points(&mut evaluations)?;
+        Ok(evaluations)
+    }
+
+    /// A row at 0..=n from at least t_h points on it, the shares of the row held by the parties' columns.
+    pub fn interpolate_row(
+        &self,
+        points: &[Share<C::ScalarField>],
+    ) -> Result<Secret<Vec<FieldElement<C::ScalarField>>>, SssError> {
+        let row = self.high.sss.reconstructing(points)?;
+        let mut parties = self.high.sss.evaluations(&row);
+        let mut evaluations = Secret::new(Vec::with_capacity(parties.len() + 1));
+        evaluations.push(row.evaluate(&FieldElement::zero()));
+        evaluations.extend_from_slice(&parties);
+        parties.erase();
+        Ok(evaluations)
+    }
+
+    /// A column from at least t_l points on it, the shares of the column held by the parties' rows.
+    pub fn interpolate_column(
+        &self,
+        points: &[Share<C::ScalarField>],
+    ) -> Result<Secret<Polynomial<FieldElement<C::ScalarField>>>, SssError> {
+        self.low.reconstructing(points)
+    }
+
+    /// Commitments of party j's row, prod_l (g^{a_kl})^{j^l} for every k.
+    pub fn row_commitments(&self, commitments: &BivariateCommitments<C>, party: u64) -> Vec<Commitment<C>> {
+        let y = Scheme::Shamir.point::<C::ScalarField>(party);
+        let powers: Vec<FieldElement<C::ScalarField>> = (0..commitments.len()).map(|l| y.pow(l as u64)).collect();
+        (0..self.high.sss.threshold)
+            .map(|k| {
+                let column: Vec<Commitment<C>> = commitments.iter().map(|commitment| commitment[k].clone()).collect();
+                evaluate_in_exponent::<C>(&column, &powers)
+            })
+            .collect()
+    }
+
+    /// Checks party j's row, its evaluations at 0..=n, against the commitments.
+    pub fn verify_row(
+        &self,
+        party: u64,
+        row: &[FieldElement<C::ScalarField>],
+        commitments: &BivariateCommitments<C>,
+    ) -> bool {
+        if !self.check_commitments(commitments) || row.len() != self.high.sss.share_amount + 1 {
+            return false;
+        }
+        // The evaluation at 0 is checked like the parties' ones
+        let shares: Vec<Share<C::ScalarField>> = row
+            .iter()
+            .enumerate()
+            .map(|(x, value)| Share {
+                index: x as u64,
+                value: value.clone(),
+                threshold: self.high.sss.threshold,
+                scheme: Scheme::Shamir,
+            })
+            .collect();
+        self.high.sss.verify_degree(&row[1..]).unwrap_or(false)
+            && self.high.batch_verify_shares(&shares, &self.row_commitments(commitments, party))
+    }
+
+    /// Checks g^{value} = g^{phi(x, y)} for parties x and y.
+    pub fn verify_point(
+        &self,
+        x: u64,
+        y: u64,
+        value: &FieldElement<C::ScalarField>,
+        commitments: &BivariateCommitments<C>,
+    ) -> bool {
+        if !self.check_commitments(commitments) {
+            return false;
+        }
+        let share = Share {
+            index: x,
+            value: value.clone(),
+            threshold: self.high.sss.threshold,
+            scheme: Scheme::Shamir,
+        };
+        self.high.verify_share(&share, &self.row_commitments(commitments, y))
+    }
+
+    // t_l commitments of t_h each
+    fn check_commitments(&self, commitments: &BivariateCommitments<C>) -> bool {
+        commitments.len() == self.low.threshold
+            && commitments
+                .iter()
+                .all(|commitment| commitment.len() == self.high.sss.threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HighThresholdSharing, Scheme, ShamirSecretSharing, Share, SssError};
+    use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{FrElement, FrField};
+
+    // The point phi(x, y) as a share of x's column, or of y's row
+    fn point(index: u64, value: &FrElement, threshold: usize) -> Share<FrField> {
+        Share {
+            index,
+            value: value.clone(),
+            threshold,
+            scheme: Scheme::Shamir,
+        }
+    }
+
+    #[test]
+    fn columns_give_high_threshold_shares() {
+        // n = 7, t = 2, the secret needs 2t + 1 shares and the columns t + 1 points
+        let hacss: HighThresholdSharing = HighThresholdSharing::new(5, 3, 7).unwrap();
+        let secret = FrElement::from(1234u64);
+        let (compact_rows, commitments) = hacss.deal(secret.clone());
+        assert_eq!(compact_rows[0].len(), 5);
+
+        let rows: Vec<_> = compact_rows.iter().map(|row| hacss.complete_row(row).unwrap()).collect();
+        for (j, row) in rows.iter().enumerate() {
+            assert_eq!(row.len(), 8);
+            assert!(hacss.verify_row(j as u64 + 1, row, &commitments));
+        }
+        assert!(!hacss.verify_row(2, &rows[0], &commitments));
+
+        // Party i's column from the rows of parties 3, 5 and 6
+        let shares: Vec<_> = (1..=7u64)
+            .map(|i| {
+                let points: Vec<_> = [3u64, 5, 6]
+                    .iter()
+                    .map(|&j| {
+                        let value = &rows[j as usize - 1][i as usize];
+                        assert!(hacss.verify_point(i, j, value, &commitments));
+                        point(j, value, 3)
+                    })
+                    .collect();
+                let column = hacss.interpolate_column(&points).unwrap();
+                point(i, &column.evaluate(&FrElement::zero()), 5)
+            })
+            .collect();
+        let sss = ShamirSecretSharing::<FrField>::new(5, 7).unwrap();
+        assert_eq!(*sss.recover(&shares[2..]).unwrap(), secret);
+        assert_eq!(sss.recover(&shares[..4]), Err(SssError::NotEnoughShares(5, 4)));
+        // Four shares interpolate a different polynomial
+        let low = ShamirSecretSharing::<FrField>::new(4, 7).unwrap();
+        assert_eq!(low.recover(&shares[..4]), Err(SssError::Incompatible));
+        let relabeled: Vec<_> = shares[..4].iter().map(|share| point(share.index, &share.value, 4)).collect();
+        assert_ne!(*low.recover(&relabeled).unwrap(), secret);
+    }
+
+    #[test]
+    fn lost_rows_are_interpolated_from_columns() {
+        let hacss: HighThresholdSharing = HighThresholdSharing::new(3, 2, 4).unwrap();
+        let (compact_rows, commitments) = hacss.deal(FrElement::from(7u64));
+        let rows: Vec<_> = compact_rows.iter().map(|row| hacss.complete_row(row).unwrap()).collect();
+
+        // Parties 1, 2 and 4 send party 3 the points phi(j, 3) of their columns
+        let points: Vec<_> = [1u64, 2, 4].iter().map(|&j| point(j, &rows[2][j as usize], 3)).collect();
+        assert_eq!(hacss.interpolate_row(&points).unwrap(), rows[2]);
+
+        let tampered = &rows[2][1] + FrElement::one();
+        assert!(!hacss.verify_point(1, 3, &tampered, &commitments));
+        let mut row = rows[2].clone();
+        row[0] = tampered;
+        assert!(!hacss.verify_row(3, &row, &commitments));
+        assert_eq!(hacss.complete_row(&compact_rows[0][..2]), Err(SssError::WrongShareCount(3, 2)));
+    }
+}
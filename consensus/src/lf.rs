@@ -1,19 +1,20 @@
+use std::collections::HashSet;
 use std::fs::File;
 use std::io::Read;
 
 use crypto::LargeFieldSer;
-use num_bigint_dig::RandBigInt;
+use num_bigint_dig::Sign;
 /**
  * Cloned from https://github.com/bitrocks/verifiable-secret-sharing
  * Author: bitrocks: https://github.com/bitrocks
  */
 
 use num_traits::{One, Zero};
-use rand::{self};
+use rand_core::{CryptoRng, OsRng, RngCore};
 
 use crypto::LargeField;
 
-use super::ShamirSecretSharing;
+use super::{ShamirSecretSharing, SssError};
 
 /// The `ShamirSecretSharing` stores threshold, share_amount and the prime of finite field.
 #[derive(Clone, Debug)]
@@ -33,52 +34,62 @@ pub struct LargeFieldSSS {
 // 64-bit variant of shamir SS mainly because of efficiency
 impl LargeFieldSSS {
 
-    pub fn get_fft_sss(&self) -> ShamirSecretSharing{
+    pub fn get_fft_sss(&self) -> Result<ShamirSecretSharing, SssError>{
         ShamirSecretSharing::new(self.threshold, self.share_amount)
     }
-    pub fn new(threshold: usize, share_amount: usize, prime: LargeField)-> LargeFieldSSS{
+    pub fn new(threshold: usize, share_amount: usize, prime: LargeField)-> Result<LargeFieldSSS, SssError>{
+        if threshold == 0 || threshold > share_amount {
+            return Err(SssError::InvalidThreshold(threshold, share_amount));
+        }
 
         let lag_coeffs = Self::lagrange_coefficients(prime.clone(), threshold, share_amount);
-        LargeFieldSSS { 
+        Ok(LargeFieldSSS { 
             threshold: threshold, 
             share_amount: share_amount, 
             prime: prime, 
             lag_coeffs: lag_coeffs ,
             vandermonde_matrix: Vec::new()
-        }
+        })
     }
 
-    pub fn new_with_vandermonde(threshold: usize, share_amount: usize, vandermonde_matrix_file: String,prime: LargeField)-> LargeFieldSSS{
+    pub fn new_with_vandermonde(threshold: usize, share_amount: usize, vandermonde_matrix_file: String,prime: LargeField)-> Result<LargeFieldSSS, SssError>{
+        if threshold == 0 || threshold > share_amount {
+            return Err(SssError::InvalidThreshold(threshold, share_amount));
+        }
         let lag_coeffs = Self::lagrange_coefficients(prime.clone(), threshold, share_amount);
         
-        let mut file = File::open(vandermonde_matrix_file).expect("Failed to open file.");
+        let mut file = File::open(vandermonde_matrix_file).map_err(|e| SssError::MatrixFile(e.to_string()))?;
         let mut contents = String::new();
-        file.read_to_string(&mut contents).expect("Failed to read file.");
+        file.read_to_string(&mut contents).map_err(|e| SssError::MatrixFile(e.to_string()))?;
         
-        let loaded_matrix: Vec<Vec<LargeFieldSer>> = serde_json::from_str(&contents).expect("Failed to deserialize matrix.");
+        let loaded_matrix: Vec<Vec<LargeFieldSer>> =
+            serde_json::from_str(&contents).map_err(|e| SssError::MatrixFile(e.to_string()))?;
         
         let load_matrix_bigint: Vec<Vec<LargeField>> = loaded_matrix.into_iter().map(|el| {
             el.into_iter().map(|el| LargeField::from_signed_bytes_be(el.as_slice())).collect()
         }).collect();
 
-        LargeFieldSSS { 
+        Ok(LargeFieldSSS { 
             threshold: threshold, 
             share_amount: share_amount, 
             prime: prime, 
             lag_coeffs: lag_coeffs,
             vandermonde_matrix: load_matrix_bigint
-        }
+        })
     }
     
     /// Split a secret according to the config.
     pub fn split(&self, secret: LargeField) -> Vec<(usize, LargeField)> {
-        assert!(self.threshold < self.share_amount);
         let polynomial = self.sample_polynomial(secret);
         // println!("polynomial: {:?}", polynomial);
         self.evaluate_polynomial(polynomial)
     }
 
-    pub fn fill_evaluation_at_all_points(&self, values: &mut Vec<LargeField>){
+    /// Extends the evaluations at 0..t to the evaluations at 0..=n.
+    pub fn fill_evaluation_at_all_points(&self, values: &mut Vec<LargeField>) -> Result<(), SssError>{
+        if values.len() != self.threshold {
+            return Err(SssError::WrongShareCount(self.threshold, values.len()));
+        }
         let mut all_values = Vec::new();
         for coefficients in self.lag_coeffs.iter(){
             let mut sum: LargeField = Zero::zero();
@@ -91,29 +102,43 @@ impl LargeFieldSSS {
             all_values.push(sum);
         }
         values.extend(all_values);
+        Ok(())
     }
 
-    pub fn verify_degree(&self, values: &mut Vec<LargeField>) -> bool{
+    /// Checks that the evaluations at 0..=n lie on a polynomial of degree < t.
+    pub fn verify_degree(&self, values: &mut Vec<LargeField>) -> Result<bool, SssError>{
+        if values.len() != self.share_amount + 1 {
+            return Err(SssError::WrongShareCount(self.share_amount + 1, values.len()));
+        }
         let mut shares_interp = Vec::new();
         
         for rep in self.share_amount - self.threshold .. self.share_amount{
             shares_interp.push((rep+1,values[rep+1].clone()));
         }
         
-        let secret = self.recover(&shares_interp);
+        let secret = self.recover(&shares_interp)?;
         //println!("Degree verification : {:?} {:?}",secret,values[0].clone());
-        secret == values[0].clone()%&self.prime
+        Ok(secret == values[0].clone()%&self.prime)
     }
 
     fn sample_polynomial(&self, secret: LargeField) -> Vec<LargeField> {
+        self.sample_polynomial_with_rng(secret, &mut OsRng)
+    }
+
+    /// Samples the random coefficients uniformly from [0, prime) by rejection sampling.
+    pub fn sample_polynomial_with_rng<R: CryptoRng + RngCore>(&self, secret: LargeField, rng: &mut R) -> Vec<LargeField> {
         let mut coefficients: Vec<LargeField> = vec![secret];
-        let mut rng = rand::thread_rng();
-        let low = LargeField::from(0u32);
-        let high = &self.prime - LargeField::from(1u32);
-        let random_coefficients: Vec<LargeField> = (0..(self.threshold - 1))
-            .map(|_| rng.gen_bigint_range(&low, &high))
-            .collect();
-        coefficients.extend(random_coefficients);
+        let bits = self.prime.bits();
+        let mut bytes = vec![0u8; bits.div_ceil(8)];
+        while coefficients.len() < self.threshold {
+            rng.fill_bytes(&mut bytes);
+            // Drop the bits above the bit length of the prime
+            bytes[0] &= 0xff >> (bytes.len() * 8 - bits);
+            let candidate = LargeField::from_bytes_be(Sign::Plus, &bytes);
+            if candidate < self.prime {
+                coefficients.push(candidate);
+            }
+        }
         coefficients
     }
 
@@ -148,14 +173,25 @@ impl LargeFieldSSS {
     }
 
     /// Recover the secret by the shares.
-    pub fn recover(&self, shares: &[(usize, LargeField)]) -> LargeField {
-        assert!(shares.len() == self.threshold, "wrong shares number");
+    pub fn recover(&self, shares: &[(usize, LargeField)]) -> Result<LargeField, SssError> {
+        if shares.len() != self.threshold {
+            return Err(SssError::WrongShareCount(self.threshold, shares.len()));
+        }
+        let mut indices = HashSet::new();
+        for (x, _) in shares {
+            if *x == 0 {
+                return Err(SssError::ZeroIndex);
+            }
+            if !indices.insert(*x) {
+                return Err(SssError::DuplicateIndex(*x as u64));
+            }
+        }
         let (xs, ys): (Vec<usize>, Vec<LargeField>) = shares.iter().cloned().unzip();
         let result = self.lagrange_interpolation(Zero::zero(), xs, ys);
         if result < Zero::zero() {
-            result + &self.prime
+            Ok(result + &self.prime)
         } else {
-            result
+            Ok(result)
         }
     }
 
@@ -335,24 +371,26 @@ impl LargeFieldSSS {
             .collect()
     }
 
-    pub fn polynomial_coefficients_with_precomputed_vandermonde_matrix(&self, y_values: &Vec<LargeField>) -> Vec<LargeField> {
+    pub fn polynomial_coefficients_with_precomputed_vandermonde_matrix(&self, y_values: &Vec<LargeField>) -> Result<Vec<LargeField>, SssError> {
         // Multiply Vandermonde inverse by the y-values vector to solve for coefficients
-        Self::matrix_vector_multiply(&self.vandermonde_matrix, y_values, &self.prime)
+        self.polynomial_coefficients_with_vandermonde_matrix(&self.vandermonde_matrix, y_values)
     }
 
-    pub fn polynomial_coefficients_with_vandermonde_matrix(&self, matrix: &Vec<Vec<LargeField>>, y_values: &Vec<LargeField>) -> Vec<LargeField>{
-        Self::matrix_vector_multiply(matrix, y_values, &self.prime)
+    pub fn polynomial_coefficients_with_vandermonde_matrix(&self, matrix: &Vec<Vec<LargeField>>, y_values: &Vec<LargeField>) -> Result<Vec<LargeField>, SssError>{
+        if matrix.len() != y_values.len() || matrix.iter().any(|row| row.len() != y_values.len()) {
+            return Err(SssError::WrongShareCount(matrix.len(), y_values.len()));
+        }
+        Ok(Self::matrix_vector_multiply(matrix, y_values, &self.prime))
     }
 
     /// Solves for polynomial coefficients in a prime field using Gaussian elimination.
     /// Points are provided as a vector of (i, x_i).
-    pub fn polynomial_coefficients(&self, points: &Vec<(LargeField, LargeField)>) -> Vec<LargeField> {
+    pub fn polynomial_coefficients(&self, points: &Vec<(LargeField, LargeField)>) -> Result<Vec<LargeField>, SssError> {
         let prime = &self.prime;
         let n = points.len(); // n = t + 1, degree of polynomial is t
-        assert!(
-            n > 0,
-            "Need at least one point to determine the coefficients."
-        );
+        if n == 0 {
+            return Err(SssError::NotEnoughShares(1, 0));
+        }
 
         let mut matrix = vec![vec![LargeField::zero(); n + 1]; n];
 
@@ -376,6 +414,10 @@ impl LargeFieldSSS {
                 }
             }
             matrix.swap(col, pivot);
+            // Only repeated points leave a zero column
+            if matrix[col][col].is_zero() {
+                return Err(SssError::SingularMatrix);
+            }
 
             // Normalize pivot row
             let inv = Self::mod_inv(&matrix[col][col], prime);
@@ -409,7 +451,7 @@ impl LargeFieldSSS {
             }
         }
 
-        coefficients
+        Ok(coefficients)
     }
 
     /// Constructs the Vandermonde matrix for a given set of x-values.
@@ -429,7 +471,7 @@ impl LargeFieldSSS {
     }
 
     /// Computes the inverse of a Vandermonde matrix modulo prime using Gaussian elimination.
-    pub fn inverse_vandermonde(&self, matrix: Vec<Vec<LargeField>>) -> Vec<Vec<LargeField>> {
+    pub fn inverse_vandermonde(&self, matrix: Vec<Vec<LargeField>>) -> Result<Vec<Vec<LargeField>>, SssError> {
         let n = matrix.len();
         let mut augmented = matrix.clone();
 
@@ -440,6 +482,12 @@ impl LargeFieldSSS {
 
         // Perform Gaussian elimination
         for col in 0..n {
+            // Swap in a row with a nonzero pivot, there is none if the points repeat
+            let pivot = (col..n)
+                .find(|&row| !(&augmented[row][col] % &self.prime).is_zero())
+                .ok_or(SssError::SingularMatrix)?;
+            augmented.swap(col, pivot);
+
             // Normalize pivot row
             let inv = Self::mod_inv(&augmented[col][col], &self.prime);
             for k in col..2 * n {
@@ -462,9 +510,9 @@ impl LargeFieldSSS {
         }
 
         // Extract the right half as the inverse
-        augmented
+        Ok(augmented
             .into_iter()
             .map(|row| row[n..2 * n].to_vec())
-            .collect()
+            .collect())
     }
 }
\ No newline at end of file
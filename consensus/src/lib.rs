@@ -1,14 +1,76 @@
 mod reed_solomon;
 pub use reed_solomon::*;
 
+mod error;
+pub use error::*;
+
+mod secret;
+pub use secret::*;
+
+mod share;
+pub use share::*;
+
+mod arithmetic;
+pub use arithmetic::*;
+
+mod byte_share;
+pub use byte_share::*;
+
+mod multipoint;
+pub use multipoint::*;
+
 mod sss;
 pub use sss::*;
 
+mod fft_plan;
+pub use fft_plan::*;
+
+mod erasure;
+pub use erasure::*;
+
 mod sss_fft;
 pub use sss_fft::*;
 
+mod batch;
+pub use batch::*;
+
+mod gf256;
+pub use gf256::*;
+
+mod packed;
+pub use packed::*;
+
+mod reconstructor;
+pub use reconstructor::*;
+
+mod degree;
+pub use degree::*;
+
+mod robust;
+pub use robust::*;
+
+mod feldman;
+pub use feldman::*;
+
+mod pedersen;
+pub use pedersen::*;
+
+mod refresh;
+pub use refresh::*;
+
+mod reshare;
+pub use reshare::*;
+
+mod recovery;
+
+mod hacss;
+pub use hacss::*;
+
 mod lf;
 pub use lf::*;
 
 mod reed_solomon_fft;
-pub use reed_solomon_fft::*;
\ No newline at end of file
+pub use reed_solomon_fft::*;
+
+mod scheme;
+pub use scheme::*;
\ No newline at end of file
@@ -0,0 +1,285 @@
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+/// From this many points on, `evaluate_at_points` and `interpolate_points` switch from the quadratic
+/// algorithms to the subproduct tree.
+pub const FAST_POLY_THRESHOLD: usize = 256;
+
+// Below this many coefficients schoolbook multiplication and long division beat the FFT
+const SCHOOLBOOK_LENGTH: usize = 64;
+
+/**
+ * Subproduct tree over arbitrary points x_1, ..., x_n
+ * Level 0 holds the leaves X - x_i, every node above is the product of its two children (an odd node out
+ * is carried up unchanged), and the root is M(X) = prod (X - x_i).
+ * Multipoint evaluation reduces f modulo the nodes on the way down, f mod (X - x_i) = f(x_i).
+ * Interpolation goes the other way: with w_i = y_i / M'(x_i), the interpolant of a node is
+ * left * M_right + right * M_left, built up from the constants w_i.
+ * With FFT multiplication and Newton division both run in O(n log^2 n).
+ */
+#[derive(Clone, Debug)]
+pub struct SubproductTree<F: IsFFTField> {
+    points: Vec<FieldElement<F>>,
+    levels: Vec<Vec<Polynomial<FieldElement<F>>>>,
+}
+
+impl<F: IsFFTField> SubproductTree<F> {
+    pub fn new(points: &[FieldElement<F>]) -> Self {
+        assert!(!points.is_empty(), "the tree needs at least one point");
+        let mut levels = vec![points
+            .iter()
+            .map(|x| Polynomial::new(&[-x, FieldElement::one()]))
+            .collect::<Vec<_>>()];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => multiply(left, right),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        SubproductTree {
+            points: points.to_vec(),
+            levels,
+        }
+    }
+
+    pub fn points(&self) -> &[FieldElement<F>] {
+        &self.points
+    }
+
+    /// prod (X - x_i)
+    pub fn root(&self) -> &Polynomial<FieldElement<F>> {
+        &self.levels.last().unwrap()[0]
+    }
+
+    /// f(x_1), ..., f(x_n)
+    pub fn evaluate(&self, polynomial: &Polynomial<FieldElement<F>>) -> Vec<FieldElement<F>> {
+        let mut evaluations = Vec::with_capacity(self.points.len());
+        let top = self.levels.len() - 1;
+        let remainder = remainder(polynomial, self.root());
+        self.evaluate_node(top, 0, &remainder, &mut evaluations);
+        evaluations
+    }
+
+    fn evaluate_node(
+        &self,
+        level: usize,
+        index: usize,
+        polynomial: &Polynomial<FieldElement<F>>,
+        evaluations: &mut Vec<FieldElement<F>>,
+    ) {
+        if level == 0 {
+            evaluations.push(polynomial.evaluate(&self.points[index]));
+            return;
+        }
+        for child in [2 * index, 2 * index + 1] {
+            if let Some(node) = self.levels[level - 1].get(child) {
+                let remainder = remainder(polynomial, node);
+                self.evaluate_node(level - 1, child, &remainder, evaluations);
+            }
+        }
+    }
+
+    /// The polynomial of degree < n through (x_i, values[i]). None if two points coincide.
+    pub fn interpolate(&self, values: &[FieldElement<F>]) -> Option<Polynomial<FieldElement<F>>> {
+        if values.len() != self.points.len() {
+            return None;
+        }
+        let mut denominators = self.evaluate(&derivative(self.root()));
+        // M'(x_i) = prod_{j != i} (x_i - x_j) vanishes iff x_i is repeated
+        FieldElement::inplace_batch_inverse(&mut denominators).ok()?;
+
+        let mut combined: Vec<Polynomial<FieldElement<F>>> = values
+            .iter()
+            .zip(denominators.iter())
+            .map(|(value, denominator)| Polynomial::new(&[value * denominator]))
+            .collect();
+        for level in &self.levels[..self.levels.len() - 1] {
+            combined = combined
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|(polynomials, nodes)| match (polynomials, nodes) {
+                    ([left, right], [left_node, right_node]) => {
+                        multiply(left, right_node) + multiply(right, left_node)
+                    }
+                    ([single], [_]) => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        combined.pop()
+    }
+}
+
+/// f(x) for every x in `points`, with the subproduct tree once there are `FAST_POLY_THRESHOLD` points.
+pub fn evaluate_at_points<F: IsFFTField>(
+    polynomial: &Polynomial<FieldElement<F>>,
+    points: &[FieldElement<F>],
+) -> Vec<FieldElement<F>> {
+    if points.len() < FAST_POLY_THRESHOLD {
+        points.iter().map(|x| polynomial.evaluate(x)).collect()
+    } else {
+        SubproductTree::new(points).evaluate(polynomial)
+    }
+}
+
+/// The polynomial through (x[i], y[i]), with the subproduct tree once there are `FAST_POLY_THRESHOLD` points.
+/// None if the lengths differ or two points coincide.
+pub fn interpolate_points<F: IsFFTField>(
+    x: &[FieldElement<F>],
+    y: &[FieldElement<F>],
+) -> Option<Polynomial<FieldElement<F>>> {
+    if x.len() < FAST_POLY_THRESHOLD {
+        Polynomial::interpolate(x, y).ok()
+    } else {
+        SubproductTree::new(x).interpolate(y)
+    }
+}
+
+/// Product of two polynomials, through the FFT for long operands.
+pub fn multiply<F: IsFFTField>(
+    a: &Polynomial<FieldElement<F>>,
+    b: &Polynomial<FieldElement<F>>,
+) -> Polynomial<FieldElement<F>> {
+    if a.coeff_len() == 0 || b.coeff_len() == 0 {
+        return Polynomial::zero();
+    }
+    if a.coeff_len().min(b.coeff_len()) < SCHOOLBOOK_LENGTH {
+        return a * b;
+    }
+    let length = (a.coeff_len() + b.coeff_len() - 1).next_power_of_two();
+    let a_evaluations = Polynomial::evaluate_fft::<F>(a, 1, Some(length)).unwrap();
+    let b_evaluations = Polynomial::evaluate_fft::<F>(b, 1, Some(length)).unwrap();
+    let product: Vec<FieldElement<F>> = a_evaluations
+        .iter()
+        .zip(b_evaluations.iter())
+        .map(|(a, b)| a * b)
+        .collect();
+    Polynomial::interpolate_fft::<F>(&product).unwrap()
+}
+
+/// a mod b for a monic b. Long divisions use Newton iteration on the reversed polynomials,
+/// rev(q) = rev(a) / rev(b) mod X^(deg a - deg b + 1).
+fn remainder<F: IsFFTField>(
+    a: &Polynomial<FieldElement<F>>,
+    b: &Polynomial<FieldElement<F>>,
+) -> Polynomial<FieldElement<F>> {
+    if a.coeff_len() < b.coeff_len() {
+        return a.clone();
+    }
+    if b.coeff_len() < SCHOOLBOOK_LENGTH || a.coeff_len() - b.coeff_len() < SCHOOLBOOK_LENGTH {
+        return a.clone().long_division_with_remainder(b).1;
+    }
+
+    let quotient_length = a.coeff_len() - b.coeff_len() + 1;
+    let b_inverse = inverse_mod_power(&reverse(b), quotient_length);
+    let reversed_quotient = truncate(&multiply(&reverse(a), &b_inverse), quotient_length);
+    let mut quotient_coefficients = reversed_quotient.coefficients().to_vec();
+    quotient_coefficients.resize(quotient_length, FieldElement::zero());
+    quotient_coefficients.reverse();
+
+    let remainder = a - &multiply(b, &Polynomial::new(&quotient_coefficients));
+    truncate(&remainder, b.coeff_len() - 1)
+}
+
+// g with f * g = 1 mod X^length, needs f(0) != 0
+fn inverse_mod_power<F: IsFFTField>(
+    f: &Polynomial<FieldElement<F>>,
+    length: usize,
+) -> Polynomial<FieldElement<F>> {
+    let mut inverse = Polynomial::new(&[f.coefficients()[0].inv().unwrap()]);
+    let mut precision = 1;
+    while precision < length {
+        precision = (2 * precision).min(length);
+        // g <- g * (2 - f * g)
+        let error = truncate(&multiply(&truncate(f, precision), &inverse), precision);
+        let correction = Polynomial::new(&[FieldElement::<F>::from(2u64)]) - error;
+        inverse = truncate(&multiply(&inverse, &correction), precision);
+    }
+    inverse
+}
+
+// X^deg f * f(1 / X)
+fn reverse<F: IsFFTField>(f: &Polynomial<FieldElement<F>>) -> Polynomial<FieldElement<F>> {
+    let mut coefficients = f.coefficients().to_vec();
+    coefficients.reverse();
+    Polynomial::new(&coefficients)
+}
+
+// f mod X^length
+fn truncate<F: IsFFTField>(f: &Polynomial<FieldElement<F>>, length: usize) -> Polynomial<FieldElement<F>> {
+    let coefficients = f.coefficients();
+    Polynomial::new(&coefficients[..length.min(coefficients.len())])
+}
+
+fn derivative<F: IsFFTField>(f: &Polynomial<FieldElement<F>>) -> Polynomial<FieldElement<F>> {
+    let coefficients: Vec<FieldElement<F>> = f
+        .coefficients()
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, coefficient)| coefficient * FieldElement::<F>::from(i as u64))
+        .collect();
+    Polynomial::new(&coefficients)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{evaluate_at_points, interpolate_points, multiply, ShamirSecretSharing, SubproductTree};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+    use lambdaworks_math::polynomial::Polynomial;
+
+    type FE = FieldElement<Babybear31PrimeField>;
+
+    fn random_polynomial(length: usize) -> Polynomial<FE> {
+        let coefficients: Vec<FE> = (0..length)
+            .map(|_| ShamirSecretSharing::<Babybear31PrimeField>::rand_field_element())
+            .collect();
+        Polynomial::new(&coefficients)
+    }
+
+    #[test]
+    fn fft_multiplication_matches_schoolbook() {
+        let a = random_polynomial(200);
+        let b = random_polynomial(130);
+        assert_eq!(multiply(&a, &b), &a * &b);
+    }
+
+    #[test]
+    fn tree_evaluates_and_interpolates_at_arbitrary_points() {
+        // Squares are spread out and far from any multiplicative subgroup
+        let points: Vec<FE> = (1..=300u64).map(|i| FE::from(i * i + 7)).collect();
+        let polynomial = random_polynomial(300);
+        let tree = SubproductTree::new(&points);
+
+        let expected: Vec<FE> = points.iter().map(|x| polynomial.evaluate(x)).collect();
+        assert_eq!(tree.evaluate(&polynomial), expected);
+        assert_eq!(tree.interpolate(&expected), Some(polynomial.clone()));
+
+        // Above the degree, evaluation reduces modulo the root first
+        let high = random_polynomial(700);
+        let expected: Vec<FE> = points.iter().map(|x| high.evaluate(x)).collect();
+        assert_eq!(evaluate_at_points(&high, &points), expected);
+
+        let mut repeated = points.clone();
+        repeated[10] = repeated[20].clone();
+        assert_eq!(interpolate_points(&repeated, &expected), None);
+    }
+
+    #[test]
+    fn shamir_switches_to_the_tree_for_many_shares() {
+        let sss = ShamirSecretSharing::<Babybear31PrimeField>::new(400, 1000).unwrap();
+        let secret = FE::from(4242u64);
+        let shares = sss.split(secret.clone()).unwrap();
+        assert_eq!(sss.recover(&shares[300..700]).unwrap(), secret);
+        assert_eq!(sss.reconstructing(&shares[..400]).unwrap().degree(), 399);
+    }
+}
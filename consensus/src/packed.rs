@@ -0,0 +1,193 @@
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
+use lambdaworks_math::polynomial::Polynomial;
+use rand_core::{CryptoRng, OsRng, RngCore};
+
+use crate::{check_shares, Erase, Scheme, Secret, ShamirSecretSharing, Share, SssError};
+
+/**
+ * Packed (Franklin-Yung) Secret Sharing
+ * k secrets sit on one polynomial of degree t + k - 1, so n shares carry k secrets and any t shares
+ * reveal nothing about them.
+ * The secret slots are the first k points of the coset g * <w_L> with L = t + k, and the remaining
+ * t points of the coset hold randomness. g is a root of unity of order 2N, so the coset never meets
+ * the share points w_N^(i-1) (N = n.next_power_of_two()).
+ * Packing is an inverse FFT over the coset followed by an FFT over the share domain, unpacking is
+ * the interpolation of the shares followed by an FFT over the coset.
+ */
+#[derive(Clone, Debug)]
+pub struct PackedSecretSharing<F: IsFFTField = Stark252PrimeField> {
+    /// the threshold of shares to recover the secrets, t + k. Must be a power of two.
+    pub threshold: usize,
+    /// the number of secrets packed into one polynomial.
+    pub secret_amount: usize,
+    /// the total number of shares to generate from the secrets.
+    pub share_amount: usize,
+    /// offset of the coset holding the secret slots
+    pub offset: FieldElement<F>,
+}
+
+impl<F: IsFFTField + IsPrimeField> PackedSecretSharing<F> {
+    pub fn new(threshold: usize, secret_amount: usize, share_amount: usize) -> Result<Self, SssError> {
+        // t + k points of the coset are interpolated with an FFT
+        if !threshold.is_power_of_two() {
+            return Err(SssError::UnsupportedFftSize(threshold));
+        }
+        if !(1..=threshold).contains(&secret_amount) || threshold > share_amount {
+            return Err(SssError::InvalidThreshold(threshold, share_amount));
+        }
+
+        let domain_size = 2 * share_amount.next_power_of_two();
+        let offset = F::get_primitive_root_of_unity(domain_size.trailing_zeros() as u64)
+            .map_err(|_| SssError::UnsupportedFftSize(domain_size))?;
+        Ok(PackedSecretSharing {
+            threshold,
+            secret_amount,
+            share_amount,
+            offset,
+        })
+    }
+
+    fn scheme(&self) -> Scheme {
+        Scheme::Packed {
+            share_amount: self.share_amount,
+            secret_amount: self.secret_amount,
+        }
+    }
+
+    /// The polynomial of degree `threshold - 1` that takes the secrets at the first slots and random values at the rest.
+    pub fn sample_polynomial(&self, secrets: &[FieldElement<F>]) -> Result<Secret<Polynomial<FieldElement<F>>>, SssError> {
+        self.sample_polynomial_with_rng(secrets, &mut OsRng)
+    }
+
+    /// Same as `sample_polynomial`, with the random slots drawn from `rng`.
+    pub fn sample_polynomial_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        secrets: &[FieldElement<F>],
+        rng: &mut R,
+    ) -> Result<Secret<Polynomial<FieldElement<F>>>, SssError> {
+        if secrets.len() != self.secret_amount {
+            return Err(SssError::WrongShareCount(self.secret_amount, secrets.len()));
+        }
+        let mut slots = Vec::with_capacity(self.threshold);
+        slots.extend_from_slice(secrets);
+        for _ in self.secret_amount..self.threshold {
+            slots.push(ShamirSecretSharing::<F>::rand_field_element_with_rng(rng));
+        }
+        let polynomial = Polynomial::interpolate_offset_fft::<F>(&slots, &self.offset).map(Secret::new);
+        slots.erase();
+        polynomial.map_err(|_| SssError::UnsupportedFftSize(self.threshold))
+    }
+
+    pub fn generating_shares(&self, polynomial: &Polynomial<FieldElement<F>>) -> Result<Vec<Share<F>>, SssError> {
+        Ok(Polynomial::evaluate_fft::<F>(polynomial, 1, Some(self.share_amount))
+            .map_err(|_| SssError::UnsupportedFftSize(self.share_amount.next_power_of_two()))?
+            .into_iter()
+            .take(self.share_amount)
+            .enumerate()
+            .map(|(i, value)| Share {
+                index: i as u64 + 1,
+                value,
+                threshold: self.threshold,
+                scheme: self.scheme(),
+            })
+            .collect())
+    }
+
+    pub fn split(&self, secrets: &[FieldElement<F>]) -> Result<Vec<Share<F>>, SssError> {
+        let polynomial = self.sample_polynomial(secrets)?;
+        self.generating_shares(&polynomial)
+    }
+
+    /// Interpolates the packed polynomial from the shares' own evaluation points.
+    pub fn reconstructing(&self, shares: &[Share<F>]) -> Result<Secret<Polynomial<FieldElement<F>>>, SssError> {
+        check_shares(shares, self.scheme(), self.threshold)?;
+        let x: Vec<FieldElement<F>> = shares.iter().map(|share| share.x()).collect();
+        let mut y: Vec<FieldElement<F>> = shares.iter().map(|share| share.value.clone()).collect();
+        let polynomial = Polynomial::interpolate(&x, &y).map(Secret::new);
+        y.erase();
+        polynomial.map_err(|_| SssError::SingularMatrix)
+    }
+
+    /// Reads the secrets off the slots of a packed polynomial.
+    pub fn unpack(&self, polynomial: &Polynomial<FieldElement<F>>) -> Result<Secret<Vec<FieldElement<F>>>, SssError> {
+        let mut slots = Secret::new(
+            Polynomial::evaluate_offset_fft::<F>(polynomial, 1, Some(self.threshold), &self.offset)
+                .map_err(|_| SssError::UnsupportedFftSize(self.threshold))?,
+        );
+        // The random slots are erased before they are dropped
+        slots[self.secret_amount..].erase();
+        slots.truncate(self.secret_amount);
+        Ok(slots)
+    }
+
+    pub fn recover(&self, shares: &[Share<F>]) -> Result<Secret<Vec<FieldElement<F>>>, SssError> {
+        let polynomial = self.reconstructing(shares)?;
+        self.unpack(&polynomial)
+    }
+
+    /// Adds two packings share by share. The result is a packing of the slot-wise sums of the secrets.
+    pub fn add_shares(&self, a: &[Share<F>], b: &[Share<F>]) -> Result<Vec<Share<F>>, SssError> {
+        if a.len() != b.len() {
+            return Err(SssError::Incompatible);
+        }
+        a.iter()
+            .zip(b)
+            .map(|(a, b)| {
+                if a.scheme != self.scheme() {
+                    return Err(SssError::Incompatible);
+                }
+                a.add_share(b)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PackedSecretSharing, SssError};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+    use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+
+    type LargeField = FieldElement<Stark252PrimeField>;
+
+    #[test]
+    fn pack_16_secrets_into_64_shares() {
+        // t = 16, k = 16, n = 64
+        let pss = PackedSecretSharing::<Stark252PrimeField>::new(32, 16, 64).unwrap();
+        let secrets: Vec<LargeField> = (0..16u64).map(|i| LargeField::from(1000 + i)).collect();
+
+        let polynomial = pss.sample_polynomial(&secrets).unwrap();
+        assert_eq!(polynomial.degree(), 31);
+        let shares = pss.generating_shares(&polynomial).unwrap();
+        assert_eq!(shares.len(), 64);
+
+        // Any 32 shares unpack all 16 secrets
+        let shares_to_use: Vec<_> = shares.iter().skip(1).step_by(2).cloned().collect();
+        assert_eq!(shares_to_use.len(), 32);
+        assert_eq!(pss.recover(&shares_to_use).unwrap(), secrets);
+        assert_eq!(pss.recover(&shares[20..52]).unwrap(), secrets);
+        assert_eq!(pss.recover(&shares[..31]), Err(SssError::NotEnoughShares(32, 31)));
+    }
+
+    #[test]
+    fn packed_shares_add_slot_wise() {
+        let pss = PackedSecretSharing::<Babybear31PrimeField>::new(8, 4, 12).unwrap();
+        let a: Vec<FieldElement<Babybear31PrimeField>> = (1..5u64).map(FieldElement::from).collect();
+        let b: Vec<FieldElement<Babybear31PrimeField>> = (10..14u64).map(FieldElement::from).collect();
+
+        let sum = pss.add_shares(&pss.split(&a).unwrap(), &pss.split(&b).unwrap()).unwrap();
+        let expected: Vec<_> = a.iter().zip(&b).map(|(a, b)| a + b).collect();
+        assert_eq!(pss.recover(&sum[4..]).unwrap(), expected);
+
+        let shares = pss.split(&a).unwrap();
+        assert_eq!(pss.add_shares(&shares[..4], &shares[1..5]), Err(SssError::Incompatible));
+        assert_eq!(pss.split(&a[..3]).unwrap_err(), SssError::WrongShareCount(4, 3));
+        assert_eq!(
+            PackedSecretSharing::<Babybear31PrimeField>::new(6, 4, 12).unwrap_err(),
+            SssError::UnsupportedFftSize(6)
+        );
+    }
+}
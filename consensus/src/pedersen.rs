@@ -0,0 +1,242 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crypto::hash::do_hash;
+use lambdaworks_math::cyclic_group::IsGroup;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::field_extension::BLS12381PrimeField;
+use lambdaworks_math::elliptic_curve::traits::IsEllipticCurve;
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsField;
+use lambdaworks_math::traits::ByteConversion;
+
+use crate::{
+    evaluate_in_exponent, Commitment, Erase, FeldmanCurve, Secret, ShamirSecretSharing, Share, SssError,
+};
+
+/// A curve with a second generator h whose discrete log with respect to g nobody knows.
+pub trait PedersenCurve: FeldmanCurve {
+    fn second_generator() -> Self::PointRepresentation;
+}
+
+const PEDERSEN_H_TAG: &[u8] = b"secret-sharing/pedersen-vss/h";
+
+// BLS12-381 G1 cofactor
+const BLS12_381_G1_COFACTOR: u128 = 0x396c8c005555e1568c00aaab0000aaab;
+
+impl PedersenCurve for BLS12381Curve {
+    /// Try-and-increment: x = SHA256(tag || counter) until x^3 + 4 is a square, then the cofactor is cleared
+    /// so h lands in the prime order subgroup. Everything is derived from a public string.
+    fn second_generator() -> Self::PointRepresentation {
+        let mut counter: u64 = 0;
+        loop {
+            let mut input = PEDERSEN_H_TAG.to_vec();
+            input.extend_from_slice(&counter.to_be_bytes());
+            counter += 1;
+
+            // 32 bytes are always below the 381 bit modulus
+            let mut x_bytes = vec![0u8; 16];
+            x_bytes.extend_from_slice(&do_hash(&input));
+            let x = FieldElement::<BLS12381PrimeField>::from_bytes_be(&x_bytes).unwrap();
+            let y_squared = x.pow(3u64) + FieldElement::from(4u64);
+            let y = match y_squared.sqrt() {
+                Some((y, _)) => y,
+                None => continue,
+            };
+            let point = match Self::create_point_from_affine(x, y) {
+                Ok(point) => point,
+                Err(_) => continue,
+            };
+            let h = point.operate_with_self(BLS12_381_G1_COFACTOR);
+            if !h.is_neutral_element() {
+                return h;
+            }
+        }
+    }
+}
+
+/// The Pedersen shares of parties 1..=n and the commitments g^{a_j} h^{b_j}.
+pub type PedersenDealing<C> = (Vec<PedersenShare<<C as FeldmanCurve>::ScalarField>>, Vec<Commitment<C>>);
+
+/// Party i's Pedersen share: f(i) as a regular share, and the blinding value r(i) at the same point.
+#[derive(Clone)]
+pub struct PedersenShare<F: IsField> {
+    pub share: Share<F>,
+    pub blinding: FieldElement<F>,
+}
+
+impl<F: IsField> Drop for PedersenShare<F> {
+    fn drop(&mut self) {
+        self.blinding.erase();
+    }
+}
+
+impl<F: IsField> fmt::Debug for PedersenShare<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PedersenShare")
+            .field("share", &self.share)
+            .field("blinding", &"<redacted>")
+            .finish()
+    }
+}
+
+impl<F: IsField> PartialEq for PedersenShare<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.share == other.share && self.blinding == other.blinding
+    }
+}
+
+/**
+ * Pedersen's Verifiable Secret Sharing
+ * The dealer samples the sharing polynomial f with f(0) = secret and an independent blinding polynomial r,
+ * and publishes C_j = g^{a_j} h^{b_j} for the coefficients a_j of f and b_j of r.
+ * Party i receives (f(i), r(i)) and accepts iff g^{f(i)} h^{r(i)} = prod_j C_j^{i^j}.
+ * Unlike Feldman, the commitments reveal nothing about the secret.
+ */
+#[derive(Clone)]
+pub struct PedersenVSS<C: PedersenCurve = BLS12381Curve> {
+    pub sss: ShamirSecretSharing<C::ScalarField>,
+    /// second generator, see `PedersenCurve::second_generator`
+    pub h: Commitment<C>,
+}
+
+impl<C: PedersenCurve> PedersenVSS<C> {
+    pub fn new(threshold: usize, share_amount: usize) -> Result<Self, SssError> {
+        Ok(PedersenVSS {
+            sss: ShamirSecretSharing::new(threshold, share_amount)?,
+            h: C::second_generator(),
+        })
+    }
+
+    /// Splits the secret and returns each party's (f(i), r(i)) along with the public commitments.
+    pub fn deal(
+        &self,
+        secret: FieldElement<C::ScalarField>,
+    ) -> Result<PedersenDealing<C>, SssError> {
+        let polynomial = self.sss.sample_polynomial(secret);
+        let blinding_polynomial = self
+            .sss
+            .sample_polynomial(ShamirSecretSharing::<C::ScalarField>::rand_field_element());
+
+        let generator = C::generator();
+        let mut commitments = Vec::with_capacity(self.sss.threshold);
+        for j in 0..self.sss.threshold {
+            let mut a = polynomial.coefficients().get(j).cloned().unwrap_or_else(FieldElement::zero);
+            let mut b = blinding_polynomial
+                .coefficients()
+                .get(j)
+                .cloned()
+                .unwrap_or_else(FieldElement::zero);
+            commitments.push(self.commit(&generator, &a, &b));
+            a.erase();
+            b.erase();
+        }
+
+        let shares = self
+            .sss
+            .generating_shares(&polynomial)?
+            .into_iter()
+            .zip(self.sss.evaluations(&blinding_polynomial))
+            .map(|(share, blinding)| PedersenShare { share, blinding })
+            .collect();
+        Ok((shares, commitments))
+    }
+
+    pub fn verify_share(&self, share: &PedersenShare<C::ScalarField>, commitments: &[Commitment<C>]) -> bool {
+        if commitments.len() != self.sss.threshold || share.share.threshold != self.sss.threshold {
+            return false;
+        }
+        let x = share.share.x();
+        let powers: Vec<FieldElement<C::ScalarField>> = (0..commitments.len())
+            .map(|j| x.pow(j as u64))
+            .collect();
+        let expected = evaluate_in_exponent::<C>(commitments, &powers);
+        self.commit(&C::generator(), &share.share.value, &share.blinding) == expected
+    }
+
+    /// Recovers the secret from the shares that pass verification, ignoring the rest.
+    pub fn recover(
+        &self,
+        shares: &[PedersenShare<C::ScalarField>],
+        commitments: &[Commitment<C>],
+    ) -> Result<Secret<FieldElement<C::ScalarField>>, SssError> {
+        let mut indices = HashSet::new();
+        let valid: Vec<Share<C::ScalarField>> = shares
+            .iter()
+            // A forged share must not shadow a valid one at the same index
+            .filter(|share| self.verify_share(share, commitments) && indices.insert(share.share.index))
+            .map(|share| share.share.clone())
+            .collect();
+        if valid.len() < self.sss.threshold {
+            return Err(SssError::NotEnoughShares(self.sss.threshold, valid.len()));
+        }
+        self.sss.recover(&valid[..self.sss.threshold])
+    }
+
+    // g^a h^b
+    fn commit(
+        &self,
+        generator: &Commitment<C>,
+        a: &FieldElement<C::ScalarField>,
+        b: &FieldElement<C::ScalarField>,
+    ) -> Commitment<C> {
+        generator
+            .operate_with_self(a.representative())
+            .operate_with(&self.h.operate_with_self(b.representative()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PedersenCurve, PedersenVSS, Secret, SssError};
+    use lambdaworks_math::cyclic_group::IsGroup;
+    use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+    use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrElement;
+    use lambdaworks_math::elliptic_curve::traits::IsEllipticCurve;
+
+    #[test]
+    fn second_generator_is_deterministic_and_in_the_subgroup() {
+        let h = BLS12381Curve::second_generator();
+        assert_eq!(h, BLS12381Curve::second_generator());
+        assert_ne!(h, BLS12381Curve::generator());
+        // r * h = 0 for the scalar field order r
+        let order_minus_one = (-FrElement::one()).representative();
+        assert!(h.operate_with_self(order_minus_one).operate_with(&h).is_neutral_element());
+    }
+
+    #[test]
+    fn pedersen_shares_verify_and_recover() {
+        let vss: PedersenVSS = PedersenVSS::new(3, 5).unwrap();
+        let secret = FrElement::from(1234u64);
+        let (mut shares, commitments) = vss.deal(secret.clone()).unwrap();
+
+        for share in &shares {
+            assert!(vss.verify_share(share, &commitments));
+        }
+        assert_eq!(vss.recover(&shares, &commitments), Ok(Secret::new(secret.clone())));
+
+        // Corrupted values or blindings are caught and skipped during reconstruction
+        shares[0].share.value += FrElement::one();
+        shares[1].blinding += FrElement::one();
+        assert!(!vss.verify_share(&shares[0], &commitments));
+        assert!(!vss.verify_share(&shares[1], &commitments));
+        assert_eq!(vss.recover(&shares, &commitments), Ok(Secret::new(secret)));
+        assert_eq!(
+            vss.recover(&shares[..4], &commitments),
+            Err(SssError::NotEnoughShares(3, 2))
+        );
+    }
+
+    #[test]
+    fn forged_share_does_not_shadow_the_valid_one() {
+        let vss: PedersenVSS = PedersenVSS::new(3, 5).unwrap();
+        let secret = FrElement::from(99u64);
+        let (shares, commitments) = vss.deal(secret.clone()).unwrap();
+
+        // A forged share for party 1 arrives before party 1's own
+        let mut forged = shares[0].clone();
+        forged.share.value += FrElement::one();
+        let received = vec![forged, shares[0].clone(), shares[1].clone(), shares[2].clone()];
+        assert_eq!(vss.recover(&received, &commitments), Ok(Secret::new(secret)));
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
+
+use crate::{Scheme, Secret, ShamirSecretSharing, ShamirSecretSharingFFT, Share, SssError};
+
+/**
+ * Reconstruction for a fixed set of parties
+ * The secret is P(0) = sum_i l_i(0) y_i, where l_i(0) = prod_{j != i} x_j / (x_j - x_i) only depends on
+ * the points of the parties. The weights are computed once in O(t^2) with a single inversion, after which
+ * every secret shared among the same parties is an O(t) inner product.
+ * Works on any share domain, the integer points of `ShamirSecretSharing` as well as the roots of unity
+ * of `ShamirSecretSharingFFT`.
+ */
+#[derive(Clone, Debug)]
+pub struct Reconstructor<F: IsFFTField = Stark252PrimeField> {
+    indices: Vec<u64>,
+    scheme: Scheme,
+    /// l_i(0) for the party at the same position in `indices`
+    weights: Vec<FieldElement<F>>,
+}
+
+impl<F: IsFFTField + IsPrimeField> Reconstructor<F> {
+    pub fn new(indices: &[u64], scheme: Scheme) -> Result<Self, SssError> {
+        if indices.is_empty() {
+            return Err(SssError::NotEnoughShares(1, 0));
+        }
+        let mut seen = HashSet::new();
+        for &index in indices {
+            scheme.check_index::<F>(index)?;
+            if !seen.insert(index) {
+                return Err(SssError::DuplicateIndex(index));
+            }
+        }
+
+        let x: Vec<FieldElement<F>> = indices.iter().map(|&index| scheme.point(index)).collect();
+        let m = x.len();
+
+        // prod_{j != i} x_j from prefix and suffix products
+        let mut numerators = vec![FieldElement::<F>::one(); m];
+        let mut prefix = FieldElement::<F>::one();
+        for i in 0..m {
+            numerators[i] = prefix.clone();
+            prefix = &prefix * &x[i];
+        }
+        let mut suffix = FieldElement::<F>::one();
+        for i in (0..m).rev() {
+            numerators[i] = &numerators[i] * &suffix;
+            suffix = &suffix * &x[i];
+        }
+
+        // prod_{j != i} (x_j - x_i), nonzero since `check_index` keeps the indices below the modulus and
+        // the points are distinct
+        let mut denominators: Vec<FieldElement<F>> = (0..m)
+            .map(|i| {
+                (0..m)
+                    .filter(|&j| j != i)
+                    .fold(FieldElement::one(), |acc, j| acc * (&x[j] - &x[i]))
+            })
+            .collect();
+        FieldElement::inplace_batch_inverse(&mut denominators).map_err(|_| SssError::SingularMatrix)?;
+
+        let weights = numerators
+            .iter()
+            .zip(denominators.iter())
+            .map(|(numerator, denominator)| numerator * denominator)
+            .collect();
+        Ok(Reconstructor {
+            indices: indices.to_vec(),
+            scheme,
+            weights,
+        })
+    }
+
+    /// The parties this reconstructor expects shares from, in order.
+    pub fn indices(&self) -> &[u64] {
+        &self.indices
+    }
+
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// The Lagrange weights l_i(0), in the order of `indices`.
+    pub fn weights(&self) -> &[FieldElement<F>] {
+        &self.weights
+    }
+
+    /// Recovers the secret from one share per party, given in the order of `indices`.
+    pub fn recover(&self, shares: &[Share<F>]) -> Result<Secret<FieldElement<F>>, SssError> {
+        if shares.len() != self.indices.len() {
+            return Err(SssError::WrongShareCount(self.indices.len(), shares.len()));
+        }
+        let mut secret = Secret::new(FieldElement::zero());
+        for ((share, index), weight) in shares.iter().zip(&self.indices).zip(&self.weights) {
+            if share.index != *index || share.scheme != self.scheme {
+                return Err(SssError::Incompatible);
+            }
+            *secret += weight * &share.value;
+        }
+        Ok(secret)
+    }
+
+    /// Recovers many secrets shared among the same parties, `shares[k]` holds the shares of the k-th secret.
+    pub fn recover_many(&self, shares: &[Vec<Share<F>>]) -> Result<Secret<Vec<FieldElement<F>>>, SssError> {
+        let mut secrets = Secret::new(Vec::with_capacity(shares.len()));
+        for sharing in shares {
+            secrets.push((*self.recover(sharing)?).clone());
+        }
+        Ok(secrets)
+    }
+}
+
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharing<F> {
+    /// Precomputes the reconstruction weights for the parties in `indices`.
+    pub fn reconstructor(&self, indices: &[u64]) -> Result<Reconstructor<F>, SssError> {
+        if indices.len() < self.threshold {
+            return Err(SssError::NotEnoughShares(self.threshold, indices.len()));
+        }
+        Reconstructor::new(indices, Scheme::Shamir)
+    }
+}
+
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharingFFT<F> {
+    /// Precomputes the reconstruction weights for the parties in `indices`.
+    pub fn reconstructor(&self, indices: &[u64]) -> Result<Reconstructor<F>, SssError> {
+        if indices.len() < self.threshold {
+            return Err(SssError::NotEnoughShares(self.threshold, indices.len()));
+        }
+        Reconstructor::new(
+            indices,
+            Scheme::ShamirFFT {
+                share_amount: self.share_amount,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LargeField, ShamirSecretSharing, ShamirSecretSharingFFT, SssError};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+
+    #[test]
+    fn reconstructor_matches_interpolation() {
+        let sss = ShamirSecretSharing::new(4, 10).unwrap();
+        let reconstructor = sss.reconstructor(&[2, 5, 7, 10]).unwrap();
+
+        let secrets: Vec<LargeField> = (0..20u64).map(|i| LargeField::from(1000 + i)).collect();
+        let sharings: Vec<_> = secrets
+            .iter()
+            .map(|secret| {
+                let shares = sss.split(*secret).unwrap();
+                vec![shares[1].clone(), shares[4].clone(), shares[6].clone(), shares[9].clone()]
+            })
+            .collect();
+
+        assert_eq!(reconstructor.recover(&sharings[0]).unwrap(), secrets[0]);
+        assert_eq!(sss.recover(&sharings[3]).unwrap(), *reconstructor.recover(&sharings[3]).unwrap());
+        assert_eq!(reconstructor.recover_many(&sharings).unwrap(), secrets);
+    }
+
+    #[test]
+    fn reconstructor_on_roots_of_unity() {
+        let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(3, 8).unwrap();
+        let reconstructor = sss.reconstructor(&[8, 1, 4]).unwrap();
+
+        let secret = FieldElement::<Babybear31PrimeField>::from(77u64);
+        let shares = sss.split(secret.clone()).unwrap();
+        let chosen = vec![shares[7].clone(), shares[0].clone(), shares[3].clone()];
+        assert_eq!(reconstructor.recover(&chosen).unwrap(), secret);
+
+        // Shares must come in the order of the reconstructor's indices
+        let reordered = vec![shares[0].clone(), shares[7].clone(), shares[3].clone()];
+        assert_eq!(reconstructor.recover(&reordered), Err(SssError::Incompatible));
+        assert_eq!(reconstructor.recover(&chosen[..2]), Err(SssError::WrongShareCount(3, 2)));
+    }
+
+    #[test]
+    fn invalid_party_sets_are_rejected() {
+        let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(3, 8).unwrap();
+        assert_eq!(sss.reconstructor(&[1, 2]).unwrap_err(), SssError::NotEnoughShares(3, 2));
+        assert_eq!(sss.reconstructor(&[1, 2, 2]).unwrap_err(), SssError::DuplicateIndex(2));
+        assert_eq!(sss.reconstructor(&[1, 2, 9]).unwrap_err(), SssError::InvalidIndex(9));
+        // Six parties leave points 7 and 8 of the domain unused
+        let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(3, 6).unwrap();
+        assert_eq!(sss.reconstructor(&[1, 2, 7]).unwrap_err(), SssError::InvalidIndex(7));
+
+        let sss = ShamirSecretSharing::<Babybear31PrimeField>::new(2, 4).unwrap();
+        assert_eq!(sss.reconstructor(&[0, 1]).unwrap_err(), SssError::ZeroIndex);
+        // p + 1 is the same point as 1
+        let p = 15 * (1 << 27) + 1;
+        assert!(sss.reconstructor(&[1, p - 1]).is_ok());
+        assert_eq!(sss.reconstructor(&[1, p]).unwrap_err(), SssError::InvalidIndex(p));
+        assert_eq!(sss.reconstructor(&[1, p + 1]).unwrap_err(), SssError::InvalidIndex(p + 1));
+        assert_eq!(sss.reconstructor(&[1, u64::MAX]).unwrap_err(), SssError::InvalidIndex(u64::MAX));
+    }
+}
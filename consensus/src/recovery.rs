@@ -0,0 +1,242 @@
+use std::collections::HashSet;
+
+use lambdaworks_math::cyclic_group::IsGroup;
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
+use lambdaworks_math::polynomial::Polynomial;
+use rand_core::{CryptoRng, OsRng, RngCore};
+
+use crate::{Commitment, Dealing, Erase, FeldmanCurve, FeldmanVSS, Scheme, Secret, ShamirSecretSharing, Share, SssError};
+
+/**
+ * Share recovery (enrollment)
+ * Party r lost its share P(r) and asks a set H of at least t helpers for it. Every helper j deals a blinding
+ * polynomial B_j of degree t - 1 with B_j(r) = 0 and sends B_j(k) to every helper k. Helper k answers r with
+ * P(k) + sum_j B_j(k), an evaluation of P + sum_j B_j, which r interpolates at x = r to get P(r) back.
+ * As long as one helper's B_j is random, the answers are uniformly distributed apart from their value at r,
+ * so r learns nothing about the other shares and the helpers learn nothing about P(r).
+ * All helpers must blind with the same set H, otherwise the answers don't lie on one polynomial.
+ * With Feldman commitments to P and to every B_j, the commitments of P + sum_j B_j are their coefficient-wise
+ * products, so r checks every answer on its own and interpolates from the first t that verify.
+ */
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharing<F> {
+    /// A random polynomial of degree `threshold - 1` that vanishes at party `lost`'s point, (x - r) R(x).
+    pub fn sample_blinding_polynomial(&self, lost: u64) -> Result<Secret<Polynomial<FieldElement<F>>>, SssError> {
+        self.sample_blinding_polynomial_with_rng(lost, &mut OsRng)
+    }
+
+    /// Same as `sample_blinding_polynomial`, with the random coefficients drawn from `rng`.
+    pub fn sample_blinding_polynomial_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        lost: u64,
+        rng: &mut R,
+    ) -> Result<Secret<Polynomial<FieldElement<F>>>, SssError> {
+        self.check_party(lost)?;
+        let mut coefficients: Vec<FieldElement<F>> = (0..self.threshold - 1)
+            .map(|_| Self::rand_field_element_with_rng(rng))
+            .collect();
+        let random = Polynomial::new(&coefficients);
+        coefficients.erase();
+        let root = Polynomial::new(&[-Scheme::Shamir.point::<F>(lost), FieldElement::one()]);
+        Ok(Secret::new(&random * &root))
+    }
+
+    /// Blinding sub-shares of a fresh blinding polynomial for the helpers, in the order of `helpers`.
+    pub fn deal_blinding(&self, lost: u64, helpers: &[u64]) -> Result<Vec<Share<F>>, SssError> {
+        self.deal_blinding_with_rng(lost, helpers, &mut OsRng)
+    }
+
+    /// Same as `deal_blinding`, with the random coefficients drawn from `rng`.
+    pub fn deal_blinding_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        lost: u64,
+        helpers: &[u64],
+        rng: &mut R,
+    ) -> Result<Vec<Share<F>>, SssError> {
+        self.check_helpers(lost, helpers)?;
+        let blinding = self.sample_blinding_polynomial_with_rng(lost, rng)?;
+        Ok(helpers
+            .iter()
+            .map(|&helper| Share {
+                index: helper,
+                value: blinding.evaluate(&Scheme::Shamir.point::<F>(helper)),
+                threshold: self.threshold,
+                scheme: Scheme::Shamir,
+            })
+            .collect())
+    }
+
+    /// A helper's answer, its share blinded with the sub-shares it received from every helper.
+    pub fn blind_share(&self, share: &Share<F>, blindings: &[Share<F>]) -> Result<Share<F>, SssError> {
+        blindings
+            .iter()
+            .try_fold(share.clone(), |blinded, blinding| blinded.add_share(blinding))
+    }
+
+    /// The lost share of party `lost`, interpolated from the answers of all the helpers.
+    pub fn recover_share(&self, lost: u64, answers: &[Share<F>]) -> Result<Share<F>, SssError> {
+        let helpers: Vec<u64> = answers.iter().map(|answer| answer.index).collect();
+        self.check_helpers(lost, &helpers)?;
+        if answers.iter().any(|answer| answer.scheme != Scheme::Shamir) {
+            return Err(SssError::Incompatible);
+        }
+        let polynomial = self.reconstructing(answers)?;
+        Ok(Share {
+            index: lost,
+            value: polynomial.evaluate(&Scheme::Shamir.point::<F>(lost)),
+            threshold: self.threshold,
+            scheme: Scheme::Shamir,
+        })
+    }
+
+    fn check_party(&self, index: u64) -> Result<(), SssError> {
+        if index == 0 {
+            return Err(SssError::ZeroIndex);
+        }
+        if index > self.share_amount as u64 {
+            return Err(SssError::InvalidIndex(index));
+        }
+        Ok(())
+    }
+
+    // At least t distinct helpers, none of them the party that lost its share
+    pub(crate) fn check_helpers(&self, lost: u64, helpers: &[u64]) -> Result<(), SssError> {
+        self.check_party(lost)?;
+        if helpers.len() < self.threshold {
+            return Err(SssError::NotEnoughShares(self.threshold, helpers.len()));
+        }
+        let mut seen = HashSet::new();
+        for &helper in helpers {
+            self.check_party(helper)?;
+            if helper == lost || !seen.insert(helper) {
+                return Err(SssError::DuplicateIndex(helper));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<C: FeldmanCurve> FeldmanVSS<C> {
+    /// Blinding sub-shares for the helpers, in the order of `helpers`, and the commitments to the blinding polynomial.
+    pub fn deal_blinding(&self, lost: u64, helpers: &[u64]) -> Result<Dealing<C>, SssError> {
+        self.sss.check_helpers(lost, helpers)?;
+        let blinding = self.sss.sample_blinding_polynomial(lost)?;
+        let commitments = self.commit(&blinding);
+        let sub_shares = helpers
+            .iter()
+            .map(|&helper| Share {
+                index: helper,
+                value: blinding.evaluate(&Scheme::Shamir.point::<C::ScalarField>(helper)),
+                threshold: self.sss.threshold,
+                scheme: Scheme::Shamir,
+            })
+            .collect();
+        Ok((sub_shares, commitments))
+    }
+
+    /// Checks a blinding sub-share against the dealer's commitments, which must open to zero at party `lost`.
+    pub fn verify_blinding(&self, sub_share: &Share<C::ScalarField>, lost: u64, commitments: &[Commitment<C>]) -> bool {
+        self.commitment_at(commitments, lost).is_neutral_element() && self.verify_share(sub_share, commitments)
+    }
+
+    /// Checks a helper's answer against the commitments of the shares and of the blindings it was blinded with.
+    pub fn verify_answer(
+        &self,
+        answer: &Share<C::ScalarField>,
+        commitments: &[Commitment<C>],
+        blinding_commitments: &[Vec<Commitment<C>>],
+    ) -> bool {
+        let mut dealings = vec![commitments.to_vec()];
+        dealings.extend_from_slice(blinding_commitments);
+        match self.combine_commitments(&dealings) {
+            Ok(combined) => self.verify_share(answer, &combined),
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FeldmanVSS, LargeField, ShamirSecretSharing, SssError};
+    use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrElement;
+
+    #[test]
+    fn lost_share_is_recovered_from_blinded_answers() {
+        let sss = ShamirSecretSharing::new(3, 6).unwrap();
+        let shares = sss.split(LargeField::from(1234u64)).unwrap();
+        let (lost, helpers) = (4u64, [1u64, 5, 6]);
+
+        let blinding = sss.sample_blinding_polynomial(lost).unwrap();
+        assert_eq!(blinding.evaluate(&LargeField::from(lost)), LargeField::from(0u64));
+        assert!(blinding.degree() < 3);
+
+        // dealings[j][k] is helper j's blinding sub-share for helper k
+        let dealings: Vec<_> = helpers.iter().map(|_| sss.deal_blinding(lost, &helpers).unwrap()).collect();
+        let answers: Vec<_> = helpers
+            .iter()
+            .enumerate()
+            .map(|(k, &helper)| {
+                let received: Vec<_> = dealings.iter().map(|dealing| dealing[k].clone()).collect();
+                sss.blind_share(&shares[helper as usize - 1], &received).unwrap()
+            })
+            .collect();
+        // The answers hide the helpers' shares
+        assert!(answers
+            .iter()
+            .zip(helpers)
+            .all(|(answer, helper)| answer.value != shares[helper as usize - 1].value));
+        assert_eq!(sss.recover_share(lost, &answers).unwrap(), shares[3]);
+    }
+
+    #[test]
+    fn helpers_are_checked() {
+        let sss = ShamirSecretSharing::new(3, 6).unwrap();
+        let shares = sss.split(LargeField::from(1u64)).unwrap();
+        assert_eq!(sss.deal_blinding(4, &[1, 2]).unwrap_err(), SssError::NotEnoughShares(3, 2));
+        assert_eq!(sss.deal_blinding(4, &[1, 2, 4]).unwrap_err(), SssError::DuplicateIndex(4));
+        assert_eq!(sss.deal_blinding(7, &[1, 2, 3]).unwrap_err(), SssError::InvalidIndex(7));
+        assert_eq!(sss.sample_blinding_polynomial(0).unwrap_err(), SssError::ZeroIndex);
+        assert_eq!(sss.recover_share(1, &shares[..3]), Err(SssError::DuplicateIndex(1)));
+        // Without blinding the answers are just shares and still give the lost one back
+        assert_eq!(sss.recover_share(6, &shares[1..4]).unwrap(), shares[5]);
+    }
+
+    #[test]
+    fn committed_answers_are_checked_one_by_one() {
+        let vss: FeldmanVSS = FeldmanVSS::new(3, 7).unwrap();
+        let (shares, commitments) = vss.deal(FrElement::from(1234u64)).unwrap();
+        // Five helpers for a threshold of three, blinded by the first three of them
+        let (lost, helpers) = (2u64, [3u64, 4, 5, 6, 7]);
+        let dealings: Vec<_> = (0..3).map(|_| vss.deal_blinding(lost, &helpers).unwrap()).collect();
+        let blinding_commitments: Vec<_> = dealings.iter().map(|(_, commitments)| commitments.clone()).collect();
+
+        let mut answers: Vec<_> = helpers
+            .iter()
+            .enumerate()
+            .map(|(k, &helper)| {
+                let received: Vec<_> = dealings
+                    .iter()
+                    .map(|(sub_shares, commitments)| {
+                        assert!(vss.verify_blinding(&sub_shares[k], lost, commitments));
+                        sub_shares[k].clone()
+                    })
+                    .collect();
+                vss.sss.blind_share(&shares[helper as usize - 1], &received).unwrap()
+            })
+            .collect();
+        answers[1].value = &answers[1].value + FrElement::one();
+
+        let verified: Vec<_> = answers
+            .into_iter()
+            .filter(|answer| vss.verify_answer(answer, &commitments, &blinding_commitments))
+            .collect();
+        assert_eq!(verified.iter().map(|answer| answer.index).collect::<Vec<_>>(), vec![3, 5, 6, 7]);
+        let recovered = vss.sss.recover_share(lost, &verified[..3]).unwrap();
+        assert_eq!(recovered, shares[1]);
+        assert!(vss.verify_share(&recovered, &commitments));
+
+        // A sharing that doesn't vanish at the lost party's point is no blinding
+        let (sub_shares, nonzero) = vss.deal(FrElement::from(1u64)).unwrap();
+        assert!(!vss.verify_blinding(&sub_shares[2], lost, &nonzero));
+    }
+}
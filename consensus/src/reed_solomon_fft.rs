@@ -1,6 +1,5 @@
-use crate::{get_shards, reconstruct_data, LargeField, ShamirSecretSharingFFT};
+use crate::{LargeField, ShamirSecretSharingFFT, SssError};
 use lambdaworks_math::traits::ByteConversion;
-use reed_solomon_erasure::Error;
 /*
  *  Steps to use FFT SSS in Reed solomon:
  * ***ENCODE***
@@ -13,17 +12,25 @@ use reed_solomon_erasure::Error;
  * ***DECODE***
  * Convert the bytes to Starkfield elements -> [shares]
  * Convert evaluation points to Roots of Unity -> [eval_points]
- * Interpolate the polynomial from [shares, eval_points] -> [polynomial_coefficients]
+ * Recover the polynomial from [shares, eval_points] with the FFT erasure decoder -> [polynomial_coefficients]
  * Evaluate the polynomial at N points using FFT -> [shares_lw]
  * Return the first t shares, convert to bytes -> [data]
  *
 
 */
 
-pub fn get_shards_fft(data: Vec<u8>, shards: usize, parity_shards: usize) -> Vec<Vec<u8>> {
+// Every shard is the 32 byte big endian encoding of one evaluation
+const SHARD_SIZE: usize = 32;
+// Data blocks stay below the modulus of the 252 bit field
+const MAX_BLOCK_SIZE: usize = SHARD_SIZE - 1;
+
+pub fn get_shards_fft(data: Vec<u8>, shards: usize, parity_shards: usize) -> Result<Vec<Vec<u8>>, SssError> {
     let original_size = data.len();
     let k = shards;
     let n = k + parity_shards;
+    if k == 0 {
+        return Err(SssError::InvalidThreshold(k, n));
+    }
 
     let size = if original_size % k == 0 {
         original_size
@@ -33,28 +40,21 @@ pub fn get_shards_fft(data: Vec<u8>, shards: usize, parity_shards: usize) -> Vec
     let mut input = data;
     input.resize(size, 0);
     let block_size = size / k;
-
-    let mut output_shards = Vec::new();
-    for i in 0..k {
-        let mut shard = vec![0; block_size];
-        shard.copy_from_slice(&input[i * block_size..(i + 1) * block_size]);
-        output_shards.push(shard);
+    if block_size > MAX_BLOCK_SIZE {
+        return Err(SssError::OutOfRange);
     }
-    assert!(output_shards.len() == k);
+
     let mut elements: Vec<LargeField> = Vec::new();
     for i in 0..k {
-        elements.push(LargeField::from_bytes_be(&output_shards[i]).unwrap());
+        let mut block = vec![0; SHARD_SIZE - block_size];
+        block.extend_from_slice(&input[i * block_size..(i + 1) * block_size]);
+        elements.push(LargeField::from_bytes_be(&block).map_err(|_| SssError::OutOfRange)?);
     }
-    let sss = ShamirSecretSharingFFT::new(k, n);
-    sss.fill_evaluation_at_all_points(&mut elements);
+    // The data blocks are P(w^0), ..., P(w^(k-1)) and the parity shards the rest of the domain
+    let sss = ShamirSecretSharingFFT::new(k, n)?;
+    sss.fill_evaluation_at_all_points_fft(&mut elements)?;
 
-    output_shards.clear();
-    for i in 0..n {
-        let mut shard = vec![0; block_size];
-        shard.copy_from_slice(&elements[i].to_bytes_be().to_vec());
-        output_shards.push(shard);
-    }
-    output_shards
+    Ok(elements.iter().map(|element| element.to_bytes_be().to_vec()).collect())
 }
 
 // The shards are reconstructed inline with the variable data
@@ -62,29 +62,41 @@ pub fn reconstruct_data_fft(
     data: &mut Vec<Option<Vec<u8>>>,
     shards: usize,
     parity_shards: usize,
-) -> Result<(), Error> {
+) -> Result<(), SssError> {
     let k = shards;
     let n = k + parity_shards;
+    if data.len() != n {
+        return Err(SssError::WrongShareCount(n, data.len()));
+    }
     let mut elements: Vec<LargeField> = Vec::new();
     let mut eval_points: Vec<u64> = Vec::new();
 
     for i in 0..n {
         if let Some(share) = &data[i] {
-            elements.push(LargeField::from_bytes_be(&share).unwrap());
-            eval_points.push(i as u64);
+            if share.len() != SHARD_SIZE {
+                return Err(SssError::InvalidLength(SHARD_SIZE, share.len()));
+            }
+            elements.push(LargeField::from_bytes_be(share).map_err(|_| SssError::OutOfRange)?);
+            eval_points.push(i as u64 + 1);
         }
     }
+    if elements.len() < k {
+        return Err(SssError::NotEnoughShares(k, elements.len()));
+    }
 
-    let sss = ShamirSecretSharingFFT::new(k, n);
-    let polynomial_coeffs = sss.reconstructing(&eval_points, &elements);
-
-    let shares = sss.generating_shares(&polynomial_coeffs);
+    // Shard i holds the evaluation at w^i, any k of them determine P
+    let sss = ShamirSecretSharingFFT::new(k, n)?;
+    let polynomial_coeffs = sss.interpolate_on_domain(&eval_points[..k], &elements[..k])?;
+    let shares = sss.evaluations(&polynomial_coeffs);
 
     for i in 0..data.len() {
+        let shard = shares[i].to_bytes_be().to_vec();
         if let Some(share) = &data[i] {
-            assert!(share == &shares[i].to_bytes_be().to_vec());
+            if share != &shard {
+                return Err(SssError::TooManyErrors);
+            }
         }
-        data[i] = Some(shares[i].to_bytes_be().to_vec());
+        data[i] = Some(shard);
     }
 
     Ok(())
@@ -94,12 +106,7 @@ pub fn reconstruct_data_fft(
 #[cfg(test)]
 mod tests {
 
-    use crate::{
-        get_shards, get_shards_fft, reconstruct_data, reconstruct_data_fft, LargeField,
-        ShamirSecretSharingFFT,
-    };
-    use lambdaworks_math::traits::ByteConversion;
-    use reed_solomon_erasure::Error;
+    use crate::{get_shards_fft, reconstruct_data_fft, SssError};
 
     #[test]
     fn test_reed_solomon_fft_encode() {
@@ -107,8 +114,28 @@ mod tests {
         let shards = 2;
         let parity_shards = 2;
 
-        let encoded_shards = get_shards_fft(data.clone(), shards, parity_shards);
+        let encoded_shards = get_shards_fft(data.clone(), shards, parity_shards).unwrap();
         assert!(encoded_shards.len() == 4);
-       
+        assert_eq!(encoded_shards[0][28..], data[..4]);
+        assert_eq!(encoded_shards[1][28..], data[4..]);
+    }
+
+    #[test]
+    fn test_reed_solomon_fft_reconstruct() {
+        let data: Vec<u8> = (1..=40).collect();
+        let encoded_shards = get_shards_fft(data, 4, 4).unwrap();
+
+        let mut received: Vec<Option<Vec<u8>>> = encoded_shards.iter().cloned().map(Some).collect();
+        for erased in [0, 2, 5, 6] {
+            received[erased] = None;
+        }
+        reconstruct_data_fft(&mut received, 4, 4).unwrap();
+        let recovered: Vec<Vec<u8>> = received.into_iter().map(Option::unwrap).collect();
+        assert_eq!(recovered, encoded_shards);
+
+        let mut too_few: Vec<Option<Vec<u8>>> = vec![None; 8];
+        too_few[1] = Some(encoded_shards[1].clone());
+        assert_eq!(reconstruct_data_fft(&mut too_few, 4, 4), Err(SssError::NotEnoughShares(4, 1)));
+        assert_eq!(get_shards_fft(vec![7; 64], 2, 2), Err(SssError::OutOfRange));
     }
 }
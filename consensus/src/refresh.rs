@@ -0,0 +1,149 @@
+use lambdaworks_math::cyclic_group::IsGroup;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::compression::{
+    compress_g1_point, decompress_g1_point,
+};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::field::element::FieldElement;
+
+use crate::{Commitment, Dealing, FeldmanCurve, FeldmanVSS, Share, SssError};
+
+/// Size of a compressed BLS12-381 G1 point.
+pub const COMMITMENT_SIZE: usize = 48;
+
+/**
+ * Proactive refresh
+ * Every party deals a Feldman sharing of zero, Z_j with Z_j(0) = 0, and party i adds the sub-shares Z_j(i) of
+ * the accepted dealers to its share P(i). The new shares lie on P + sum_j Z_j, which has the same secret but
+ * is independent of P, so shares taken from different epochs don't combine.
+ * A zero-sharing is only accepted if its commitments open to zero at x = 0, i.e. g^{Z_j(0)} is the identity,
+ * and the sub-share matches them; otherwise a dealer could shift the secret.
+ * The commitments of the refreshed polynomial are the coefficient-wise products of the old ones and those of
+ * the accepted zero-sharings, so new shares keep verifying with `verify_share`.
+ */
+impl<C: FeldmanCurve> FeldmanVSS<C> {
+    /// Sub-shares of a fresh sharing of zero, and its commitments.
+    pub fn deal_zero(&self) -> Result<Dealing<C>, SssError> {
+        self.deal(FieldElement::zero())
+    }
+
+    /// Checks a sub-share of a zero-sharing against the dealer's commitments.
+    pub fn verify_zero_share(&self, share: &Share<C::ScalarField>, commitments: &[Commitment<C>]) -> bool {
+        match commitments.first() {
+            Some(constant) if constant.is_neutral_element() => self.verify_share(share, commitments),
+            _ => false,
+        }
+    }
+
+    /// The share after adding the sub-shares of the accepted zero-sharings.
+    pub fn refresh_share(
+        &self,
+        share: &Share<C::ScalarField>,
+        sub_shares: &[Share<C::ScalarField>],
+    ) -> Result<Share<C::ScalarField>, SssError> {
+        sub_shares
+            .iter()
+            .try_fold(share.clone(), |refreshed, sub_share| refreshed.add_share(sub_share))
+    }
+
+    /// Commitments of the sum of the committed polynomials, all with `threshold` commitments.
+    pub fn combine_commitments(&self, commitments: &[Vec<Commitment<C>>]) -> Result<Vec<Commitment<C>>, SssError> {
+        let mut combined = vec![Commitment::<C>::neutral_element(); self.sss.threshold];
+        for dealing in commitments {
+            if dealing.len() != self.sss.threshold {
+                return Err(SssError::WrongShareCount(self.sss.threshold, dealing.len()));
+            }
+            for (sum, commitment) in combined.iter_mut().zip(dealing) {
+                *sum = sum.operate_with(commitment);
+            }
+        }
+        Ok(combined)
+    }
+}
+
+/// Compressed encoding of BLS12-381 commitments, `COMMITMENT_SIZE` bytes each.
+pub fn commitments_to_bytes(commitments: &[Commitment<BLS12381Curve>]) -> Vec<u8> {
+    commitments.iter().flat_map(compress_g1_point).collect()
+}
+
+/// Decodes compressed BLS12-381 commitments, rejecting points outside the prime order subgroup.
+pub fn commitments_from_bytes(bytes: &[u8]) -> Result<Vec<Commitment<BLS12381Curve>>, SssError> {
+    if bytes.len() % COMMITMENT_SIZE != 0 {
+        return Err(SssError::InvalidLength(COMMITMENT_SIZE, bytes.len() % COMMITMENT_SIZE));
+    }
+    bytes
+        .chunks(COMMITMENT_SIZE)
+        .map(|chunk| {
+            let mut compressed = [0u8; COMMITMENT_SIZE];
+            compressed.copy_from_slice(chunk);
+            decompress_g1_point(&mut compressed).map_err(|_| SssError::InvalidCommitment)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{commitments_from_bytes, commitments_to_bytes, FeldmanVSS, SssError};
+    use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrElement;
+
+    #[test]
+    fn refreshed_shares_keep_the_secret() {
+        let vss: FeldmanVSS = FeldmanVSS::new(3, 5).unwrap();
+        let secret = FrElement::from(1234u64);
+        let (shares, commitments) = vss.deal(secret.clone()).unwrap();
+        let zero_sharings: Vec<_> = (0..4).map(|_| vss.deal_zero().unwrap()).collect();
+
+        let mut dealings = vec![commitments];
+        dealings.extend(zero_sharings.iter().map(|(_, commitments)| commitments.clone()));
+        let refreshed_commitments = vss.combine_commitments(&dealings).unwrap();
+
+        let refreshed: Vec<_> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, share)| {
+                let sub_shares: Vec<_> = zero_sharings
+                    .iter()
+                    .map(|(sub_shares, commitments)| {
+                        assert!(vss.verify_zero_share(&sub_shares[i], commitments));
+                        sub_shares[i].clone()
+                    })
+                    .collect();
+                vss.refresh_share(share, &sub_shares).unwrap()
+            })
+            .collect();
+
+        assert_ne!(refreshed[0], shares[0]);
+        assert!(refreshed
+            .iter()
+            .all(|share| vss.verify_share(share, &refreshed_commitments)));
+        assert_eq!(*vss.sss.recover(&refreshed[2..]).unwrap(), secret);
+        // Old and new shares lie on different polynomials
+        let mixed = vec![shares[0].clone(), shares[1].clone(), refreshed[2].clone()];
+        assert_ne!(*vss.sss.recover(&mixed).unwrap(), secret);
+    }
+
+    #[test]
+    fn sharings_of_nonzero_values_are_rejected() {
+        let vss: FeldmanVSS = FeldmanVSS::new(3, 5).unwrap();
+        let (shares, commitments) = vss.deal(FrElement::from(1u64)).unwrap();
+        assert!(vss.verify_share(&shares[0], &commitments));
+        assert!(!vss.verify_zero_share(&shares[0], &commitments));
+
+        let (mut sub_shares, zero_commitments) = vss.deal_zero().unwrap();
+        sub_shares[1].value = &sub_shares[1].value + FrElement::one();
+        assert!(!vss.verify_zero_share(&sub_shares[1], &zero_commitments));
+        assert!(!vss.verify_zero_share(&sub_shares[0], &[]));
+    }
+
+    #[test]
+    fn commitments_roundtrip_through_bytes() {
+        let vss: FeldmanVSS = FeldmanVSS::new(3, 5).unwrap();
+        let (_, commitments) = vss.deal_zero().unwrap();
+        let bytes = commitments_to_bytes(&commitments);
+        assert_eq!(bytes.len(), 3 * 48);
+        assert_eq!(commitments_from_bytes(&bytes).unwrap(), commitments);
+        assert_eq!(commitments_from_bytes(&bytes[1..]), Err(SssError::InvalidLength(48, 47)));
+        let mut corrupted = bytes.clone();
+        corrupted[60] ^= 1;
+        assert_eq!(commitments_from_bytes(&corrupted), Err(SssError::InvalidCommitment));
+    }
+}
@@ -0,0 +1,203 @@
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
+use rand_core::{CryptoRng, OsRng, RngCore};
+
+use crate::feldman::evaluate_in_exponent;
+use crate::{
+    linear_combination, Commitment, FeldmanCurve, FeldmanVSS, Reconstructor, Scheme, ShamirSecretSharing, Share,
+    SssError,
+};
+
+/**
+ * Resharing to a new committee
+ * The secret is shared as P of degree t - 1 among n parties and moves to a sharing of degree t' - 1 among n'
+ * parties without being reconstructed. Every old party i in a set S of at least t parties shares its share
+ * with Q_i of degree t' - 1, Q_i(0) = P(i), and new party j gets Q_i(j) from each of them.
+ * With the Lagrange weights l_i(0) of S, new party j's share is sum_i l_i(0) Q_i(j), the evaluation at j of
+ * Q = sum_i l_i(0) Q_i, and Q(0) = sum_i l_i(0) P(i) = P(0). The weights only depend on S, so they are
+ * computed once with `combiner` and every new party must use the same S.
+ */
+#[derive(Clone, Debug)]
+pub struct Resharing<F: IsFFTField = Stark252PrimeField> {
+    /// the sharing the secret is held in now, (t, n)
+    pub old: ShamirSecretSharing<F>,
+    /// the sharing it moves to, (t', n')
+    pub new: ShamirSecretSharing<F>,
+}
+
+impl<F: IsFFTField + IsPrimeField> Resharing<F> {
+    pub fn new(old: ShamirSecretSharing<F>, new: ShamirSecretSharing<F>) -> Self {
+        Resharing { old, new }
+    }
+
+    /// Sub-shares of an old party's share for the new parties 1..=n'.
+    pub fn reshare(&self, share: &Share<F>) -> Result<Vec<Share<F>>, SssError> {
+        self.reshare_with_rng(share, &mut OsRng)
+    }
+
+    /// Same as `reshare`, with the random coefficients drawn from `rng`.
+    pub fn reshare_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        share: &Share<F>,
+        rng: &mut R,
+    ) -> Result<Vec<Share<F>>, SssError> {
+        if share.scheme != Scheme::Shamir || share.threshold != self.old.threshold {
+            return Err(SssError::Incompatible);
+        }
+        if share.index == 0 || share.index > self.old.share_amount as u64 {
+            return Err(SssError::InvalidIndex(share.index));
+        }
+        let polynomial = self.new.sample_polynomial_with_rng(share.value.clone(), rng);
+        self.new.generating_shares(&polynomial)
+    }
+
+    /// Precomputes the weights for the old parties in `dealers`, at least t of them.
+    pub fn combiner(&self, dealers: &[u64]) -> Result<Reconstructor<F>, SssError> {
+        self.old.reconstructor(dealers)
+    }
+
+    /// A new party's share from its sub-shares, one per dealer in the order of `combiner.indices()`.
+    pub fn combine(&self, combiner: &Reconstructor<F>, sub_shares: &[Share<F>]) -> Result<Share<F>, SssError> {
+        if sub_shares.len() != combiner.indices().len() {
+            return Err(SssError::WrongShareCount(combiner.indices().len(), sub_shares.len()));
+        }
+        if sub_shares
+            .iter()
+            .any(|sub_share| sub_share.scheme != Scheme::Shamir || sub_share.threshold != self.new.threshold)
+        {
+            return Err(SssError::Incompatible);
+        }
+        linear_combination(sub_shares, combiner.weights())
+    }
+}
+
+/**
+ * Verifiable resharing
+ * When the old sharing has Feldman commitments, dealer i commits to Q_i with the new committee's `FeldmanVSS`.
+ * Its constant commitment g^{Q_i(0)} must be g^{P(i)}, which everybody computes from the old commitments, so
+ * a dealer can't reshare anything but its share. The new commitments are prod_i (g^{b_ik})^{l_i(0)}
+ * coefficient by coefficient and keep g^{P(0)} as the constant.
+ */
+impl<C: FeldmanCurve> FeldmanVSS<C> {
+    /// g^{P(x)} at party `index`'s point, from the commitments of P.
+    pub fn commitment_at(&self, commitments: &[Commitment<C>], index: u64) -> Commitment<C> {
+        let x = Scheme::Shamir.point::<C::ScalarField>(index);
+        let powers: Vec<FieldElement<C::ScalarField>> = (0..commitments.len())
+            .map(|j| x.pow(j as u64))
+            .collect();
+        evaluate_in_exponent::<C>(commitments, &powers)
+    }
+
+    /// Checks a sub-share dealt by old party `dealer` against its commitments, and that they reshare the
+    /// dealer's share of the sharing committed to by `old_commitments`.
+    pub fn verify_sub_share(
+        &self,
+        sub_share: &Share<C::ScalarField>,
+        dealer: u64,
+        dealer_commitments: &[Commitment<C>],
+        old_commitments: &[Commitment<C>],
+    ) -> bool {
+        match dealer_commitments.first() {
+            Some(constant) if *constant == self.commitment_at(old_commitments, dealer) => {
+                self.verify_share(sub_share, dealer_commitments)
+            }
+            _ => false,
+        }
+    }
+
+    /// Commitments of the new sharing from the dealers' commitments, in the order of `combiner.indices()`.
+    pub fn combine_reshared_commitments(
+        &self,
+        combiner: &Reconstructor<C::ScalarField>,
+        dealer_commitments: &[Vec<Commitment<C>>],
+    ) -> Result<Vec<Commitment<C>>, SssError> {
+        if dealer_commitments.len() != combiner.indices().len() {
+            return Err(SssError::WrongShareCount(combiner.indices().len(), dealer_commitments.len()));
+        }
+        if let Some(dealing) = dealer_commitments.iter().find(|dealing| dealing.len() != self.sss.threshold) {
+            return Err(SssError::WrongShareCount(self.sss.threshold, dealing.len()));
+        }
+        Ok((0..self.sss.threshold)
+            .map(|k| {
+                let column: Vec<Commitment<C>> = dealer_commitments.iter().map(|dealing| dealing[k].clone()).collect();
+                evaluate_in_exponent::<C>(&column, combiner.weights())
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FeldmanVSS, LargeField, Resharing, ShamirSecretSharing, SssError};
+    use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrElement;
+
+    #[test]
+    fn reshared_secret_is_unchanged() {
+        // (3, 5) to (4, 7), the dealers are old parties 1, 3 and 4
+        let resharing = Resharing::new(
+            ShamirSecretSharing::new(3, 5).unwrap(),
+            ShamirSecretSharing::new(4, 7).unwrap(),
+        );
+        let secret = LargeField::from(1234u64);
+        let old_shares = resharing.old.split(secret).unwrap();
+        let dealers = [1u64, 3, 4];
+        let sub_shares: Vec<_> = dealers
+            .iter()
+            .map(|&dealer| resharing.reshare(&old_shares[dealer as usize - 1]).unwrap())
+            .collect();
+
+        let combiner = resharing.combiner(&dealers).unwrap();
+        let new_shares: Vec<_> = (0..7)
+            .map(|j| {
+                let received: Vec<_> = sub_shares.iter().map(|dealing| dealing[j].clone()).collect();
+                resharing.combine(&combiner, &received).unwrap()
+            })
+            .collect();
+        assert_eq!(new_shares[6].index, 7);
+        assert_eq!(new_shares[6].threshold, 4);
+        assert_eq!(resharing.new.recover(&new_shares[3..]).unwrap(), secret);
+        // The new threshold holds, three new shares are not enough
+        assert_eq!(resharing.new.recover(&new_shares[..3]), Err(SssError::NotEnoughShares(4, 3)));
+
+        assert_eq!(resharing.combiner(&[1, 2]).unwrap_err(), SssError::NotEnoughShares(3, 2));
+        assert_eq!(resharing.reshare(&new_shares[0]), Err(SssError::Incompatible));
+        assert_eq!(
+            resharing.combine(&combiner, &[new_shares[0].clone()]),
+            Err(SssError::WrongShareCount(3, 1))
+        );
+    }
+
+    #[test]
+    fn verifiable_resharing_keeps_the_commitment_to_the_secret() {
+        let old: FeldmanVSS = FeldmanVSS::new(2, 4).unwrap();
+        let new: FeldmanVSS = FeldmanVSS::new(3, 5).unwrap();
+        let resharing = Resharing::new(old.sss.clone(), new.sss.clone());
+        let (old_shares, old_commitments) = old.deal(FrElement::from(99u64)).unwrap();
+
+        let dealers = [2u64, 4];
+        let dealings: Vec<_> = dealers
+            .iter()
+            .map(|&dealer| new.deal(old_shares[dealer as usize - 1].value.clone()).unwrap())
+            .collect();
+        for (&dealer, (sub_shares, commitments)) in dealers.iter().zip(&dealings) {
+            assert!(sub_shares
+                .iter()
+                .all(|sub_share| new.verify_sub_share(sub_share, dealer, commitments, &old_commitments)));
+        }
+        // Dealer 2 can't pass off a sharing of another value as its share
+        let (forged, forged_commitments) = new.deal(FrElement::from(5u64)).unwrap();
+        assert!(!new.verify_sub_share(&forged[0], 2, &forged_commitments, &old_commitments));
+        assert!(!new.verify_sub_share(&dealings[1].0[0], 2, &dealings[1].1, &old_commitments));
+
+        let combiner = resharing.combiner(&dealers).unwrap();
+        let dealer_commitments: Vec<_> = dealings.iter().map(|(_, commitments)| commitments.clone()).collect();
+        let new_commitments = new.combine_reshared_commitments(&combiner, &dealer_commitments).unwrap();
+        assert_eq!(new_commitments[0], old_commitments[0]);
+        for j in 0..5 {
+            let received: Vec<_> = dealings.iter().map(|(sub_shares, _)| sub_shares[j].clone()).collect();
+            let share = resharing.combine(&combiner, &received).unwrap();
+            assert!(new.verify_share(&share, &new_commitments));
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::{IsFFTField, IsField, IsPrimeField};
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{check_shares, Erase, Scheme, Secret, Share, SssError};
+
+/**
+ * Gao's Reed-Solomon decoder
+ * Given m points of which at most (m - k) / 2 are wrong, finds the unique polynomial f of degree < k
+ * through the remaining ones.
+ * 1. g0 = prod (X - x_i), g1 = the interpolation of all points
+ * 2. Run the extended Euclidean algorithm on (g0, g1) until the remainder g has degree < (m + k) / 2,
+ *    giving u * g0 + v * g1 = g
+ * 3. f = g / v if the division is exact and deg f < k, otherwise there were too many errors
+ */
+pub fn gao_decode<F: IsField>(
+    x: &[FieldElement<F>],
+    y: &[FieldElement<F>],
+    k: usize,
+) -> Option<Polynomial<FieldElement<F>>> {
+    let m = x.len();
+    if m != y.len() || m < k {
+        return None;
+    }
+
+    let g0 = x.iter().fold(
+        Polynomial::new(&[FieldElement::one()]),
+        |acc, xi| acc * Polynomial::new(&[-xi, FieldElement::one()]),
+    );
+    let g1 = Polynomial::interpolate(x, y).ok()?;
+
+    let mut r_prev = g0;
+    let mut r = g1;
+    let mut v_prev = Polynomial::zero();
+    let mut v = Polynomial::new(&[FieldElement::one()]);
+    // deg r >= (m + k) / 2, the zero polynomial has degree -infinity
+    while !is_zero(&r) && 2 * r.degree() >= m + k {
+        let (q, rem) = r_prev.long_division_with_remainder(&r);
+        r_prev = r;
+        r = rem;
+        let next_v = &v_prev - &(&q * &v);
+        v_prev = v;
+        v = next_v;
+    }
+
+    let (f, rem) = r.long_division_with_remainder(&v);
+    if is_zero(&rem) && (is_zero(&f) || f.degree() < k) {
+        Some(f)
+    } else {
+        None
+    }
+}
+
+fn is_zero<F: IsField>(polynomial: &Polynomial<FieldElement<F>>) -> bool {
+    polynomial.coefficients().is_empty()
+}
+
+/// Decodes the sharing polynomial from possibly corrupted shares and returns it with the indices
+/// of the shares that don't lie on it.
+pub(crate) fn decode_shares<F: IsFFTField + IsPrimeField>(
+    shares: &[Share<F>],
+    scheme: Scheme,
+    threshold: usize,
+) -> Result<(Secret<Polynomial<FieldElement<F>>>, Vec<u64>), SssError> {
+    check_shares(shares, scheme, threshold)?;
+
+    let x: Vec<FieldElement<F>> = shares.iter().map(|share| share.x()).collect();
+    let mut y: Vec<FieldElement<F>> = shares.iter().map(|share| share.value.clone()).collect();
+    let polynomial = gao_decode(&x, &y, threshold).map(Secret::new);
+    y.erase();
+    let polynomial = polynomial.ok_or(SssError::TooManyErrors)?;
+
+    let bad_indices = shares
+        .iter()
+        .zip(x.iter())
+        .filter(|(share, xi)| polynomial.evaluate(xi) != share.value)
+        .map(|(share, _)| share.index)
+        .collect();
+    Ok((polynomial, bad_indices))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Secret, ShamirSecretSharing, ShamirSecretSharingFFT, SssError};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+    use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+
+    type LargeField = FieldElement<Stark252PrimeField>;
+
+    #[test]
+    fn recover_robust_corrects_bad_shares() {
+        let secret = LargeField::from(1234u64);
+        let sss = ShamirSecretSharing::new(3, 7).unwrap();
+        let mut shares = sss.split(secret).unwrap();
+
+        // 7 shares of a degree 2 polynomial correct up to 2 errors
+        shares[1].value += LargeField::one();
+        shares[5].value = LargeField::from(99u64);
+        assert_eq!(sss.recover_robust(&shares), Ok((Secret::new(secret), vec![2, 6])));
+
+        // Honest shares decode without blame
+        let shares = sss.split(secret).unwrap();
+        assert_eq!(sss.recover_robust(&shares[..4]), Ok((Secret::new(secret), vec![])));
+    }
+
+    #[test]
+    fn recover_robust_fft_corrects_bad_shares() {
+        let secret = FieldElement::<Babybear31PrimeField>::from(77u64);
+        let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(4, 8).unwrap();
+        let mut shares = sss.split(secret.clone()).unwrap();
+
+        shares[0].value += FieldElement::<Babybear31PrimeField>::one();
+        shares[6].value += FieldElement::<Babybear31PrimeField>::one();
+        assert_eq!(sss.recover_robust(&shares), Ok((Secret::new(secret), vec![1, 7])));
+    }
+
+    #[test]
+    fn recover_robust_fails_with_too_many_errors() {
+        let sss = ShamirSecretSharing::new(3, 5).unwrap();
+        let mut shares = sss.split(LargeField::from(5u64)).unwrap();
+
+        // 5 shares of a degree 2 polynomial only correct 1 error
+        shares[0].value += LargeField::one();
+        shares[3].value += LargeField::from(5u64);
+        assert_eq!(sss.recover_robust(&shares), Err(SssError::TooManyErrors));
+        assert_eq!(
+            sss.recover_robust(&shares[..2]),
+            Err(SssError::NotEnoughShares(3, 2))
+        );
+    }
+}
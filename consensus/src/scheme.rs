@@ -0,0 +1,262 @@
+use std::fmt::Debug;
+
+use crypto::LargeField as BigIntField;
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
+
+use crate::{LargeFieldSSS, Scheme, ShamirSecretSharing, ShamirSecretSharingFFT, Share, SssError};
+
+/**
+ * The operations protocols need from a secret-sharing backend, so they can switch between them
+ * `ShamirSecretSharing` puts party i at x = i, `ShamirSecretSharingFFT` at w^(i-1) and `LargeFieldSSS` at the
+ * integer i modulo its prime. Behind the trait a party is always its 1-based index and `point` gives its
+ * x-coordinate. Evaluation vectors always start with the secret at x = 0, followed by the parties in order.
+ */
+pub trait SecretSharingScheme {
+    /// Secrets, shares and evaluation points
+    type Field: Clone + PartialEq + Debug;
+    /// A party's share, as returned by the backend's own `split`
+    type Share: Clone;
+
+    fn threshold(&self) -> usize;
+
+    fn share_amount(&self) -> usize;
+
+    /// The x-coordinate of party `index`.
+    fn point(&self, index: u64) -> Result<Self::Field, SssError>;
+
+    /// Shares for the parties 1..=n, in order.
+    fn split(&self, secret: Self::Field) -> Result<Vec<Self::Share>, SssError>;
+
+    /// Recovers the secret from at least `threshold` shares.
+    fn recover(&self, shares: &[Self::Share]) -> Result<Self::Field, SssError>;
+
+    /// Extends the evaluations at the secret and the first parties, P(0), P(x_1), ..., P(x_(k-1)) for k >= t,
+    /// to the evaluations at the secret and all n parties.
+    fn fill_evaluation_at_all_points(&self, values: &mut Vec<Self::Field>) -> Result<(), SssError>;
+
+    /// The share party `index` holds when its evaluation is `value`, e.g. after receiving it over the network.
+    fn make_share(&self, index: u64, value: Self::Field) -> Self::Share;
+
+    fn share_index(share: &Self::Share) -> u64;
+
+    fn share_value(share: &Self::Share) -> &Self::Field;
+}
+
+fn check_party(index: u64, share_amount: usize) -> Result<(), SssError> {
+    if index == 0 {
+        return Err(SssError::ZeroIndex);
+    }
+    if index > share_amount as u64 {
+        return Err(SssError::InvalidIndex(index));
+    }
+    Ok(())
+}
+
+impl<F: IsFFTField + IsPrimeField> SecretSharingScheme for ShamirSecretSharing<F> {
+    type Field = FieldElement<F>;
+    type Share = Share<F>;
+
+    fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    fn share_amount(&self) -> usize {
+        self.share_amount
+    }
+
+    fn point(&self, index: u64) -> Result<FieldElement<F>, SssError> {
+        check_party(index, self.share_amount)?;
+        Ok(Scheme::Shamir.point(index))
+    }
+
+    fn split(&self, secret: FieldElement<F>) -> Result<Vec<Share<F>>, SssError> {
+        ShamirSecretSharing::split(self, secret)
+    }
+
+    fn recover(&self, shares: &[Share<F>]) -> Result<FieldElement<F>, SssError> {
+        ShamirSecretSharing::recover(self, shares).map(|secret| (*secret).clone())
+    }
+
+    fn fill_evaluation_at_all_points(&self, values: &mut Vec<FieldElement<F>>) -> Result<(), SssError> {
+        ShamirSecretSharing::fill_evaluation_at_all_points(self, values)
+    }
+
+    fn make_share(&self, index: u64, value: FieldElement<F>) -> Share<F> {
+        Share {
+            index,
+            value,
+            threshold: self.threshold,
+            scheme: Scheme::Shamir,
+        }
+    }
+
+    fn share_index(share: &Share<F>) -> u64 {
+        share.index
+    }
+
+    fn share_value(share: &Share<F>) -> &FieldElement<F> {
+        &share.value
+    }
+}
+
+impl<F: IsFFTField + IsPrimeField> SecretSharingScheme for ShamirSecretSharingFFT<F> {
+    type Field = FieldElement<F>;
+    type Share = Share<F>;
+
+    fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    fn share_amount(&self) -> usize {
+        self.share_amount
+    }
+
+    fn point(&self, index: u64) -> Result<FieldElement<F>, SssError> {
+        check_party(index, self.share_amount)?;
+        Ok(self.plan().domain()[index as usize - 1].clone())
+    }
+
+    fn split(&self, secret: FieldElement<F>) -> Result<Vec<Share<F>>, SssError> {
+        ShamirSecretSharingFFT::split(self, secret)
+    }
+
+    fn recover(&self, shares: &[Share<F>]) -> Result<FieldElement<F>, SssError> {
+        ShamirSecretSharingFFT::recover(self, shares).map(|secret| (*secret).clone())
+    }
+
+    fn fill_evaluation_at_all_points(&self, values: &mut Vec<FieldElement<F>>) -> Result<(), SssError> {
+        ShamirSecretSharingFFT::fill_evaluation_at_all_points(self, values)?;
+        // The inherent version evaluates on the whole domain, past the last party
+        values.truncate(self.share_amount + 1);
+        Ok(())
+    }
+
+    fn make_share(&self, index: u64, value: FieldElement<F>) -> Share<F> {
+        Share {
+            index,
+            value,
+            threshold: self.threshold,
+            scheme: Scheme::ShamirFFT {
+                share_amount: self.share_amount,
+            },
+        }
+    }
+
+    fn share_index(share: &Share<F>) -> u64 {
+        share.index
+    }
+
+    fn share_value(share: &Share<F>) -> &FieldElement<F> {
+        &share.value
+    }
+}
+
+impl SecretSharingScheme for LargeFieldSSS {
+    type Field = BigIntField;
+    type Share = (usize, BigIntField);
+
+    fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    fn share_amount(&self) -> usize {
+        self.share_amount
+    }
+
+    fn point(&self, index: u64) -> Result<BigIntField, SssError> {
+        check_party(index, self.share_amount)?;
+        Ok(BigIntField::from(index))
+    }
+
+    fn split(&self, secret: BigIntField) -> Result<Vec<(usize, BigIntField)>, SssError> {
+        Ok(LargeFieldSSS::split(self, secret))
+    }
+
+    // The inherent version interpolates exactly t shares
+    fn recover(&self, shares: &[(usize, BigIntField)]) -> Result<BigIntField, SssError> {
+        if shares.len() < self.threshold {
+            return Err(SssError::NotEnoughShares(self.threshold, shares.len()));
+        }
+        LargeFieldSSS::recover(self, &shares[..self.threshold])
+    }
+
+    // The precomputed Lagrange coefficients take exactly the evaluations at 0..t
+    fn fill_evaluation_at_all_points(&self, values: &mut Vec<BigIntField>) -> Result<(), SssError> {
+        if values.len() < self.threshold {
+            return Err(SssError::NotEnoughShares(self.threshold, values.len()));
+        }
+        if values.len() > self.share_amount + 1 {
+            return Err(SssError::WrongShareCount(self.share_amount + 1, values.len()));
+        }
+        values.truncate(self.threshold);
+        LargeFieldSSS::fill_evaluation_at_all_points(self, values)
+    }
+
+    fn make_share(&self, index: u64, value: BigIntField) -> (usize, BigIntField) {
+        (index as usize, value)
+    }
+
+    fn share_index(share: &(usize, BigIntField)) -> u64 {
+        share.0 as u64
+    }
+
+    fn share_value(share: &(usize, BigIntField)) -> &BigIntField {
+        &share.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LargeFieldSSS, SecretSharingScheme, ShamirSecretSharing, ShamirSecretSharingFFT, SssError};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+    use num_bigint_dig::BigInt;
+
+    // Everything a protocol does with a backend, written once
+    fn check_scheme<S: SecretSharingScheme>(scheme: &S, secret: S::Field) {
+        let shares = scheme.split(secret.clone()).unwrap();
+        assert_eq!(shares.len(), scheme.share_amount());
+        let t = scheme.threshold();
+        assert_eq!(scheme.recover(&shares[shares.len() - t..]).unwrap(), secret);
+        assert_eq!(
+            scheme.recover(&shares[..t - 1]),
+            Err(SssError::NotEnoughShares(t, t - 1))
+        );
+
+        // Shares rebuilt from (index, value) pairs, as received from the network
+        let rebuilt: Vec<S::Share> = shares
+            .iter()
+            .rev()
+            .take(t)
+            .map(|share| scheme.make_share(S::share_index(share), S::share_value(share).clone()))
+            .collect();
+        assert_eq!(scheme.recover(&rebuilt).unwrap(), secret);
+
+        let mut values = vec![secret.clone()];
+        values.extend(shares[..t - 1].iter().map(|share| S::share_value(share).clone()));
+        scheme.fill_evaluation_at_all_points(&mut values).unwrap();
+        let mut expected = vec![secret];
+        expected.extend(shares.iter().map(|share| S::share_value(share).clone()));
+        assert_eq!(values, expected);
+
+        assert!(scheme.point(1).is_ok());
+        assert_eq!(scheme.point(0), Err(SssError::ZeroIndex));
+        let outside = scheme.share_amount() as u64 + 1;
+        assert_eq!(scheme.point(outside), Err(SssError::InvalidIndex(outside)));
+    }
+
+    #[test]
+    fn all_backends_behave_alike() {
+        type FE = FieldElement<Babybear31PrimeField>;
+        check_scheme(&ShamirSecretSharing::<Babybear31PrimeField>::new(3, 7).unwrap(), FE::from(42u64));
+        // 6 parties on a domain of 8
+        check_scheme(&ShamirSecretSharingFFT::<Babybear31PrimeField>::new(3, 6).unwrap(), FE::from(42u64));
+        let prime = BigInt::parse_bytes(
+            b"115792088158918333131516597762172392628570465465856793992332884130307292657121",
+            10,
+        )
+        .unwrap();
+        check_scheme(&LargeFieldSSS::new(3, 7, prime).unwrap(), BigInt::from(42u64));
+    }
+}
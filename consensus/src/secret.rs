@@ -0,0 +1,141 @@
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsField;
+use lambdaworks_math::polynomial::Polynomial;
+use zeroize::Zeroize;
+
+/// Overwrites secret material in place with `zeroize`, so it doesn't linger in freed memory. A vector is
+/// wiped over its whole capacity, but copies left behind by an earlier reallocation are out of reach, so
+/// vectors that hold secrets are allocated at their final size.
+pub trait Erase {
+    fn erase(&mut self);
+}
+
+impl<F: IsField> Erase for FieldElement<F> {
+    fn erase(&mut self) {
+        // Safety: field elements are plain limbs, and all zero bits is the zero element
+        unsafe { zeroize::zeroize_flat_type(self as *mut Self) };
+    }
+}
+
+impl Erase for u8 {
+    fn erase(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<T: Erase> Erase for [T] {
+    fn erase(&mut self) {
+        for value in self.iter_mut() {
+            value.erase();
+        }
+    }
+}
+
+impl<T: Erase> Erase for Vec<T> {
+    fn erase(&mut self) {
+        self.as_mut_slice().erase();
+        // Values a truncation left past the length
+        self.spare_capacity_mut().zeroize();
+    }
+}
+
+impl<F: IsField> Erase for Polynomial<FieldElement<F>> {
+    fn erase(&mut self) {
+        self.coefficients.erase();
+    }
+}
+
+/// Holds secret-bearing values (sharing polynomials, recovered secrets). The value is erased when
+/// dropped and never shows up in `Debug` output. Read it through `Deref`.
+pub struct Secret<T: Erase>(T);
+
+impl<T: Erase> Secret<T> {
+    pub fn new(value: T) -> Self {
+        Secret(value)
+    }
+}
+
+impl<T: Erase> Deref for Secret<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Erase> DerefMut for Secret<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Erase> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.erase();
+    }
+}
+
+impl<T: Erase> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(<redacted>)")
+    }
+}
+
+impl<T: Erase + Clone> Clone for Secret<T> {
+    fn clone(&self) -> Self {
+        Secret(self.0.clone())
+    }
+}
+
+impl<T: Erase + PartialEq> PartialEq for Secret<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Erase + PartialEq> PartialEq<T> for Secret<T> {
+    fn eq(&self, other: &T) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Erase, LargeField, Secret, ShamirSecretSharing};
+
+    #[test]
+    fn secrets_and_shares_are_redacted() {
+        let sss = ShamirSecretSharing::new(2, 3).unwrap();
+        let secret = LargeField::from(987654321u64);
+        let polynomial = sss.sample_polynomial(secret);
+        let shares = sss.generating_shares(&polynomial).unwrap();
+
+        let printed = format!("{:?} {:?} {:?}", polynomial, shares, sss.recover(&shares).unwrap());
+        assert!(!printed.contains(&format!("{:?}", secret)));
+        assert!(!printed.contains(&format!("{:?}", shares[0].value)));
+        assert!(printed.contains("<redacted>"));
+        assert_eq!(Secret::new(secret), secret);
+    }
+
+    #[test]
+    fn erase_zeroes_values() {
+        let mut coefficients = vec![LargeField::from(5u64), LargeField::from(7u64)];
+        coefficients.erase();
+        assert_eq!(coefficients, vec![LargeField::zero(); 2]);
+
+        let mut bytes = b"password".to_vec();
+        bytes.erase();
+        assert_eq!(bytes, vec![0u8; 8]);
+
+        // Bytes a truncation left in the capacity are wiped too
+        let mut bytes = b"password".to_vec();
+        bytes.truncate(2);
+        bytes.erase();
+        assert_eq!(bytes, vec![0u8; 2]);
+        unsafe { bytes.set_len(8) };
+        assert_eq!(bytes, vec![0u8; 8]);
+    }
+}
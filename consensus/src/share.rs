@@ -0,0 +1,332 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, IsField, IsPrimeField};
+use lambdaworks_math::traits::ByteConversion;
+use num_bigint_dig::{BigInt, Sign};
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Erase, SssError};
+
+/// The evaluation domain a share was produced on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Scheme {
+    /// `ShamirSecretSharing`: party i holds P(i).
+    Shamir,
+    /// `ShamirSecretSharingFFT`: party i holds P(w^(i-1)), where w generates the
+    /// roots of unity of order `share_amount.next_power_of_two()`.
+    ShamirFFT { share_amount: usize },
+    /// `PackedSecretSharing`: same share points as `ShamirFFT`, the polynomial carries `secret_amount`
+    /// secrets on a coset of the roots of unity.
+    Packed {
+        share_amount: usize,
+        secret_amount: usize,
+    },
+}
+
+/// A single party's share of a secret. `index` is the 1-based party id; the
+/// x-coordinate of the share is derived from it through the `scheme`.
+#[derive(Clone)]
+pub struct Share<F: IsField = Stark252PrimeField> {
+    pub index: u64,
+    pub value: FieldElement<F>,
+    /// the number of shares needed to recover the secret.
+    pub threshold: usize,
+    pub scheme: Scheme,
+}
+
+impl<F: IsField> Drop for Share<F> {
+    fn drop(&mut self) {
+        self.value.erase();
+    }
+}
+
+impl<F: IsField> fmt::Debug for Share<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Share")
+            .field("index", &self.index)
+            .field("value", &"<redacted>")
+            .field("threshold", &self.threshold)
+            .field("scheme", &self.scheme)
+            .finish()
+    }
+}
+
+// Field markers don't implement PartialEq, so compare the fields directly instead of deriving
+impl<F: IsField> PartialEq for Share<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+            && self.value == other.value
+            && self.threshold == other.threshold
+            && self.scheme == other.scheme
+    }
+}
+
+impl<F: IsFFTField> Share<F> {
+    /// The point this share was evaluated at.
+    pub fn x(&self) -> FieldElement<F> {
+        self.scheme.point(self.index)
+    }
+}
+
+impl Scheme {
+    /// The point party `index` evaluates the sharing polynomial at.
+    pub fn point<F: IsFFTField>(&self, index: u64) -> FieldElement<F> {
+        match self {
+            Scheme::Shamir => FieldElement::from(index),
+            Scheme::ShamirFFT { share_amount } | Scheme::Packed { share_amount, .. } => {
+                let order = share_amount.next_power_of_two().trailing_zeros() as u64;
+                F::get_primitive_root_of_unity(order).unwrap().pow(index - 1)
+            }
+        }
+    }
+
+    /// Rejects indices that don't name one of the scheme's parties: an index at or above the modulus
+    /// collides with a smaller one, and the FFT domain only has `share_amount` share points. The domain
+    /// itself must exist in the field, or `point` would have no root of unity to take.
+    pub(crate) fn check_index<F: IsFFTField + IsPrimeField>(&self, index: u64) -> Result<(), SssError> {
+        if index == 0 {
+            return Err(SssError::ZeroIndex);
+        }
+        let in_domain = match self {
+            Scheme::Shamir => below_modulus::<F>(index),
+            Scheme::ShamirFFT { share_amount } | Scheme::Packed { share_amount, .. } => {
+                let order = share_amount.checked_next_power_of_two().map(|size| size.trailing_zeros() as u64);
+                if order.is_none_or(|order| order > F::TWO_ADICITY) {
+                    return Err(SssError::UnsupportedFftSize(*share_amount));
+                }
+                index <= *share_amount as u64
+            }
+        };
+        if in_domain {
+            Ok(())
+        } else {
+            Err(SssError::InvalidIndex(index))
+        }
+    }
+}
+
+/// The bit length of p - 1.
+pub(crate) fn modulus_bits<F: IsPrimeField>() -> usize {
+    let mut max = F::modulus_minus_one();
+    let mut bits = 0;
+    while max != F::RepresentativeType::from(0) {
+        max >>= 1;
+        bits += 1;
+    }
+    bits
+}
+
+// With B the bit length of p - 1, an index of B bits is below 2p and only reduces if it is at least p
+fn below_modulus<F: IsPrimeField>(index: u64) -> bool {
+    let bits = modulus_bits::<F>();
+    let index_bits = 64 - index.leading_zeros() as usize;
+    if index_bits != bits {
+        return index_bits < bits;
+    }
+    F::representative(&F::from_u64(index)) >> (bits - 1) != F::RepresentativeType::from(0)
+}
+
+/// Checks shares received from other parties before they are interpolated: at least `threshold` of them,
+/// all from the recovering instance's scheme and threshold, at distinct points inside the domain.
+pub(crate) fn check_shares<F: IsFFTField + IsPrimeField>(
+    shares: &[Share<F>],
+    scheme: Scheme,
+    threshold: usize,
+) -> Result<(), SssError> {
+    if shares.len() < threshold || shares.is_empty() {
+        return Err(SssError::NotEnoughShares(threshold.max(1), shares.len()));
+    }
+    let mut indices = HashSet::new();
+    for share in shares {
+        // A share of another split would be interpolated silently into a wrong secret
+        if share.scheme != scheme || share.threshold != threshold {
+            return Err(SssError::Incompatible);
+        }
+        scheme.check_index::<F>(share.index)?;
+        if !indices.insert(share.index) {
+            return Err(SssError::DuplicateIndex(share.index));
+        }
+    }
+    Ok(())
+}
+
+/// Canonical big-endian encoding of a field element.
+pub fn field_to_bytes<F: IsField>(value: &FieldElement<F>) -> Vec<u8>
+where
+    FieldElement<F>: ByteConversion,
+{
+    value.to_bytes_be()
+}
+
+/// Decodes a canonical big-endian encoding, rejecting wrong lengths and integers that are not below the modulus.
+pub fn field_from_bytes<F: IsField>(bytes: &[u8]) -> Result<FieldElement<F>, SssError>
+where
+    FieldElement<F>: ByteConversion,
+{
+    // p - 1 is the largest canonical encoding
+    let max = (-FieldElement::<F>::one()).to_bytes_be();
+    if bytes.len() != max.len() {
+        return Err(SssError::InvalidLength(max.len(), bytes.len()));
+    }
+    // Equal-length big-endian byte strings compare like the integers they encode
+    if bytes > &max[..] {
+        return Err(SssError::OutOfRange);
+    }
+    FieldElement::<F>::from_bytes_be(bytes).map_err(|_| SssError::OutOfRange)
+}
+
+/// Converts a non-negative BigInt below the modulus, as used by `LargeFieldSSS`, to a field element.
+pub(crate) fn bigint_to_field<F: IsField>(value: &BigInt) -> Result<FieldElement<F>, SssError>
+where
+    FieldElement<F>: ByteConversion,
+{
+    let (sign, bytes) = value.to_bytes_be();
+    let width = (-FieldElement::<F>::one()).to_bytes_be().len();
+    if sign == Sign::Minus || bytes.len() > width {
+        return Err(SssError::OutOfRange);
+    }
+    let mut padded = vec![0u8; width - bytes.len()];
+    padded.extend_from_slice(&bytes);
+    field_from_bytes(&padded)
+}
+
+// Wire format of a share, the value is kept in its canonical encoding
+#[derive(Serialize, Deserialize)]
+struct ShareRepr {
+    index: u64,
+    value: Vec<u8>,
+    threshold: usize,
+    scheme: Scheme,
+}
+
+impl<F: IsFFTField + IsPrimeField> Share<F>
+where
+    FieldElement<F>: ByteConversion,
+{
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("Failed to serialize share")
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, bincode::Error> {
+        bincode::deserialize(bytes)
+    }
+}
+
+impl<F: IsField> Serialize for Share<F>
+where
+    FieldElement<F>: ByteConversion,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ShareRepr {
+            index: self.index,
+            value: field_to_bytes(&self.value),
+            threshold: self.threshold,
+            scheme: self.scheme,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: IsFFTField + IsPrimeField> Deserialize<'de> for Share<F>
+where
+    FieldElement<F>: ByteConversion,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = ShareRepr::deserialize(deserializer)?;
+        repr.scheme.check_index::<F>(repr.index).map_err(D::Error::custom)?;
+        if repr.threshold == 0 {
+            return Err(D::Error::custom(SssError::ZeroThreshold));
+        }
+        let value = field_from_bytes(&repr.value).map_err(D::Error::custom)?;
+        Ok(Share {
+            index: repr.index,
+            value,
+            threshold: repr.threshold,
+            scheme: repr.scheme,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{field_from_bytes, LargeField, Scheme, ShamirSecretSharing, ShamirSecretSharingFFT, Share, SssError};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+    use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+
+    #[test]
+    fn share_roundtrips_through_bytes() {
+        let secret = LargeField::from(1234u64);
+        let sss = ShamirSecretSharingFFT::new(3, 6).unwrap();
+        let shares = sss.split(secret).unwrap();
+
+        let decoded: Vec<Share> = shares
+            .iter()
+            .map(|share| Share::from_bytes(&share.to_bytes()).unwrap())
+            .collect();
+        assert_eq!(decoded, shares);
+        assert_eq!(sss.recover(&decoded[2..5]).unwrap(), secret);
+
+        let sss = ShamirSecretSharing::<Babybear31PrimeField>::new(2, 4).unwrap();
+        let shares = sss.split(FieldElement::from(7u64)).unwrap();
+        let decoded = Share::<Babybear31PrimeField>::from_bytes(&shares[3].to_bytes()).unwrap();
+        assert_eq!(decoded, shares[3]);
+    }
+
+    #[test]
+    fn out_of_range_encoding_is_rejected() {
+        // 2^256 - 1 is above the Stark 252 modulus
+        assert_eq!(
+            field_from_bytes::<Stark252PrimeField>(&[0xff; 32]),
+            Err(SssError::OutOfRange)
+        );
+        assert_eq!(
+            field_from_bytes::<Stark252PrimeField>(&[0u8; 31]),
+            Err(SssError::InvalidLength(32, 31))
+        );
+
+        // p itself is not a canonical encoding of zero
+        let p_minus_one = (-LargeField::one()).to_bytes_be();
+        let mut p = p_minus_one;
+        *p.last_mut().unwrap() += 1;
+        assert_eq!(field_from_bytes::<Stark252PrimeField>(&p), Err(SssError::OutOfRange));
+        assert_eq!(field_from_bytes(&p_minus_one), Ok(-LargeField::one()));
+    }
+
+    #[test]
+    fn share_with_out_of_range_value_fails_to_deserialize() {
+        let sss = ShamirSecretSharing::new(2, 3).unwrap();
+        let share = sss.split(LargeField::from(5u64)).unwrap().remove(0);
+        let mut bytes = share.to_bytes();
+        // bincode layout: index (8 bytes), value length (8 bytes), value (32 bytes), ...
+        for b in bytes[16..48].iter_mut() {
+            *b = 0xff;
+        }
+        let decoded: Result<Share, _> = Share::from_bytes(&bytes);
+        assert!(decoded.is_err());
+    }
+
+    #[test]
+    fn shares_outside_the_recovering_scheme_are_rejected() {
+        // BabyBear has roots of unity up to order 2^24, a peer can't name a larger domain
+        let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(2, 4).unwrap();
+        let mut share = sss.split(FieldElement::from(3u64)).unwrap().remove(0);
+        share.scheme = Scheme::ShamirFFT { share_amount: 1 << 40 };
+        assert!(Share::<Babybear31PrimeField>::from_bytes(&share.to_bytes()).is_err());
+
+        // Shares of another scheme or threshold don't reach the interpolation
+        let shares = sss.split(FieldElement::from(3u64)).unwrap();
+        let mut forged = shares.clone();
+        forged[1].scheme = Scheme::ShamirFFT { share_amount: 1 << 40 };
+        assert_eq!(sss.recover(&forged), Err(SssError::Incompatible));
+        let mut forged = shares.clone();
+        forged[1].threshold = 3;
+        assert_eq!(sss.recover(&forged), Err(SssError::Incompatible));
+        let shamir = ShamirSecretSharing::<Babybear31PrimeField>::new(2, 4).unwrap();
+        assert_eq!(shamir.recover(&shares), Err(SssError::Incompatible));
+    }
+}
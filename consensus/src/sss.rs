@@ -1,60 +1,129 @@
+use std::marker::PhantomData;
+
 use lambdaworks_math::field::element::FieldElement;
 use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
 use lambdaworks_math::polynomial::Polynomial;
 use lambdaworks_math::traits::ByteConversion;
-use lambdaworks_math::unsigned_integer::element::UnsignedInteger;
 use num_bigint_dig::BigInt;
-use rand;
-use rand::random;
+use rand_core::{CryptoRng, OsRng, RngCore};
+
+use crate::{
+    bigint_to_field, bytes_to_limbs, check_shares, decode_shares, evaluate_at_points, interpolate_points,
+    limbs_to_bytes, modulus_bits, ByteShare, Erase, Reconstructor, Scheme, Secret, Share, SssError,
+};
 
 pub type LargeField = FieldElement<Stark252PrimeField>;
 
+/// Shamir's Secret Sharing over any FFT-friendly lambdaworks field. Party i receives P(i) for i in 1..=n.
+/// Defaults to the Stark 252 prime field.
 #[derive(Clone, Debug)]
-pub struct ShamirSecretSharing {
+pub struct ShamirSecretSharing<F: IsFFTField = Stark252PrimeField> {
     /// the threshold of shares to recover the secret.
     pub threshold: usize,
     /// the total number of shares to generate from the secret.
     pub share_amount: usize,
+    _field: PhantomData<F>,
 }
 
-impl ShamirSecretSharing {
-    pub fn new(threshold: usize, share_amount: usize) -> Self {
-        ShamirSecretSharing {
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharing<F> {
+    pub fn new(threshold: usize, share_amount: usize) -> Result<Self, SssError> {
+        if threshold == 0 || threshold > share_amount {
+            return Err(SssError::InvalidThreshold(threshold, share_amount));
+        }
+        Ok(ShamirSecretSharing {
             threshold,
             share_amount,
-        }
+            _field: PhantomData,
+        })
+    }
+
+    /// Uniformly random field element from the OS RNG.
+    pub fn rand_field_element() -> FieldElement<F> {
+        Self::rand_field_element_with_rng(&mut OsRng)
     }
-    pub fn rand_field_element() -> LargeField {
-        let rand_big = UnsignedInteger { limbs: random() };
-        LargeField::new(rand_big)
+
+    /// Uniformly random field element by rejection sampling: draw as many random bits as p - 1 has, in
+    /// 64-bit limbs, and retry until the integer is below the modulus. Every try succeeds with probability
+    /// above 1/2.
+    pub fn rand_field_element_with_rng<R: CryptoRng + RngCore>(rng: &mut R) -> FieldElement<F> {
+        let bits = modulus_bits::<F>();
+        let limbs = bits.div_ceil(64);
+        let top_bits = bits - 64 * (limbs - 1);
+        // 2^64 in the field
+        let base = FieldElement::<F>::from(u64::MAX) + FieldElement::<F>::one();
+        loop {
+            let mut top = 0u64;
+            let mut element = FieldElement::<F>::zero();
+            for i in 0..limbs {
+                let mut limb = rng.next_u64();
+                if i == 0 && top_bits < 64 {
+                    limb &= (1 << top_bits) - 1;
+                }
+                if i == 0 {
+                    top = limb;
+                }
+                element = element * &base + FieldElement::<F>::from(limb);
+            }
+            // Below 2^(bits - 1) the integer is below p. Above, it is below 2p, and it reduces to something
+            // under 2^(bits - 1) exactly when it is not below p.
+            let high = (top >> (top_bits - 1)) & 1 == 1;
+            if !high || element.representative() >> (bits - 1) != F::RepresentativeType::from(0) {
+                return element;
+            }
+        }
     }
 
     /// Generates coefficients for a polynomial of degree `threshold - 1` such that the constant term is the secret.
-    pub fn sample_polynomial(&self, secret: LargeField) -> Polynomial<LargeField> {
+    pub fn sample_polynomial(&self, secret: FieldElement<F>) -> Secret<Polynomial<FieldElement<F>>> {
+        self.sample_polynomial_with_rng(secret, &mut OsRng)
+    }
+
+    /// Same as `sample_polynomial`, with the random coefficients drawn from `rng`.
+    pub fn sample_polynomial_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        secret: FieldElement<F>,
+        rng: &mut R,
+    ) -> Secret<Polynomial<FieldElement<F>>> {
         let threshold = self.threshold;
-        let mut coefficients: Vec<LargeField> = Vec::new();
+        let mut coefficients: Vec<FieldElement<F>> = Vec::with_capacity(threshold);
         // first element is the secret
         coefficients.push(secret);
         for _ in 0..threshold - 1 {
-            coefficients.push(Self::rand_field_element());
+            coefficients.push(Self::rand_field_element_with_rng(rng));
         }
 
-        Polynomial::new(&coefficients[..])
+        let polynomial = Secret::new(Polynomial::new(&coefficients[..]));
+        coefficients.erase();
+        polynomial
     }
 
-    // Generating vector of starkfield elements rather than shares for now since we aren't generating random X values
-    pub fn generating_shares(&self, polynomial: &Polynomial<LargeField>) -> Vec<LargeField> {
-        let mut shares: Vec<LargeField> = Vec::new();
+    /// Evaluations of the polynomial at the party points 1..=n
+    pub fn evaluations(&self, polynomial: &Polynomial<FieldElement<F>>) -> Vec<FieldElement<F>> {
+        let x: Vec<FieldElement<F>> = (1..self.share_amount as u64 + 1).map(FieldElement::from).collect();
+        // Horner at every point, or the subproduct tree once n reaches FAST_POLY_THRESHOLD
+        evaluate_at_points(polynomial, &x)
+    }
 
-        for i in 1..self.share_amount + 1 {
-            let x = LargeField::from(i as u64);
-            let y = polynomial.evaluate(&x);
-            shares.push(y);
+    /// The shares of parties 1..=n, fails if the polynomial has degree `threshold` or more.
+    pub fn generating_shares(&self, polynomial: &Polynomial<FieldElement<F>>) -> Result<Vec<Share<F>>, SssError> {
+        if polynomial.coefficients().len() > self.threshold {
+            return Err(SssError::DegreeTooHigh(self.threshold, polynomial.coefficients().len()));
         }
-        shares
+        Ok(self
+            .evaluations(polynomial)
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| Share {
+                index: i as u64 + 1,
+                value,
+                threshold: self.threshold,
+                scheme: Scheme::Shamir,
+            })
+            .collect())
     }
 
-    pub fn split(&self, secret: LargeField) -> Vec<LargeField> {
+    pub fn split(&self, secret: FieldElement<F>) -> Result<Vec<Share<F>>, SssError> {
         let polynomial = self.sample_polynomial(secret);
         self.generating_shares(&polynomial)
     }
@@ -67,65 +136,123 @@ impl ShamirSecretSharing {
     5. Unit test all above functions
      */
 
-    pub fn reconstructing(
-        &self,
-        x: &Vec<LargeField>,
-        y: &Vec<LargeField>,
-    ) -> Polynomial<LargeField> {
-        Polynomial::interpolate(&x, &y).unwrap()
+    /// Interpolates the sharing polynomial from the shares' own evaluation points.
+    pub fn reconstructing(&self, shares: &[Share<F>]) -> Result<Secret<Polynomial<FieldElement<F>>>, SssError> {
+        check_shares(shares, Scheme::Shamir, self.threshold)?;
+        let x: Vec<FieldElement<F>> = shares.iter().map(|share| share.x()).collect();
+        let mut y: Vec<FieldElement<F>> = shares.iter().map(|share| share.value.clone()).collect();
+        // Distinct indices can still meet modulo a small prime
+        let polynomial = interpolate_points(&x, &y).map(Secret::new);
+        y.erase();
+        polynomial.ok_or(SssError::SingularMatrix)
+    }
+
+    pub fn recover(&self, shares: &[Share<F>]) -> Result<Secret<FieldElement<F>>, SssError> {
+        let polynomial = self.reconstructing(shares)?;
+        // A zero polynomial has no coefficients
+        Ok(Secret::new(
+            polynomial
+                .coefficients()
+                .first()
+                .cloned()
+                .unwrap_or_else(FieldElement::zero),
+        ))
     }
 
-    pub fn recover(&self, polynomial: &Polynomial<LargeField>) -> LargeField {
-        polynomial.coefficients()[0].clone()
+    /// Recovers the secret even if some shares are corrupted, as long as at most (m - threshold) / 2 of
+    /// the m shares are wrong. Returns the secret and the indices of the shares that were wrong.
+    pub fn recover_robust(&self, shares: &[Share<F>]) -> Result<(Secret<FieldElement<F>>, Vec<u64>), SssError> {
+        let (polynomial, bad_indices) = decode_shares(shares, Scheme::Shamir, self.threshold)?;
+        let secret = polynomial
+            .coefficients()
+            .first()
+            .cloned()
+            .unwrap_or_else(FieldElement::zero);
+        Ok((Secret::new(secret), bad_indices))
     }
 
-    pub fn evaluate_at(&self, polynomial: &Polynomial<LargeField>, x: LargeField) -> LargeField {
+    pub fn evaluate_at(&self, polynomial: &Polynomial<FieldElement<F>>, x: FieldElement<F>) -> FieldElement<F> {
         polynomial.evaluate(&x)
     }
 }
 
 // Conversion functions
-impl ShamirSecretSharing {
+impl ShamirSecretSharing<Stark252PrimeField> {
     /// Temporary functions to convert a large field element to a BigInt. Get rid of this once the whole library is using Lambdaworks Math.
     pub fn lf_to_bigint(field_elem: &LargeField) -> BigInt {
         let bytes = field_elem.to_bytes_be();
         BigInt::from_signed_bytes_be(&bytes)
     }
 
-    pub fn bigint_to_lf(bigint: &BigInt) -> LargeField {
-        let bytes = bigint.to_signed_bytes_be();
-        FieldElement::<Stark252PrimeField>::from_bytes_be(&bytes).unwrap()
+    pub fn bigint_to_lf(bigint: &BigInt) -> Result<LargeField, SssError> {
+        bigint_to_field(bigint)
+    }
+}
+
+// Byte string secrets
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharing<F>
+where
+    FieldElement<F>: ByteConversion,
+{
+    /// Shares a byte string of any length, limb by limb, under one set of party indices.
+    pub fn split_bytes(&self, secret: &[u8]) -> Result<Vec<ByteShare<F>>, SssError> {
+        let limb_shares = bytes_to_limbs::<F>(secret)?
+            .into_iter()
+            .map(|limb| self.split(limb))
+            .collect::<Result<_, _>>()?;
+        Ok(ByteShare::deal(
+            limb_shares,
+            secret.len(),
+            self.threshold,
+            Scheme::Shamir,
+            self.share_amount,
+        ))
+    }
+
+    pub fn recover_bytes(&self, shares: &[ByteShare<F>]) -> Result<Secret<Vec<u8>>, SssError> {
+        let limb_shares = ByteShare::limb_shares(shares, self.threshold, Scheme::Shamir)?;
+        // Every limb is shared among the same parties, so the Lagrange weights are computed once
+        let indices: Vec<u64> = shares.iter().map(|share| share.index).collect();
+        let limbs = Reconstructor::new(&indices, Scheme::Shamir)?.recover_many(&limb_shares)?;
+        limbs_to_bytes(&limbs, shares[0].length)
     }
 }
 
-// Functions that will be needed for HACSS (High threshold asyncronous complete secret sharing)
+// Functions used by HACSS (High threshold asyncronous complete secret sharing), see hacss.rs
 
-impl ShamirSecretSharing {
-    pub fn fill_evaluation_at_all_points(&self, polynomial_evals: &mut Vec<LargeField>) {
-        let mut all_values = Vec::new();
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharing<F> {
+    pub fn fill_evaluation_at_all_points(&self, polynomial_evals: &mut Vec<FieldElement<F>>) -> Result<(), SssError> {
+        // Evaluations at 0, 1, ..., at least t of them and at most one per party plus the secret
+        if polynomial_evals.len() < self.threshold {
+            return Err(SssError::NotEnoughShares(self.threshold, polynomial_evals.len()));
+        }
+        if polynomial_evals.len() > self.share_amount + 1 {
+            return Err(SssError::WrongShareCount(self.share_amount + 1, polynomial_evals.len()));
+        }
+        let mut all_values = Vec::with_capacity(self.share_amount + 1);
 
-        // assert polynomial evals length = t + 1
         let mut x = Vec::new();
         for i in 0..polynomial_evals.len() {
-            x.push(LargeField::from(i as u64));
+            x.push(FieldElement::<F>::from(i as u64));
         }
-        let coeffs = self.reconstructing(&x, &polynomial_evals);
-        all_values.push(polynomial_evals[0]);
-        all_values.extend(self.generating_shares(&coeffs));
-        *polynomial_evals = all_values;
+        let coeffs = Secret::new(interpolate_points(&x, polynomial_evals).ok_or(SssError::SingularMatrix)?);
+        all_values.push(polynomial_evals[0].clone());
+        all_values.extend(self.evaluations(&coeffs));
+        std::mem::replace(polynomial_evals, all_values).erase();
+        Ok(())
     }
 
     pub fn add_polynomials(
-        poly1: &Polynomial<LargeField>,
-        poly2: &Polynomial<LargeField>,
-    ) -> Polynomial<LargeField> {
+        poly1: &Polynomial<FieldElement<F>>,
+        poly2: &Polynomial<FieldElement<F>>,
+    ) -> Polynomial<FieldElement<F>> {
         poly1 + poly2
     }
 
     pub fn multiply_polynomials(
-        poly1: &Polynomial<LargeField>,
-        poly2: &Polynomial<LargeField>,
-    ) -> Polynomial<LargeField> {
+        poly1: &Polynomial<FieldElement<F>>,
+        poly2: &Polynomial<FieldElement<F>>,
+    ) -> Polynomial<FieldElement<F>> {
         poly1 * poly2
     }
 }
@@ -134,33 +261,117 @@ impl ShamirSecretSharing {
 #[allow(non_snake_case)]
 mod tests {
 
-    use crate::ShamirSecretSharing;
+    use crate::{LargeField, ShamirSecretSharing, SssError};
+    use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
     use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
     use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+    use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
     use lambdaworks_math::unsigned_integer::element::UnsignedInteger;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    fn split_and_recover<F: IsFFTField + IsPrimeField>() {
+        let secret = FieldElement::<F>::from(1234u64);
+        let sss = ShamirSecretSharing::<F>::new(3, 6).unwrap();
+
+        let shares = sss.split(secret.clone()).unwrap();
+        let shares_to_use = vec![shares[1].clone(), shares[4].clone(), shares[5].clone()];
+        assert_eq!(sss.recover(&shares_to_use).unwrap(), secret);
+    }
+
+    #[test]
+    fn malformed_input_is_an_error() {
+        assert_eq!(ShamirSecretSharing::<Stark252PrimeField>::new(7, 6).unwrap_err(), SssError::InvalidThreshold(7, 6));
+        assert_eq!(ShamirSecretSharing::<Stark252PrimeField>::new(0, 6).unwrap_err(), SssError::InvalidThreshold(0, 6));
+
+        let sss = ShamirSecretSharing::new(3, 6).unwrap();
+        let shares = sss.split(LargeField::from(5u64)).unwrap();
+        assert_eq!(sss.recover(&shares[..2]), Err(SssError::NotEnoughShares(3, 2)));
+
+        let repeated = vec![shares[0].clone(), shares[1].clone(), shares[1].clone()];
+        assert_eq!(sss.recover(&repeated), Err(SssError::DuplicateIndex(2)));
+
+        let mut at_zero = shares[..3].to_vec();
+        at_zero[0].index = 0;
+        assert_eq!(sss.recover(&at_zero), Err(SssError::ZeroIndex));
+    }
+
+    #[test]
+    fn seeded_rng_makes_sampling_reproducible() {
+        let sss = ShamirSecretSharing::<Stark252PrimeField>::new(4, 8).unwrap();
+        let secret = FieldElement::<Stark252PrimeField>::from(1234u64);
+
+        let polynomial = sss.sample_polynomial_with_rng(secret, &mut ChaCha20Rng::seed_from_u64(7));
+        assert_eq!(polynomial, sss.sample_polynomial_with_rng(secret, &mut ChaCha20Rng::seed_from_u64(7)));
+        assert_ne!(polynomial, sss.sample_polynomial_with_rng(secret, &mut ChaCha20Rng::seed_from_u64(8)));
+
+        let shares = sss.generating_shares(&polynomial).unwrap();
+        assert_eq!(sss.recover(&shares[3..7]).unwrap(), secret);
+    }
+
+    #[test]
+    fn rejection_sampling_is_uniform_over_babybear() {
+        // Babybear has 31 bit elements, so half of the samples should have the top bit set
+        // and every residue mod 4 should show up about as often
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+        let mut buckets = [0usize; 4];
+        let mut high = 0usize;
+        let samples = 20000;
+        for _ in 0..samples {
+            let x = ShamirSecretSharing::<Babybear31PrimeField>::rand_field_element_with_rng(&mut rng).representative().limbs[0];
+            buckets[(x % 4) as usize] += 1;
+            if x >= (1 << 30) {
+                high += 1;
+            }
+        }
+        for bucket in buckets {
+            assert!((4500..5500).contains(&bucket));
+        }
+        // Elements above 2^30 make up (p - 2^30) / p of the field
+        let expected_high = samples as f64 * (2013265921.0 - 1073741824.0) / 2013265921.0;
+        assert!((high as f64 - expected_high).abs() < 500.0);
+
+        // Same over the four limbs of BLS12-381 Fr, where about 45% of the elements are above 2^254
+        let samples = 4000;
+        let high = (0..samples)
+            .filter(|_| {
+                let x = ShamirSecretSharing::<FrField>::rand_field_element_with_rng(&mut rng).representative();
+                x.limbs[0] >> 62 == 1
+            })
+            .count();
+        let r_top = 0x73eda753u64 as f64 / (1u64 << 32) as f64;
+        let expected_high = samples as f64 * (r_top - 0.25) / r_top;
+        assert!((high as f64 - expected_high).abs() < 150.0);
+    }
+
+    #[test]
+    fn shamir_secret_sharing_works_over_babybear() {
+        split_and_recover::<Babybear31PrimeField>();
+    }
+
+    #[test]
+    fn shamir_secret_sharing_works_over_bls12_381_scalar_field() {
+        split_and_recover::<FrField>();
+    }
 
     #[test]
     fn shamir_secret_sharing_works() {
         type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
         let secret = LargeField::new(UnsignedInteger::from(1234u64));
 
-        let sss = ShamirSecretSharing {
-            share_amount: 6,
-            threshold: 3,
-        };
+        let sss = ShamirSecretSharing::new(3, 6).unwrap();
 
         let polynomial = sss.sample_polynomial(secret);
-        let shares = sss.generating_shares(&polynomial);
+        let shares = sss.generating_shares(&polynomial).unwrap();
+        assert_eq!(shares[2].index, 3);
+        assert_eq!(shares[2].threshold, 3);
 
-        let shares_to_use_x = vec![
-            LargeField::new(UnsignedInteger::from(1u64)),
-            LargeField::new(UnsignedInteger::from(3u64)),
-            LargeField::new(UnsignedInteger::from(4u64)),
-        ];
-        let shares_to_use_y = vec![shares[0], shares[2], shares[3]];
-        let poly_2 = sss.reconstructing(&shares_to_use_x, &shares_to_use_y);
-        let secret_recovered = sss.recover(&poly_2);
-        assert_eq!(secret, secret_recovered);
+        let shares_to_use = vec![shares[0].clone(), shares[2].clone(), shares[3].clone()];
+        let poly_2 = sss.reconstructing(&shares_to_use).unwrap();
+        assert_eq!(polynomial, poly_2);
+        let secret_recovered = sss.recover(&shares_to_use).unwrap();
+        assert_eq!(secret, *secret_recovered);
     }
 
     #[test]
@@ -168,18 +379,15 @@ mod tests {
         type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
         let secret = LargeField::new(UnsignedInteger::from(1234u64));
 
-        let sss = ShamirSecretSharing {
-            share_amount: 6,
-            threshold: 3,
-        };
+        let sss = ShamirSecretSharing::new(3, 6).unwrap();
 
         // generate polynomial, generate shares, then create a new vector with the first t+1 shares and the secret, and then verify that its equal to the shares polynomial after fill evals at all points
         let polynomial = sss.sample_polynomial(secret);
-        let shares = sss.generating_shares(&polynomial);
+        let shares = sss.evaluations(&polynomial);
         let mut shares_to_use = Vec::new();
         shares_to_use.push(secret);
         shares_to_use.extend(shares[0..sss.threshold + 1].to_vec());
-        sss.fill_evaluation_at_all_points(&mut shares_to_use);
+        sss.fill_evaluation_at_all_points(&mut shares_to_use).unwrap();
         // assert first element of shares_to_use is equal to secret
         assert_eq!(shares_to_use[0], secret);
         // remove shares_to_use[0]
@@ -1,159 +1,345 @@
+use std::collections::HashSet;
+
 use lambdaworks_math::fft::cpu::roots_of_unity::get_powers_of_primitive_root;
 use lambdaworks_math::field::element::FieldElement;
 use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
-use lambdaworks_math::field::traits::RootsConfig;
+use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField, RootsConfig};
 use lambdaworks_math::polynomial::Polynomial;
 use lambdaworks_math::traits::ByteConversion;
-use lambdaworks_math::unsigned_integer::element::UnsignedInteger;
 use num_bigint_dig::BigInt;
-use rand;
-use rand::random;
+use rand_core::{CryptoRng, OsRng, RngCore};
+use crate::{
+    bigint_to_field, bytes_to_limbs, check_shares, decode_shares, erasure_decode, limbs_to_bytes, ByteShare, Erase,
+    FftPlan, Reconstructor, Scheme, Secret, ShamirSecretSharing, Share, SssError,
+};
 type LargeField = FieldElement<Stark252PrimeField>;
-use lambdaworks_math::fft::polynomial;
 
 /**
  * Shamir's Secret Sharing Scheme using Fast Fourier Transform
  * Send evaluation at P(w^i-1) to party i
  * Secret stored at P(0)
+ * Generic over any FFT-friendly field, defaults to Stark 252
  */
 
 #[derive(Clone, Debug)]
-pub struct ShamirSecretSharingFFT {
+pub struct ShamirSecretSharingFFT<F: IsFFTField = Stark252PrimeField> {
     /// the threshold of shares to recover the secret.
     pub threshold: usize,
     /// the total number of shares to generate from the secret.
     pub share_amount: usize,
-    pub roots_of_unity: Vec<LargeField>,
+    /// the roots of unity and their twiddles, shared by dealing and reconstruction
+    plan: FftPlan<F>,
 }
 
-impl ShamirSecretSharingFFT {
-    pub fn new(threshold: usize, share_amount: usize) -> Self {
-        ShamirSecretSharingFFT {
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharingFFT<F> {
+    pub fn new(threshold: usize, share_amount: usize) -> Result<Self, SssError> {
+        if threshold == 0 || threshold > share_amount {
+            return Err(SssError::InvalidThreshold(threshold, share_amount));
+        }
+        let plan = FftPlan::new(share_amount)?;
+        Ok(ShamirSecretSharingFFT {
             threshold,
             share_amount,
-            roots_of_unity: Self::gen_roots_of_unity(share_amount),
-        }
+            plan,
+        })
     }
-    pub fn rand_field_element() -> LargeField {
-        let rand_big = UnsignedInteger { limbs: random() };
-        LargeField::new(rand_big)
+    pub fn rand_field_element() -> FieldElement<F> {
+        ShamirSecretSharing::<F>::rand_field_element()
     }
 
-    pub fn gen_roots_of_unity(n: usize) -> Vec<LargeField> {
+    pub fn rand_field_element_with_rng<R: CryptoRng + RngCore>(rng: &mut R) -> FieldElement<F> {
+        ShamirSecretSharing::<F>::rand_field_element_with_rng(rng)
+    }
+
+    /// The powers of a primitive root of unity of order n.next_power_of_two(), fails if the field's
+    /// two-adicity is too small for that.
+    pub fn gen_roots_of_unity(n: usize) -> Result<Vec<FieldElement<F>>, SssError> {
         let len = n.next_power_of_two();
         let order = len.trailing_zeros();
-        get_powers_of_primitive_root(order.into(), len, RootsConfig::Natural).unwrap()
+        get_powers_of_primitive_root::<F>(order.into(), len, RootsConfig::Natural)
+            .map_err(|_| SssError::UnsupportedFftSize(len))
     }
 
     /// Generates coefficients for a polynomial of degree `threshold - 1` such that the constant term is the secret.
-    pub fn sample_polynomial(&self, secret: LargeField) -> Polynomial<LargeField> {
+    pub fn sample_polynomial(&self, secret: FieldElement<F>) -> Secret<Polynomial<FieldElement<F>>> {
+        self.sample_polynomial_with_rng(secret, &mut OsRng)
+    }
+
+    /// Same as `sample_polynomial`, with the random coefficients drawn from `rng`.
+    pub fn sample_polynomial_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        secret: FieldElement<F>,
+        rng: &mut R,
+    ) -> Secret<Polynomial<FieldElement<F>>> {
         let threshold = self.threshold;
-        let mut coefficients: Vec<LargeField> = Vec::new();
+        let mut coefficients: Vec<FieldElement<F>> = Vec::with_capacity(threshold);
         // first element is the secret
         coefficients.push(secret);
         for _ in 0..threshold - 1 {
-            coefficients.push(Self::rand_field_element());
+            coefficients.push(Self::rand_field_element_with_rng(rng));
         }
 
-        Polynomial::new(&coefficients[..])
+        let polynomial = Secret::new(Polynomial::new(&coefficients[..]));
+        coefficients.erase();
+        polynomial
     }
 
-    // Generating vector of starkfield elements rather than shares for now since we aren't generating random X values
-    pub fn generating_shares(&self, polynomial: &Polynomial<LargeField>) -> Vec<LargeField> {
-        Polynomial::evaluate_fft::<Stark252PrimeField>(&polynomial, 1, Some(self.share_amount))
-            .unwrap()
+    /// The precomputed transforms for the domain of the roots of unity.
+    pub fn plan(&self) -> &FftPlan<F> {
+        &self.plan
     }
 
-    pub fn split(&self, secret: LargeField) -> Vec<LargeField> {
+    /// Evaluations at the whole domain w^0, ..., w^(N-1), the first n of them go to the parties.
+    pub fn evaluations(&self, polynomial: &Polynomial<FieldElement<F>>) -> Vec<FieldElement<F>> {
+        self.plan.forward(polynomial.coefficients())
+    }
+
+    /// The shares of parties 1..=n, fails if the polynomial has degree `threshold` or more.
+    pub fn generating_shares(&self, polynomial: &Polynomial<FieldElement<F>>) -> Result<Vec<Share<F>>, SssError> {
+        if polynomial.coefficients().len() > self.threshold {
+            return Err(SssError::DegreeTooHigh(self.threshold, polynomial.coefficients().len()));
+        }
+        Ok(self.to_shares(&self.evaluations(polynomial)))
+    }
+
+    pub fn split(&self, secret: FieldElement<F>) -> Result<Vec<Share<F>>, SssError> {
         let polynomial = self.sample_polynomial(secret);
         self.generating_shares(&polynomial)
     }
 
-    pub fn reconstructing(
+    /// Splits every secret, `shares[k]` holds the shares of `secrets[k]`.
+    pub fn split_many(&self, secrets: &[FieldElement<F>]) -> Vec<Vec<Share<F>>> {
+        self.split_many_with_rng(secrets, &mut OsRng)
+    }
+
+    /// Same as `split_many`, with the random coefficients drawn from `rng`. All polynomials go through one
+    /// buffer that is transformed in place with the precomputed plan.
+    pub fn split_many_with_rng<R: CryptoRng + RngCore>(
         &self,
-        x: &Vec<u64>, // Parties
-        y: &Vec<LargeField>,
-    ) -> Polynomial<LargeField> {
-        let mapped_x: Vec<LargeField> = x.iter()
-        .map(|xi| if *xi == 0 {
-            LargeField::zero()
-        } else {
-            self.roots_of_unity[(*xi - 1) as usize].clone()  
-        })
-        .collect();
+        secrets: &[FieldElement<F>],
+        rng: &mut R,
+    ) -> Vec<Vec<Share<F>>> {
+        let mut buffer = vec![FieldElement::<F>::zero(); self.plan.size()];
+        let sharings = secrets
+            .iter()
+            .map(|secret| {
+                buffer[0] = secret.clone();
+                for coefficient in buffer[1..self.threshold].iter_mut() {
+                    *coefficient = Self::rand_field_element_with_rng(rng);
+                }
+                for coefficient in buffer[self.threshold..].iter_mut() {
+                    *coefficient = FieldElement::zero();
+                }
+                self.plan.forward_in_place(&mut buffer);
+                self.to_shares(&buffer)
+            })
+            .collect();
+        buffer.erase();
+        sharings
+    }
 
-        Polynomial::interpolate(&mapped_x, &y).unwrap()
+    fn scheme(&self) -> Scheme {
+        Scheme::ShamirFFT {
+            share_amount: self.share_amount,
+        }
+    }
+
+    fn to_shares(&self, evaluations: &[FieldElement<F>]) -> Vec<Share<F>> {
+        evaluations
+            .iter()
+            .take(self.share_amount)
+            .enumerate()
+            .map(|(i, value)| Share {
+                index: i as u64 + 1,
+                value: value.clone(),
+                threshold: self.threshold,
+                scheme: self.scheme(),
+            })
+            .collect()
+    }
+
+    /// Interpolates the sharing polynomial, party i's share sits at w^(i-1).
+    pub fn reconstructing(&self, shares: &[Share<F>]) -> Result<Secret<Polynomial<FieldElement<F>>>, SssError> {
+        check_shares(shares, self.scheme(), self.threshold)?;
+        let x: Vec<u64> = shares.iter().map(|share| share.index).collect();
+        let mut y: Vec<FieldElement<F>> = shares.iter().map(|share| share.value.clone()).collect();
+        let polynomial = self.interpolate_on_domain(&x, &y).map(Secret::new);
+        y.erase();
+        polynomial
     }
 
-    pub fn recover(&self, polynomial: &Polynomial<LargeField>) -> LargeField {
-        polynomial.coefficients()[0].clone()
+    /// Interpolates evaluations given by party id, where id 0 stands for the point 0 and id i for w^(i-1).
+    pub fn interpolate_parties(
+        &self,
+        x: &[u64], // Parties
+        y: &[FieldElement<F>],
+    ) -> Result<Polynomial<FieldElement<F>>, SssError> {
+        if x.len() != y.len() {
+            return Err(SssError::WrongShareCount(x.len(), y.len()));
+        }
+        let mut seen = HashSet::new();
+        let mut mapped_x: Vec<FieldElement<F>> = Vec::with_capacity(x.len());
+        for &xi in x {
+            if !seen.insert(xi) {
+                return Err(SssError::DuplicateIndex(xi));
+            }
+            mapped_x.push(if xi == 0 {
+                FieldElement::zero()
+            } else {
+                self.plan
+                    .domain()
+                    .get((xi - 1) as usize)
+                    .cloned()
+                    .ok_or(SssError::InvalidIndex(xi))?
+            });
+        }
+
+        Polynomial::interpolate(&mapped_x, y).map_err(|_| SssError::SingularMatrix)
     }
 
-    pub fn evaluate_at(&self, polynomial: &Polynomial<LargeField>, x: LargeField) -> LargeField {
+    pub fn recover(&self, shares: &[Share<F>]) -> Result<Secret<FieldElement<F>>, SssError> {
+        let polynomial = self.reconstructing(shares)?;
+        // A zero polynomial has no coefficients
+        Ok(Secret::new(
+            polynomial
+                .coefficients()
+                .first()
+                .cloned()
+                .unwrap_or_else(FieldElement::zero),
+        ))
+    }
+
+    /// Recovers the secret even if some shares are corrupted, as long as at most (m - threshold) / 2 of
+    /// the m shares are wrong. Returns the secret and the indices of the shares that were wrong.
+    pub fn recover_robust(&self, shares: &[Share<F>]) -> Result<(Secret<FieldElement<F>>, Vec<u64>), SssError> {
+        let (polynomial, bad_indices) = decode_shares(shares, self.scheme(), self.threshold)?;
+        let secret = polynomial
+            .coefficients()
+            .first()
+            .cloned()
+            .unwrap_or_else(FieldElement::zero);
+        Ok((Secret::new(secret), bad_indices))
+    }
+
+    pub fn evaluate_at(&self, polynomial: &Polynomial<FieldElement<F>>, x: FieldElement<F>) -> FieldElement<F> {
         polynomial.evaluate(&x)
     }
 }
 
 // Conversion functions
-impl ShamirSecretSharingFFT {
+impl ShamirSecretSharingFFT<Stark252PrimeField> {
     /// Temporary functions to convert a large field element to a BigInt. Get rid of this once the whole library is using Lambdaworks Math.
     pub fn lf_to_bigint(field_elem: &LargeField) -> BigInt {
         let bytes = field_elem.to_bytes_be();
         BigInt::from_signed_bytes_be(&bytes)
     }
 
-    pub fn bigint_to_lf(bigint: &BigInt) -> LargeField {
-        let bytes = bigint.to_signed_bytes_be();
-        FieldElement::<Stark252PrimeField>::from_bytes_be(&bytes).unwrap()
+    pub fn bigint_to_lf(bigint: &BigInt) -> Result<LargeField, SssError> {
+        bigint_to_field(bigint)
+    }
+}
+
+// Byte string secrets
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharingFFT<F>
+where
+    FieldElement<F>: ByteConversion,
+{
+    /// Shares a byte string of any length, limb by limb, under one set of party indices.
+    pub fn split_bytes(&self, secret: &[u8]) -> Result<Vec<ByteShare<F>>, SssError> {
+        let limb_shares = bytes_to_limbs::<F>(secret)?
+            .into_iter()
+            .map(|limb| self.split(limb))
+            .collect::<Result<_, _>>()?;
+        Ok(ByteShare::deal(
+            limb_shares,
+            secret.len(),
+            self.threshold,
+            self.scheme(),
+            self.share_amount,
+        ))
+    }
+
+    pub fn recover_bytes(&self, shares: &[ByteShare<F>]) -> Result<Secret<Vec<u8>>, SssError> {
+        let scheme = self.scheme();
+        let limb_shares = ByteShare::limb_shares(shares, self.threshold, scheme)?;
+        // Every limb is shared among the same parties, so the Lagrange weights are computed once
+        let indices: Vec<u64> = shares.iter().map(|share| share.index).collect();
+        let limbs = Reconstructor::new(&indices, scheme)?.recover_many(&limb_shares)?;
+        limbs_to_bytes(&limbs, shares[0].length)
     }
 }
 
 // Functions that will be needed for HACSS (High threshold asyncronous complete secret sharing)
 
-impl ShamirSecretSharingFFT {
-    // Note that we expect polynomial_evaluations at points 0, w^0, w^1, ... w^(t), and not 0... t like we did before
-    // We return the polynomial evaluations at points 0, w^0, w^1, ... w^(n) where n is the share amount
-    // Note that we can only use this function when t+1 = 2^m
-    pub fn fill_evaluation_at_all_points_fft(&self, polynomial_evals: &mut Vec<LargeField>) {
-        let mut all_values = Vec::new();
-        all_values.push(polynomial_evals[0]);
-        polynomial_evals.remove(0);
-        let coeffs = Polynomial::interpolate_fft::<Stark252PrimeField>(&polynomial_evals).unwrap();
-        let evals = Polynomial::evaluate_fft::<Stark252PrimeField>(&coeffs, 1, Some(self.share_amount)).unwrap();
-        all_values.extend(evals);
-
-        while all_values.len() > self.share_amount+1 {
-            all_values.pop();
+impl<F: IsFFTField + IsPrimeField> ShamirSecretSharingFFT<F> {
+    /// Extends the evaluations at the first parties, P(w^0), ..., P(w^(k-1)) for any k >= t, to the evaluations
+    /// at all n parties P(w^0), ..., P(w^(n-1)). Unlike `fill_evaluation_at_all_points`, there is no slot for
+    /// the secret: `polynomial_evals[0]` is party 1's P(w^0), not P(0), and the output has n entries. Callers
+    /// that need the secret evaluate the interpolated polynomial at 0 or use `fill_evaluation_at_all_points`.
+    pub fn fill_evaluation_at_all_points_fft(&self, polynomial_evals: &mut Vec<FieldElement<F>>) -> Result<(), SssError> {
+        if polynomial_evals.len() < self.threshold {
+            return Err(SssError::NotEnoughShares(self.threshold, polynomial_evals.len()));
         }
-        *polynomial_evals = all_values; 
-    }
+        let parties: Vec<u64> = (1..polynomial_evals.len() as u64 + 1).collect();
+        let coeffs = Secret::new(self.interpolate_on_domain(&parties, polynomial_evals)?);
 
+        let mut evaluations = self.evaluations(&coeffs);
+        let mut all_values = Vec::with_capacity(self.share_amount);
+        all_values.extend_from_slice(&evaluations[..self.share_amount]);
+        evaluations.erase();
+        std::mem::replace(polynomial_evals, all_values).erase();
+        Ok(())
+    }
 
-    pub fn fill_evaluation_at_all_points(&self, polynomial_evals: &mut Vec<LargeField>) {
-        let mut all_values = Vec::new();
+    /// Interpolates the polynomial through the evaluations of any set of parties on the roots-of-unity domain,
+    /// in O(N log N) with the erasure decoder.
+    pub fn interpolate_on_domain(
+        &self,
+        parties: &[u64],
+        values: &[FieldElement<F>],
+    ) -> Result<Polynomial<FieldElement<F>>, SssError> {
+        // The decoder takes any position of the domain, but only the first n are parties
+        for &party in parties {
+            if party == 0 {
+                return Err(SssError::ZeroIndex);
+            }
+            if party > self.share_amount as u64 {
+                return Err(SssError::InvalidIndex(party));
+            }
+        }
+        let positions: Vec<usize> = parties.iter().map(|&party| party as usize - 1).collect();
+        erasure_decode(&self.plan, &positions, values)
+    }
 
-        // assert polynomial evals length = t + 1
+    pub fn fill_evaluation_at_all_points(&self, polynomial_evals: &mut Vec<FieldElement<F>>) -> Result<(), SssError> {
+        // Evaluations at the parties 0, 1, ..., at least t of them and at most one per party plus the secret
+        if polynomial_evals.len() < self.threshold {
+            return Err(SssError::NotEnoughShares(self.threshold, polynomial_evals.len()));
+        }
         let mut x = Vec::new();
         for i in 0..polynomial_evals.len() {
             x.push(i as u64);
         }
-        let coeffs = self.reconstructing(&x, &polynomial_evals);
-        all_values.push(polynomial_evals[0]);
-        all_values.extend(self.generating_shares(&coeffs));
-        *polynomial_evals = all_values;
+        let coeffs = Secret::new(self.interpolate_parties(&x, polynomial_evals)?);
+        let evaluations = self.evaluations(&coeffs);
+        let mut all_values = Vec::with_capacity(evaluations.len() + 1);
+        all_values.push(polynomial_evals[0].clone());
+        all_values.extend(evaluations);
+        std::mem::replace(polynomial_evals, all_values).erase();
+        Ok(())
     }
 
     pub fn add_polynomials(
-        poly1: &Polynomial<LargeField>,
-        poly2: &Polynomial<LargeField>,
-    ) -> Polynomial<LargeField> {
+        poly1: &Polynomial<FieldElement<F>>,
+        poly2: &Polynomial<FieldElement<F>>,
+    ) -> Polynomial<FieldElement<F>> {
         poly1 + poly2
     }
 
     pub fn multiply_polynomials(
-        poly1: &Polynomial<LargeField>,
-        poly2: &Polynomial<LargeField>,
-    ) -> Polynomial<LargeField> {
+        poly1: &Polynomial<FieldElement<F>>,
+        poly2: &Polynomial<FieldElement<F>>,
+    ) -> Polynomial<FieldElement<F>> {
         poly1 * poly2
     }
 }
@@ -162,30 +348,137 @@ impl ShamirSecretSharingFFT {
 #[allow(non_snake_case)]
 mod tests {
 
-    use crate::ShamirSecretSharingFFT;
+    use crate::{ShamirSecretSharingFFT, SssError};
+    use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
     use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
     use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+    use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
     use lambdaworks_math::unsigned_integer::element::UnsignedInteger;
 
+    fn split_and_recover_fft<F: IsFFTField + IsPrimeField>() {
+        let secret = FieldElement::<F>::from(1234u64);
+        let sss = ShamirSecretSharingFFT::<F>::new(4, 8).unwrap();
+
+        let shares = sss.split(secret.clone()).unwrap();
+        let shares_to_use: Vec<_> = [2usize, 5, 7, 8].iter().map(|i| shares[i - 1].clone()).collect();
+        assert_eq!(sss.recover(&shares_to_use).unwrap(), secret);
+    }
+
+    #[test]
+    fn shamir_secret_sharing_fft_works_over_babybear() {
+        split_and_recover_fft::<Babybear31PrimeField>();
+    }
+
+    #[test]
+    fn shamir_secret_sharing_fft_works_over_bls12_381_scalar_field() {
+        split_and_recover_fft::<FrField>();
+    }
+
+    #[test]
+    fn fft_domain_errors() {
+        // Babybear's FFT domains stop at 2^24
+        assert_eq!(
+            ShamirSecretSharingFFT::<Babybear31PrimeField>::new(2, 1 << 25).unwrap_err(),
+            SssError::UnsupportedFftSize(1 << 25)
+        );
+        let sss = ShamirSecretSharingFFT::<Stark252PrimeField>::new(3, 8).unwrap();
+        let mut evaluations = vec![FieldElement::<Stark252PrimeField>::from(1u64); 9];
+        assert_eq!(sss.fill_evaluation_at_all_points_fft(&mut evaluations), Err(SssError::InvalidIndex(9)));
+        assert_eq!(
+            sss.fill_evaluation_at_all_points(&mut evaluations[..2].to_vec()),
+            Err(SssError::NotEnoughShares(3, 2))
+        );
+        assert_eq!(
+            sss.interpolate_on_domain(&[0, 1, 2], &evaluations[..3]),
+            Err(SssError::ZeroIndex)
+        );
+        // Party 10 would be w^9, inside the domain of 16 but not one of the 9 parties
+        let sss = ShamirSecretSharingFFT::<Stark252PrimeField>::new(3, 9).unwrap();
+        assert_eq!(
+            sss.interpolate_on_domain(&[1, 2, 10], &evaluations[..3]),
+            Err(SssError::InvalidIndex(10))
+        );
+        assert_eq!(
+            sss.interpolate_on_domain(&[1, 2, 2], &evaluations[..3]),
+            Err(SssError::DuplicateIndex(2))
+        );
+    }
+
+    #[test]
+    fn fill_evaluation_at_all_points_fft_for_any_threshold() {
+        for (threshold, share_amount) in [(5, 12), (3, 8), (7, 7), (1, 3), (16, 32)] {
+            let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(threshold, share_amount).unwrap();
+            let polynomial = sss.sample_polynomial(FieldElement::from(99u64));
+            let mut shares = sss.evaluations(&polynomial);
+            shares.truncate(share_amount);
+
+            let mut evaluations = shares[..threshold].to_vec();
+            sss.fill_evaluation_at_all_points_fft(&mut evaluations).unwrap();
+            assert_eq!(evaluations, shares);
+
+            // Any parties on the domain determine the polynomial
+            let even = (2..=share_amount as u64).step_by(2);
+            let parties: Vec<u64> = even.chain((1..=share_amount as u64).step_by(2)).take(threshold).collect();
+            let values: Vec<_> = parties.iter().map(|&party| shares[party as usize - 1].clone()).collect();
+            assert_eq!(sss.interpolate_on_domain(&parties, &values).unwrap(), *polynomial);
+        }
+    }
+
+    #[test]
+    fn fill_evaluation_at_all_points_fft_has_no_secret_slot() {
+        let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(3, 6).unwrap();
+        let secret = FieldElement::<Babybear31PrimeField>::from(42u64);
+        let polynomial = sss.sample_polynomial(secret.clone());
+        let shares = sss.evaluations(&polynomial);
+
+        // The first entry is P(w^0) = P(1), both on the way in and on the way out
+        let mut evaluations = shares[..3].to_vec();
+        sss.fill_evaluation_at_all_points_fft(&mut evaluations).unwrap();
+        assert_eq!(evaluations.len(), 6);
+        assert_eq!(evaluations[0], polynomial.evaluate(&FieldElement::one()));
+        assert_eq!(evaluations[..], shares[..6]);
+
+        // Putting the secret first, as `fill_evaluation_at_all_points` expects, reads it as party 1's share
+        let mut with_secret = vec![secret.clone()];
+        with_secret.extend(shares[..3].iter().cloned());
+        let mut filled = with_secret.clone();
+        sss.fill_evaluation_at_all_points(&mut filled).unwrap();
+        assert_eq!(filled[0], secret);
+        assert_eq!(filled[1..7], shares[..6]);
+        sss.fill_evaluation_at_all_points_fft(&mut with_secret).unwrap();
+        assert_ne!(with_secret[..], shares[..6]);
+    }
+
+    #[test]
+    fn split_many_reuses_the_plan() {
+        let sss = ShamirSecretSharingFFT::<Babybear31PrimeField>::new(5, 12).unwrap();
+        let secrets: Vec<FieldElement<Babybear31PrimeField>> = (0..50u64).map(FieldElement::from).collect();
+        let sharings = sss.split_many(&secrets);
+
+        assert_eq!(sharings.len(), 50);
+        for (secret, shares) in secrets.iter().zip(sharings.iter()) {
+            assert_eq!(shares.len(), 12);
+            assert_eq!(sss.recover(&shares[7..]).unwrap(), *secret);
+        }
+    }
+
     #[test]
     fn shamir_secret_sharing_works() {
         type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
         let secret = LargeField::new(UnsignedInteger::from(1234u64));
 
-        let sss = ShamirSecretSharingFFT {
-            share_amount: 6,
-            threshold: 3,
-            roots_of_unity: ShamirSecretSharingFFT::gen_roots_of_unity(6),
-        };
+        let sss = ShamirSecretSharingFFT::new(3, 6).unwrap();
 
         let polynomial = sss.sample_polynomial(secret);
-        let shares = sss.generating_shares(&polynomial);
+        let shares = sss.generating_shares(&polynomial).unwrap();
+        assert_eq!(shares.len(), 6);
 
-        let shares_to_use_x = vec![1u64, 3u64, 4u64];
-        let shares_to_use_y = vec![shares[0], shares[2], shares[3]];
-        let poly_2 = sss.reconstructing(&shares_to_use_x, &shares_to_use_y);
-        let secret_recovered = sss.recover(&poly_2);
-        assert_eq!(secret, secret_recovered);
+        let shares_to_use = vec![shares[0].clone(), shares[2].clone(), shares[3].clone()];
+        let poly_2 = sss.reconstructing(&shares_to_use).unwrap();
+        assert_eq!(polynomial, poly_2);
+        let secret_recovered = sss.recover(&shares_to_use).unwrap();
+        assert_eq!(secret, *secret_recovered);
     }
 
     #[test]
@@ -193,19 +486,15 @@ mod tests {
         type LargeField = FieldElement<Stark252PrimeField>; // Alias for LargeField
         let secret = LargeField::new(UnsignedInteger::from(1234u64));
 
-        let sss = ShamirSecretSharingFFT {
-            share_amount: 32,
-            threshold: 16,
-            roots_of_unity: ShamirSecretSharingFFT::gen_roots_of_unity(32),
-        };
+        let sss = ShamirSecretSharingFFT::new(16, 32).unwrap();
 
         // generate polynomial, generate shares, then create a new vector with the first t+1 shares and the secret, and then verify that its equal to the shares polynomial after fill evals at all points
         let polynomial = sss.sample_polynomial(secret);
-        let shares = sss.generating_shares(&polynomial);
+        let shares = sss.evaluations(&polynomial);
         let mut shares_to_use = Vec::new();
         shares_to_use.push(secret);
         shares_to_use.extend(shares[0..sss.threshold + 1].to_vec());
-        sss.fill_evaluation_at_all_points(&mut shares_to_use);
+        sss.fill_evaluation_at_all_points(&mut shares_to_use).unwrap();
         // assert first element of shares_to_use is equal to secret
         assert_eq!(shares_to_use[0], secret);
         // remove shares_to_use[0]
@@ -9,6 +9,23 @@ use signal_hook::{
 };
 use std::net::{SocketAddr, SocketAddrV4};
 
+fn read_config(conf_str: &str) -> Node {
+    let conf_file = std::path::Path::new(conf_str);
+    let str = String::from(conf_str);
+    match conf_file
+        .extension()
+        .expect("Unable to get file extension")
+        .to_str()
+        .expect("Failed to convert the extension into ascii string")
+    {
+        "json" => Node::from_json(str),
+        "dat" => Node::from_bin(str),
+        "toml" => Node::from_toml(str),
+        "yaml" => Node::from_yaml(str),
+        _ => panic!("Invalid config file extension"),
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     log::error!("{}", std::env::current_dir().unwrap().display());
@@ -36,20 +53,7 @@ async fn main() -> Result<()> {
             panic!("Byz flag invalid value");
         }
     };
-    let conf_file = std::path::Path::new(conf_str);
-    let str = String::from(conf_str);
-    let mut config = match conf_file
-        .extension()
-        .expect("Unable to get file extension")
-        .to_str()
-        .expect("Failed to convert the extension into ascii string")
-    {
-        "json" => Node::from_json(str),
-        "dat" => Node::from_bin(str),
-        "toml" => Node::from_toml(str),
-        "yaml" => Node::from_yaml(str),
-        _ => panic!("Invalid config file extension"),
-    };
+    let mut config = read_config(conf_str);
 
     simple_logger::SimpleLogger::new()
         .with_utc_timestamps()
@@ -76,6 +80,44 @@ async fn main() -> Result<()> {
                 ecc_rbc::Context::spawn(config, input_value.as_bytes().to_vec(), node_normal)
                     .unwrap();
         }
+        "refresh" => {
+            exit_tx =
+                refresh::Context::spawn(config, node_normal)
+                    .unwrap();
+        }
+        "reshare" => {
+            // The old and the new committee, as config files listing the addresses of their members
+            let old_committee = read_config(
+                m.value_of("old_committee")
+                    .expect("Resharing needs the old committee's config file"),
+            );
+            let new_committee = read_config(
+                m.value_of("new_committee")
+                    .expect("Resharing needs the new committee's config file"),
+            );
+            old_committee.validate().expect("The old committee's config is not valid");
+            new_committee.validate().expect("The new committee's config is not valid");
+            exit_tx = reshare::Context::spawn(
+                config,
+                old_committee,
+                new_committee,
+                node_normal,
+            )
+            .unwrap();
+        }
+        "recovery" => {
+            exit_tx =
+                recovery::Context::spawn(config, node_normal)
+                    .unwrap();
+        }
+        "avss" => {
+            // The dealer shares the values the syncer sends it
+            exit_tx = avss::Context::spawn(config, node_normal).unwrap();
+        }
+        "hacss" => {
+            // The dealer shares the values the syncer sends it with threshold 2t + 1
+            exit_tx = hacss::Context::spawn(config, node_normal).unwrap();
+        }
         "sync" => {
             let f_str = syncer_file.to_string();
             log::info!("Logging the file f {}", f_str);
@@ -18,7 +18,9 @@ use tokio::{
     },
     time,
 };
-use types::{RBCSyncMsg, Replica, SyncMsg, SyncState};
+use consensus::{ByteShare, ShamirSecretSharing};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use types::{RBCSyncMsg, Replica, ShareSyncMsg, SyncMsg, SyncState};
 
 use crate::SyncHandler;
 
@@ -31,6 +33,8 @@ pub struct Syncer {
     pub rbc_start_times: HashMap<usize, u128>,
     pub rbc_complete_times: HashMap<usize, HashMap<Replica, u128>>,
     pub rbc_comp_values: HashMap<usize, HashSet<String>>,
+    /// Shares reported by the nodes at the end of a sharing
+    pub shares: HashMap<usize, HashMap<Replica, ByteShare<FrField>>>,
 
     pub broadcast_msgs: Vec<String>,
 
@@ -82,6 +86,7 @@ impl Syncer {
                 rbc_start_times: HashMap::default(),
                 rbc_complete_times: HashMap::default(),
                 rbc_comp_values: HashMap::default(),
+                shares: HashMap::default(),
 
                 broadcast_msgs: broadcast_msgs,
 
@@ -154,7 +159,13 @@ impl Syncer {
                             value_set.insert(rbc_msg.msg.to_string());
                             if latency_map.len() == self.num_nodes{
 
-                                let start_time = self.rbc_start_times.get(&rbc_msg.id).unwrap();
+                                let start_time = match self.rbc_start_times.get(&rbc_msg.id){
+                                    Some(start_time) => start_time,
+                                    None => {
+                                        log::warn!("Nodes completed ID: {}, which was never started",rbc_msg.id);
+                                        continue;
+                                    }
+                                };
                                 // All nodes terminated protocol
 
                                 let mut vec_times = Vec::new();
@@ -175,6 +186,40 @@ impl Syncer {
                                 }
                             }
                         }
+                        SyncState::SHARED=>{
+                            log::debug!("Got SHARED message from node {}",msg.sender);
+
+                            let share_msg: ShareSyncMsg = bincode::deserialize(&msg.value).expect("Unable to deserialize message received from node");
+                            let share = ByteShare::<FrField>::from_bytes(&share_msg.share).expect("Unable to deserialize share received from node");
+
+                            let latency_map = self.rbc_complete_times.entry(share_msg.id).or_default();
+                            latency_map.insert(msg.sender, SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .unwrap()
+                            .as_millis());
+                            let all_shared = latency_map.len() == self.num_nodes;
+
+                            self.shares.entry(share_msg.id).or_default().insert(msg.sender, share);
+                            if all_shared{
+                                let start_time = match self.rbc_start_times.get(&share_msg.id){
+                                    Some(start_time) => start_time,
+                                    None => {
+                                        log::warn!("Nodes shared ID: {}, which was never started",share_msg.id);
+                                        continue;
+                                    }
+                                };
+                                let mut vec_times: Vec<u128> = self.rbc_complete_times[&share_msg.id].values().map(|time| time-start_time).collect();
+                                vec_times.sort();
+
+                                match self.check_shares(share_msg.id){
+                                    Ok(()) => log::info!("All n nodes completed the sharing for ID: {} with latency {:?}, the shares reconstruct the dealer's input",share_msg.id,vec_times),
+                                    Err(e) => log::info!("Shares of ID: {} do not reconstruct the dealer's input, sharing failed, rerun test: {}",share_msg.id,e),
+                                }
+                                if self.rbc_id >= self.broadcast_msgs.len(){
+                                    self.broadcast(SyncMsg { sender: self.num_nodes, state: SyncState::STOP, value:"".to_string().into_bytes()}).await;
+                                }
+                            }
+                        }
                         _=>{}
                     }
                 },
@@ -190,12 +235,12 @@ impl Syncer {
                             msg: self.broadcast_msgs.get(&self.rbc_id-1).unwrap().to_string(),
                         };
                         let binaryfy_val = bincode::serialize(&sync_rbc_msg).expect("Failed to serialize client message");
-                        let cancel_handler:CancelHandler<Acknowledgement> = self.net_send.send(0, SyncMsg {
+                        // Every node hears of the instance from the syncer itself, the protocols pick who acts on it
+                        self.broadcast(SyncMsg {
                             sender: self.num_nodes,
                             state: SyncState::START,
                             value:binaryfy_val
                         }).await;
-                        self.add_cancel_handler(cancel_handler);
 
                         let start_time = SystemTime::now()
                         .duration_since(UNIX_EPOCH)
@@ -212,6 +257,23 @@ impl Syncer {
     pub fn add_cancel_handler(&mut self, canc: CancelHandler<Acknowledgement>) {
         self.cancel_handlers.push(canc);
     }
+
+    // Reconstructs the sharing of `id` from the first t shares and from the last t shares, both must give back
+    // the value the dealer was started with
+    fn check_shares(&self, id: usize) -> Result<(), String> {
+        let mut shares: Vec<ByteShare<FrField>> = self.shares[&id].values().cloned().collect();
+        shares.sort_by_key(|share| share.index);
+        let threshold = shares[0].threshold;
+        let sss = ShamirSecretSharing::<FrField>::new(threshold, self.num_nodes).map_err(|e| e.to_string())?;
+        let expected = self.broadcast_msgs[id - 1].as_bytes();
+        for subset in [&shares[..threshold], &shares[shares.len() - threshold..]] {
+            let secret = sss.recover_bytes(subset).map_err(|e| e.to_string())?;
+            if secret.as_slice() != expected {
+                return Err(format!("parties {:?} reconstruct a different value", subset.iter().map(|share| share.index).collect::<Vec<_>>()));
+            }
+        }
+        Ok(())
+    }
 }
 
 fn read_lines(filename: &str) -> Vec<String> {
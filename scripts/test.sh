@@ -34,4 +34,4 @@ for((i=0;i<16;i++)); do
 done
 
 # Kill all nodes sudo lsof -ti:7000-7015 | xargs kill -9
-# options for $5: rbc, ctrbc, ecc_rbc
\ No newline at end of file
+# options for $5: rbc, ctrbc, ecc_rbc, refresh, recovery, avss, hacss (reshare also takes --old-committee and --new-committee)
\ No newline at end of file
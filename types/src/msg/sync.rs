@@ -8,6 +8,8 @@ pub enum SyncState{
     START,
     STARTED,
     COMPLETED,
+    // A node completed a sharing and sends its share, see ShareSyncMsg
+    SHARED,
     STOP,
     STOPPED
 }
@@ -25,6 +27,13 @@ pub struct RBCSyncMsg{
     pub msg: String,
 }
 
+#[derive(Debug,Serialize,Deserialize,Clone)]
+pub struct ShareSyncMsg{
+    pub id: usize,
+    /// The node's serialized share
+    pub share: Vec<u8>,
+}
+
 impl WireReady for SyncMsg{
     fn from_bytes(bytes: &[u8]) -> Self {
         let c:Self = bincode::deserialize(bytes)